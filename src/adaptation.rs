@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests;
+
+use std::marker::PhantomData;
+use crate::{
+    error::OutOfGamut,
+    lms::{
+        Bradford,
+        ConeFundamentals,
+    },
+    matrix,
+    Ciexyz,
+    Lms,
+};
+
+/// A chromatic adaptation transform, predicting the color that looks the same under a
+/// destination white point as a given color under a source white point.
+///
+/// The adaptation is a von Kries style scaling of the cone responses given by the `M` type
+/// parameter. Using `VonKries`, `Bradford`, `Cat02` or `Cat16` from the `lms` module gives
+/// the transforms of the same name.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ChromaticAdaptation<M> {
+    gains: [f32; 3],
+    cone_fundamentals: PhantomData<M>,
+}
+
+impl<M: ConeFundamentals> ChromaticAdaptation<M> {
+    /// Creates a chromatic adaptation transform with complete adaptation from a source white
+    /// point to a destination white point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ adaptation::ChromaticAdaptation, ciexyz, lms::Bradford };
+    /// let d50_to_d65 = ChromaticAdaptation::<Bradford>::new(ciexyz::D50, ciexyz::D65);
+    /// ```
+    pub fn new(source_white: Ciexyz, destination_white: Ciexyz) -> Self {
+        Self::partial(source_white, destination_white, 1f32).unwrap()
+    }
+
+    /// Creates a chromatic adaptation transform with partial adaptation from a source white
+    /// point to a destination white point.
+    ///
+    /// The degree of adaptation must be between 0 (no adaptation at all) and 1 (complete
+    /// adaptation) included otherwise the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ adaptation::ChromaticAdaptation, ciexyz, lms::Cat02 };
+    /// let valid = ChromaticAdaptation::<Cat02>::partial(ciexyz::A, ciexyz::D65, 0.8); // Ok.
+    /// let invalid = ChromaticAdaptation::<Cat02>::partial(ciexyz::A, ciexyz::D65, 1.2); // Not ok. The degree of adaptation is greater than 1.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn partial(source_white: Ciexyz, destination_white: Ciexyz, degree: f32) -> Option<Self> {
+        let source: [f32; 3] = Lms::<M>::from(source_white).into();
+        let destination: [f32; 3] = Lms::<M>::from(destination_white).into();
+        let gains = [0, 1, 2].map(|i| degree * destination[i] / source[i] + 1f32 - degree);
+        match (0f32..=1f32).contains(&degree) {
+            true    => Some(Self { gains, cone_fundamentals: PhantomData }),
+            false   => None,
+        }
+    }
+
+    /// Adapts a CIEXYZ color seen under the source white point to the destination white point.
+    /// The adaptation may fail and return an OutOfGamut error
+    /// if the adapted color isn't a valid CIEXYZ color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ adaptation::ChromaticAdaptation, Ciexyz, ciexyz, lms::Bradford, Srgb };
+    /// let d50_to_d65 = ChromaticAdaptation::<Bradford>::new(ciexyz::D50, ciexyz::D65);
+    /// let measured = Ciexyz::new(0.4, 0.35, 0.2).unwrap();
+    /// let srgb = Srgb::try_from(d50_to_d65.adapt(measured).unwrap());
+    /// ```
+    pub fn adapt(&self, ciexyz: Ciexyz) -> Result<Ciexyz, OutOfGamut> {
+        let lms: [f32; 3] = Lms::<M>::from(ciexyz).into();
+        let adapted: Lms<M> = [0, 1, 2].map(|i| lms[i] * self.gains[i]).into();
+        Ciexyz::try_from(adapted)
+    }
+
+    //Matrix applying the adaptation to CIEXYZ values, without checking that the adapted values
+    //are a valid CIEXYZ color.
+    pub(crate) fn matrix(&self) -> [f32; 9] {
+        let [l, m, s] = self.gains;
+        let gains = [l, 0f32, 0f32, 0f32, m, 0f32, 0f32, 0f32, s];
+        matrix::multiply(M::TO_CIEXYZ, matrix::multiply(gains, M::FROM_CIEXYZ))
+    }
+}
+
+//Adapts CIEXYZ values from a white point to another with the Bradford transform, the one used
+//by ICC profiles and CSS to go between D65 and D50. The values are left as they are if the white
+//points are the same.
+pub(crate) fn bradford(source_white: Ciexyz, destination_white: Ciexyz, ciexyz: [f32; 3]) -> [f32; 3] {
+    match source_white == destination_white {
+        true    => ciexyz,
+        false   => matrix::transform(ChromaticAdaptation::<Bradford>::new(source_white, destination_white).matrix(), ciexyz),
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+use crate::{
+    ciexyz,
+    lms::{
+        Bradford,
+        Cat02,
+        Cat16,
+        VonKries,
+    },
+};
+
+fn assert_close(lhs: Ciexyz, rhs: Ciexyz, tolerance: f32) {
+    let lhs: [f32; 3] = lhs.into();
+    let rhs: [f32; 3] = rhs.into();
+    assert!((0..3).all(|i| (lhs[i] - rhs[i]).abs() < tolerance), "{lhs:?} != {rhs:?}");
+}
+
+#[test]
+fn white_to_white() {
+    assert_close(ChromaticAdaptation::<VonKries>::new(ciexyz::D50, ciexyz::D65).adapt(ciexyz::D50).unwrap(), ciexyz::D65, 1e-5);
+    assert_close(ChromaticAdaptation::<Bradford>::new(ciexyz::D50, ciexyz::D65).adapt(ciexyz::D50).unwrap(), ciexyz::D65, 1e-5);
+    assert_close(ChromaticAdaptation::<Cat02>::new(ciexyz::A, ciexyz::D65).adapt(ciexyz::A).unwrap(), ciexyz::D65, 1e-5);
+    assert_close(ChromaticAdaptation::<Cat16>::new(ciexyz::D65, ciexyz::F11).adapt(ciexyz::D65).unwrap(), ciexyz::F11, 1e-5);
+}
+
+#[test]
+fn bradford_d50_to_d65() {
+    //Reference matrix from Bruce Lindbloom.
+    let [x, y, z] = [0.4f32, 0.35f32, 0.2f32];
+    let expected = Ciexyz::new(
+        0.9555766 * x - 0.0230393 * y + 0.0631636 * z,
+        -0.0282895 * x + 1.0099416 * y + 0.0210077 * z,
+        0.0122982 * x - 0.0204830 * y + 1.3299098 * z,
+    ).unwrap();
+    let d50_to_d65 = ChromaticAdaptation::<Bradford>::new(ciexyz::D50, ciexyz::D65);
+    assert_close(d50_to_d65.adapt(Ciexyz::new(x, y, z).unwrap()).unwrap(), expected, 1e-3);
+}
+
+#[test]
+fn partial() {
+    let color = Ciexyz::new(0.4, 0.35, 0.2).unwrap();
+    let none = ChromaticAdaptation::<Cat02>::partial(ciexyz::A, ciexyz::D65, 0.0).unwrap();
+    assert_close(none.adapt(color).unwrap(), color, 1e-6);
+    let complete = ChromaticAdaptation::<Cat02>::partial(ciexyz::A, ciexyz::D65, 1.0).unwrap();
+    assert_eq!(complete, ChromaticAdaptation::new(ciexyz::A, ciexyz::D65));
+    let half = ChromaticAdaptation::<Cat02>::partial(ciexyz::A, ciexyz::D65, 0.5).unwrap();
+    let half_z = half.adapt(color).unwrap().z();
+    assert!(color.z() < half_z && half_z < complete.adapt(color).unwrap().z());
+    assert!(ChromaticAdaptation::<Cat02>::partial(ciexyz::A, ciexyz::D65, -0.1).is_none());
+}
+
+#[test]
+fn matrix() {
+    let adaptation = ChromaticAdaptation::<Bradford>::new(ciexyz::D65, ciexyz::D50);
+    let ciexyz = Ciexyz::new(0.4, 0.35, 0.2).unwrap();
+    let expected: [f32; 3] = adaptation.adapt(ciexyz).unwrap().into();
+    let adapted = crate::matrix::transform(adaptation.matrix(), ciexyz.into());
+    assert!((0..3).all(|i| (adapted[i] - expected[i]).abs() < 1e-6));
+    assert_eq!(bradford(ciexyz::D65, ciexyz::D65, [0.4, 0.35, 0.2]), [0.4, 0.35, 0.2]);
+    let adapted = bradford(ciexyz::D65, ciexyz::D50, ciexyz::D65.into());
+    let d50: [f32; 3] = ciexyz::D50.into();
+    assert!((0..3).all(|i| (adapted[i] - d50[i]).abs() < 1e-5));
+}
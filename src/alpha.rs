@@ -0,0 +1,344 @@
+//! Colors with an alpha channel, in straight and premultiplied forms.
+//!
+//! Colors with alpha channels convert between color spaces the same way as the colors they
+//! wrap, the alpha being carried over.
+//!
+//! ```
+//! use cowor::{ Alpha, Cielab, Srgb, Srgba };
+//! let srgba = Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap();
+//! let cielaba: Alpha<Cielab> = srgba.into();
+//! let back = Srgba::try_from(cielaba).unwrap();
+//! assert_eq!(back, srgba);
+//! ```
+
+mod conversions;
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    illuminant::{
+        D65,
+        Illuminant,
+    },
+    interpolation::{
+        self,
+        Mix,
+    },
+    lms::ConeFundamentals,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Lms,
+    Oklab,
+    Oklch,
+    Srgb,
+    Ycocg,
+};
+
+/// A color of type `C` with an alpha channel, its opacity, in straight (not premultiplied) form.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Alpha<C> {
+    color: C,
+    alpha: f32,
+}
+
+/// A color of type `C` with an alpha channel, its components being premultiplied by the alpha.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Premultiplied<C> {
+    color: C,
+    alpha: f32,
+}
+
+/// An sRGB color with an alpha channel.
+pub type Srgba = Alpha<Srgb>;
+
+/// A CIELAB color with an alpha channel.
+pub type Cielaba<W = D65> = Alpha<Cielab<W>>;
+
+/// A CIELCh color with an alpha channel.
+pub type Cielcha<W = D65> = Alpha<Cielch<W>>;
+
+/// A color whose components can be premultiplied by an alpha.
+///
+/// Only the components on which colors are interpolated linearly are premultiplied, the hue of
+/// polar color spaces is left as is.
+pub trait Premultiply: Sized {
+    /// Multiplies the components of a color by an alpha.
+    fn premultiply(&self, alpha: f32) -> Self;
+
+    /// Divides the components of a premultiplied color by an alpha.
+    /// The components are left as is if the alpha is 0.
+    fn unpremultiply(&self, alpha: f32) -> Self;
+}
+
+impl<C> Alpha<C> {
+    /// Creates a color with an alpha channel from a color and its alpha.
+    ///
+    /// The alpha must be between 0 (fully transparent) and 1 (fully opaque) included
+    /// otherwise the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Alpha, Srgb };
+    /// let valid = Alpha::new(Srgb::new(10, 20, 30), 0.5); // Ok.
+    /// let invalid = Alpha::new(Srgb::new(10, 20, 30), 1.5); // Not ok. The alpha is greater than 1.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn new(color: C, alpha: f32) -> Option<Self> {
+        match (0f32..=1f32).contains(&alpha) {
+            true    => Some(Self { color, alpha }),
+            false   => None,
+        }
+    }
+
+    /// Creates a fully opaque color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Alpha, Srgb };
+    /// let opaque = Alpha::opaque(Srgb::new(10, 20, 30));
+    /// assert_eq!(opaque.alpha(), 1.0);
+    /// ```
+    pub fn opaque(color: C) -> Self {
+        Self { color, alpha: 1f32 }
+    }
+
+    /// Alpha of a color, between 0 (fully transparent) and 1 (fully opaque) included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Alpha, Srgb };
+    /// let srgba = Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap();
+    /// assert_eq!(srgba.alpha(), 0.5);
+    /// ```
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+}
+
+impl<C: Copy> Alpha<C> {
+    /// Color of a color with an alpha channel, without its alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Alpha, Srgb };
+    /// let srgba = Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap();
+    /// assert_eq!(srgba.color(), Srgb::new(10, 20, 30));
+    /// ```
+    pub fn color(&self) -> C {
+        self.color
+    }
+}
+
+impl<C> Premultiplied<C> {
+    /// Creates a premultiplied color from its already premultiplied components and its alpha.
+    ///
+    /// The alpha must be between 0 (fully transparent) and 1 (fully opaque) included
+    /// otherwise the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ alpha::Premultiplied, Srgb };
+    /// let valid = Premultiplied::new(Srgb::new(10, 20, 30), 0.5); // Ok.
+    /// let invalid = Premultiplied::new(Srgb::new(10, 20, 30), -0.5); // Not ok. The alpha is negative.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn new(color: C, alpha: f32) -> Option<Self> {
+        match (0f32..=1f32).contains(&alpha) {
+            true    => Some(Self { color, alpha }),
+            false   => None,
+        }
+    }
+
+    /// Alpha of a premultiplied color, between 0 (fully transparent) and 1 (fully opaque) included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ alpha::Premultiplied, Srgb };
+    /// let premultiplied = Premultiplied::new(Srgb::new(10, 20, 30), 0.5).unwrap();
+    /// assert_eq!(premultiplied.alpha(), 0.5);
+    /// ```
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+}
+
+impl<C: Copy> Premultiplied<C> {
+    /// Premultiplied components of a premultiplied color, without its alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ alpha::Premultiplied, Alpha, Srgb };
+    /// let srgba = Alpha::new(Srgb::new(255, 128, 0), 0.5).unwrap();
+    /// let premultiplied = Premultiplied::from(srgba);
+    /// assert_eq!(premultiplied.color(), Srgb::new(128, 64, 0));
+    /// ```
+    pub fn color(&self) -> C {
+        self.color
+    }
+}
+
+/// Premultiplication of a color with an alpha channel.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ alpha::Premultiplied, Alpha, Srgb };
+/// let srgba = Alpha::new(Srgb::new(255, 128, 0), 0.5).unwrap();
+/// let premultiplied: Premultiplied<Srgb> = srgba.into();
+/// ```
+impl<C: Premultiply> From<Alpha<C>> for Premultiplied<C> {
+    fn from(alpha: Alpha<C>) -> Self {
+        Self { color: alpha.color.premultiply(alpha.alpha), alpha: alpha.alpha }
+    }
+}
+
+/// Division of a premultiplied color by its alpha.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ alpha::Premultiplied, Alpha, Srgb };
+/// let premultiplied = Premultiplied::new(Srgb::new(128, 64, 0), 0.5).unwrap();
+/// let srgba: Alpha<Srgb> = premultiplied.into();
+/// assert_eq!(srgba.color(), Srgb::new(255, 128, 0));
+/// ```
+impl<C: Premultiply> From<Premultiplied<C>> for Alpha<C> {
+    fn from(premultiplied: Premultiplied<C>) -> Self {
+        Self { color: premultiplied.color.unpremultiply(premultiplied.alpha), alpha: premultiplied.alpha }
+    }
+}
+
+/// Interpolation between two colors with alpha channels, done on their premultiplied components
+/// as the CSS `color-mix()` function does. The alphas are interpolated linearly.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ interpolation::Mix, Alpha, Srgb };
+/// let red = Alpha::new(Srgb::new(255, 0, 0), 1.0).unwrap();
+/// let transparent = Alpha::new(Srgb::new(0, 0, 255), 0.0).unwrap();
+/// let mixed = red.mix(&transparent, 0.5);
+/// assert_eq!(mixed.color(), Srgb::new(255, 0, 0));
+/// assert_eq!(mixed.alpha(), 0.5);
+/// ```
+impl<C: Mix + Premultiply + Copy> Mix for Alpha<C> {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        let first = Premultiplied::from(*self);
+        let second = Premultiplied::from(*other);
+        let alpha = interpolation::lerp(self.alpha, other.alpha, amount);
+        Premultiplied { color: first.color.mix(&second.color, amount), alpha }.into()
+    }
+}
+
+//Multiplies the components of an array by an alpha.
+fn multiply(components: [f32; 3], alpha: f32) -> [f32; 3] {
+    components.map(|x| x * alpha)
+}
+
+//Divides the components of an array by an alpha, unless it is 0.
+fn divide(components: [f32; 3], alpha: f32) -> [f32; 3] {
+    match alpha == 0f32 {
+        true    => components,
+        false   => components.map(|x| x / alpha),
+    }
+}
+
+impl Premultiply for Srgb {
+    fn premultiply(&self, alpha: f32) -> Self {
+        multiply((*self).into(), alpha).into()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        divide((*self).into(), alpha).map(|x| x.clamp(0f32, 1f32)).into()
+    }
+}
+
+impl Premultiply for Ciexyz {
+    fn premultiply(&self, alpha: f32) -> Self {
+        Self::try_from(multiply((*self).into(), alpha)).unwrap()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        let [x, y, z] = divide((*self).into(), alpha);
+        Self::try_from([x, y.min(1f32), z]).unwrap()
+    }
+}
+
+impl<W: Illuminant> Premultiply for Cielab<W> {
+    fn premultiply(&self, alpha: f32) -> Self {
+        Self::try_from(multiply((*self).into(), alpha)).unwrap()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        let [l, a, b] = divide((*self).into(), alpha);
+        Self::try_from([l.min(100f32), a, b]).unwrap()
+    }
+}
+
+impl<W: Illuminant> Premultiply for Cielch<W> {
+    fn premultiply(&self, alpha: f32) -> Self {
+        let [l, c, h]: [f32; 3] = (*self).into();
+        Self::try_from([l * alpha, c * alpha, h]).unwrap()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        let [l, c, h]: [f32; 3] = (*self).into();
+        let [l, c, _] = divide([l, c, 0f32], alpha);
+        Self::try_from([l.min(100f32), c, h]).unwrap()
+    }
+}
+
+impl<M: ConeFundamentals> Premultiply for Lms<M> {
+    fn premultiply(&self, alpha: f32) -> Self {
+        multiply((*self).into(), alpha).into()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        divide((*self).into(), alpha).into()
+    }
+}
+
+impl Premultiply for Oklab {
+    fn premultiply(&self, alpha: f32) -> Self {
+        Self::try_from(multiply((*self).into(), alpha)).unwrap()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        let [l, a, b] = divide((*self).into(), alpha);
+        Self::try_from([l.min(1f32), a, b]).unwrap()
+    }
+}
+
+impl Premultiply for Oklch {
+    fn premultiply(&self, alpha: f32) -> Self {
+        let [l, c, h]: [f32; 3] = (*self).into();
+        Self::try_from([l * alpha, c * alpha, h]).unwrap()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        let [l, c, h]: [f32; 3] = (*self).into();
+        let [l, c, _] = divide([l, c, 0f32], alpha);
+        Self::try_from([l.min(1f32), c, h]).unwrap()
+    }
+}
+
+impl Premultiply for Ycocg {
+    fn premultiply(&self, alpha: f32) -> Self {
+        Self::try_from(multiply((*self).into(), alpha)).unwrap()
+    }
+
+    fn unpremultiply(&self, alpha: f32) -> Self {
+        let [y, co, cg] = divide((*self).into(), alpha);
+        Self::try_from([y.min(1f32), co.clamp(-0.5f32, 0.5f32), cg.clamp(-0.5f32, 0.5f32)]).unwrap()
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    error::OutOfSpecification,
+    illuminant::Illuminant,
+    lms::ConeFundamentals,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Lms,
+    Oklab,
+    Oklch,
+    Srgb,
+    Ycocg,
+    YcocgR,
+};
+use super::Alpha;
+
+//Conversion between two colors with alpha channels whose colors can always be converted.
+//The alpha is carried over as is.
+macro_rules! from {
+    ($from:ty => $to:ty $(, $generic:ident: $bound:path)*) => {
+        impl<$($generic: $bound),*> From<Alpha<$from>> for Alpha<$to> {
+            fn from(alpha: Alpha<$from>) -> Self {
+                Self { color: alpha.color.into(), alpha: alpha.alpha }
+            }
+        }
+    };
+}
+
+//Conversion between two colors with alpha channels whose colors can't always be converted.
+//The error is the one of the conversion between the colors.
+macro_rules! try_from {
+    ($from:ty => $to:ty $(, $generic:ident: $bound:path)*) => {
+        impl<$($generic: $bound),*> TryFrom<Alpha<$from>> for Alpha<$to> {
+            type Error = <$to as TryFrom<$from>>::Error;
+
+            fn try_from(alpha: Alpha<$from>) -> Result<Self, Self::Error> {
+                Ok(Self { color: alpha.color.try_into()?, alpha: alpha.alpha })
+            }
+        }
+    };
+}
+
+from!(Srgb => Ciexyz);
+from!(Srgb => Cielab<W>, W: Illuminant);
+from!(Srgb => Cielch<W>, W: Illuminant);
+from!(Srgb => Lms<M>, M: ConeFundamentals);
+from!(Srgb => Oklab);
+from!(Srgb => Oklch);
+from!(Srgb => Ycocg);
+from!(Srgb => YcocgR);
+from!(Ciexyz => Cielab<W>, W: Illuminant);
+from!(Ciexyz => Cielch<W>, W: Illuminant);
+from!(Ciexyz => Lms<M>, M: ConeFundamentals);
+from!(Ciexyz => Oklab);
+from!(Ciexyz => Oklch);
+from!(Cielab<W> => Ciexyz, W: Illuminant);
+from!(Cielab<W> => Cielch<W>, W: Illuminant);
+from!(Cielab<W> => Lms<M>, M: ConeFundamentals, W: Illuminant);
+from!(Cielab<W> => Oklab, W: Illuminant);
+from!(Cielab<W> => Oklch, W: Illuminant);
+from!(Cielch<W> => Ciexyz, W: Illuminant);
+from!(Cielch<W> => Cielab<W>, W: Illuminant);
+from!(Cielch<W> => Lms<M>, M: ConeFundamentals, W: Illuminant);
+from!(Cielch<W> => Oklab, W: Illuminant);
+from!(Cielch<W> => Oklch, W: Illuminant);
+from!(Oklab => Oklch);
+from!(Oklab => Lms<M>, M: ConeFundamentals);
+from!(Oklch => Oklab);
+from!(Oklch => Lms<M>, M: ConeFundamentals);
+
+try_from!(Ciexyz => Srgb);
+try_from!(Cielab<W> => Srgb, W: Illuminant);
+try_from!(Cielch<W> => Srgb, W: Illuminant);
+try_from!(Lms<M> => Srgb, M: ConeFundamentals);
+try_from!(Ycocg => Srgb);
+try_from!(YcocgR => Srgb);
+try_from!(Lms<M> => Ciexyz, M: ConeFundamentals);
+try_from!(Lms<M> => Cielab<W>, M: ConeFundamentals, W: Illuminant);
+try_from!(Lms<M> => Cielch<W>, M: ConeFundamentals, W: Illuminant);
+try_from!(Lms<M> => Oklab, M: ConeFundamentals);
+try_from!(Lms<M> => Oklch, M: ConeFundamentals);
+try_from!(Oklab => Srgb);
+try_from!(Oklab => Ciexyz);
+try_from!(Oklab => Cielab<W>, W: Illuminant);
+try_from!(Oklab => Cielch<W>, W: Illuminant);
+try_from!(Oklch => Srgb);
+try_from!(Oklch => Ciexyz);
+try_from!(Oklch => Cielab<W>, W: Illuminant);
+try_from!(Oklch => Cielch<W>, W: Illuminant);
+
+/// New sRGB color with an alpha channel from an array of 4 unsigned 8 bit integers,
+/// the last one being the alpha.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Srgba;
+/// let srgba: Srgba = [10, 20, 30, 128].into();
+/// ```
+impl From<[u8; 4]> for Alpha<Srgb> {
+    fn from(array: [u8; 4]) -> Self {
+        let [r, g, b, a] = array;
+        Self { color: [r, g, b].into(), alpha: a as f32 / 255f32 }
+    }
+}
+
+/// Convert an sRGB color with an alpha channel into an array of 4 unsigned 8 bit integers,
+/// the last one being the alpha.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Alpha, Srgb };
+/// let [r, g, b, a]: [u8; 4] = Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap().into();
+/// assert_eq!(a, 128);
+/// ```
+impl From<Alpha<Srgb>> for [u8; 4] {
+    fn from(srgba: Alpha<Srgb>) -> Self {
+        let [r, g, b]: [u8; 3] = srgba.color.into();
+        [r, g, b, (srgba.alpha * 255f32).round() as u8]
+    }
+}
+
+/// New sRGB color with an alpha channel from an array of 4 floats, the last one being the alpha.
+/// Convertion may fail if the alpha isn't between 0 and 1 included.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Srgba;
+/// let srgba: Srgba = [0.1, 0.2, 0.3, 0.5].try_into().unwrap();
+/// assert!(Srgba::try_from([0.1, 0.2, 0.3, 1.5]).is_err());
+/// ```
+impl TryFrom<[f32; 4]> for Alpha<Srgb> {
+    type Error = OutOfSpecification;
+
+    fn try_from(array: [f32; 4]) -> Result<Self, Self::Error> {
+        let [r, g, b, a] = array;
+        Self::new([r, g, b].into(), a).ok_or(OutOfSpecification)
+    }
+}
+
+/// Convert an sRGB color with an alpha channel into an array of 4 floats, the last one being
+/// the alpha.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Alpha, Srgb };
+/// let [r, g, b, a]: [f32; 4] = Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap().into();
+/// ```
+impl From<Alpha<Srgb>> for [f32; 4] {
+    fn from(srgba: Alpha<Srgb>) -> Self {
+        let [r, g, b]: [f32; 3] = srgba.color.into();
+        [r, g, b, srgba.alpha]
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::{
+    illuminant::D50,
+    lms::Bradford,
+    YcocgR,
+};
+
+#[test]
+fn alpha_range() {
+    let srgb = Srgb::new(10, 20, 30);
+    assert!(Alpha::new(srgb, 0.0).is_some());
+    assert!(Alpha::new(srgb, 1.0).is_some());
+    assert!(Alpha::new(srgb, -0.1).is_none());
+    assert!(Alpha::new(srgb, 1.1).is_none());
+    assert!(Alpha::new(srgb, f32::NAN).is_none());
+    assert!(Premultiplied::new(srgb, 1.1).is_none());
+}
+
+#[test]
+fn premultiplication_round_trip() {
+    let srgba = Alpha::new(Srgb::new(200, 100, 50), 0.25).unwrap();
+    let premultiplied = Premultiplied::from(srgba);
+    assert_eq!(premultiplied.color(), Srgb::new(50, 25, 13));
+    assert_eq!(premultiplied.alpha(), 0.25);
+    assert_eq!(Alpha::from(premultiplied), srgba);
+
+    let cielch = Alpha::new(Cielch::new(60.0, 40.0, 2.0).unwrap(), 0.5).unwrap();
+    let premultiplied = Premultiplied::from(cielch);
+    let [l, c, h]: [f32; 3] = premultiplied.color().into();
+    assert_eq!([l, c, h], [30.0, 20.0, 2.0]);
+    let [l, c, h]: [f32; 3] = Alpha::from(premultiplied).color().into();
+    assert_eq!([l, c, h], [60.0, 40.0, 2.0]);
+}
+
+#[test]
+fn unpremultiplication_stays_valid() {
+    let transparent = Premultiplied::new(Srgb::new(10, 20, 30), 0.0).unwrap();
+    assert_eq!(Alpha::from(transparent).color(), Srgb::new(10, 20, 30));
+    let overflowing = Premultiplied::new(Srgb::new(200, 200, 200), 0.5).unwrap();
+    assert_eq!(Alpha::from(overflowing).color(), Srgb::new(255, 255, 255));
+    let overflowing = Premultiplied::new(Ycocg::try_from([0.8, 0.4, -0.4]).unwrap(), 0.5).unwrap();
+    let [y, co, cg]: [f32; 3] = Alpha::from(overflowing).color().into();
+    assert_eq!([y, co, cg], [1.0, 0.5, -0.5]);
+}
+
+#[test]
+fn conversions_carry_alpha() {
+    let srgba = Alpha::new(Srgb::new(10, 120, 230), 0.3).unwrap();
+    let ciexyz: Alpha<Ciexyz> = srgba.into();
+    let cielab: Cielaba<D50> = ciexyz.into();
+    let cielch: Cielcha<D50> = cielab.into();
+    let lms: Alpha<Lms<Bradford>> = cielch.into();
+    let ycocg_r: Alpha<YcocgR> = srgba.into();
+    assert_eq!(ciexyz.alpha(), 0.3);
+    assert_eq!(cielab.alpha(), 0.3);
+    assert_eq!(cielch.alpha(), 0.3);
+    assert_eq!(lms.alpha(), 0.3);
+    assert_eq!(Srgba::try_from(lms).unwrap(), srgba);
+    assert_eq!(Srgba::try_from(ycocg_r).unwrap(), srgba);
+
+    let out_of_gamut = Alpha::new(Cielab::new(100.0, 100.0, 0.0).unwrap(), 0.5).unwrap();
+    assert!(Srgba::try_from(out_of_gamut).is_err());
+}
+
+#[test]
+fn arrays() {
+    let srgba: Srgba = [10, 20, 30, 51].into();
+    assert_eq!(srgba.color(), Srgb::new(10, 20, 30));
+    assert_eq!(srgba.alpha(), 0.2);
+    assert_eq!(<[u8; 4]>::from(srgba), [10, 20, 30, 51]);
+    assert_eq!(<[f32; 4]>::from(srgba)[3], 0.2);
+    assert!(Srgba::try_from([0.1, 0.2, 0.3, -0.5]).is_err());
+}
+
+#[test]
+fn mix() {
+    //Mixing with a fully transparent color only changes the alpha, as in CSS.
+    let red = Alpha::new(Srgb::new(255, 0, 0), 1.0).unwrap();
+    let transparent = Alpha::new(Srgb::new(0, 0, 255), 0.0).unwrap();
+    let mixed = red.mix(&transparent, 0.25);
+    assert_eq!(mixed.color(), Srgb::new(255, 0, 0));
+    assert!((mixed.alpha() - 0.75).abs() < 1e-6);
+
+    let first = Alpha::new(Srgb::new(255, 0, 0), 0.8).unwrap();
+    let second = Alpha::new(Srgb::new(0, 0, 255), 0.2).unwrap();
+    let mixed = first.mix(&second, 0.5);
+    assert_eq!(mixed.color(), Srgb::new(204, 0, 51));
+    assert!((mixed.alpha() - 0.5).abs() < 1e-6);
+    assert_eq!(first.mix(&second, 0.0), first);
+}
@@ -1,10 +1,26 @@
 mod conversions;
+mod difference;
 #[cfg(test)]
 mod tests;
 
+use std::marker::PhantomData;
+use crate::illuminant::{
+    D65,
+    Illuminant,
+};
+
+pub use difference::Cie94Application;
+pub(crate) use conversions::{
+    from_ciexyz,
+    to_ciexyz,
+};
+
 /// A struct representing a color in the CIELAB color space.
+///
+/// CIELAB is defined relatively to a reference white, given by the `W` type parameter.
+/// It is D65 by default, the reference white of sRGB.
 #[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Cielab(f32, f32, f32); // lightness, a, b
+pub struct Cielab<W = D65>(f32, f32, f32, PhantomData<W>); // lightness, a, b
 
 impl Cielab {
     /// Creates a Cielab instance from 3 float
@@ -24,8 +40,30 @@ impl Cielab {
     /// assert!(invalid.is_none());
     /// ```
     pub fn new(lightness: f32, a: f32, b: f32) -> Option<Self> {
+        Self::with_reference_white(lightness, a, b, D65)
+    }
+}
+
+impl<W: Illuminant> Cielab<W> {
+    /// Creates a Cielab instance relative to a given reference white from 3 float
+    /// representing the lightness, a, and b components.
+    ///
+    /// The lightness component must between 0 and 100 included
+    /// otherwise it doesn't represent a valid CIELAB color
+    /// and the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Cielab, illuminant::D50 };
+    /// let valid = Cielab::with_reference_white(10.0, 20.0, 30.0, D50); // Ok.
+    /// let invalid = Cielab::with_reference_white(110.0, 20.0, 30.0, D50); // Not ok. The lightness component is greater than 100.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn with_reference_white(lightness: f32, a: f32, b: f32, _reference_white: W) -> Option<Self> {
         match (0f32..=100f32).contains(&lightness) {
-            true    => Some(Self(lightness, a, b)),
+            true    => Some(Self(lightness, a, b, PhantomData)),
             false   => None,
         }
     }
@@ -1,38 +1,74 @@
+use std::marker::PhantomData;
 use super::Cielab;
 use crate::{
-    error::OutOfSpecification,
+    adaptation,
+    error::{ OutOfGamut, OutOfSpecification },
+    illuminant::{
+        D65,
+        Illuminant,
+    },
+    lms::ConeFundamentals,
+    Lms,
     Cielch,
     Ciexyz,
-    ciexyz::D65,
+    Oklab,
+    Oklch,
     Srgb,
 };
 
+//Conversion of CIEXYZ values relative to D65, which may fall outside of the CIEXYZ specification
+//for colors outside of the gamut of real colors, to CIELAB.
+pub(crate) fn from_ciexyz<W: Illuminant>(ciexyz: [f32; 3]) -> Cielab<W> {
+    let delta = 6f32 / 29f32;
+    let f = |t| match t > f32::powf(delta, 3f32) {
+        true    => f32::powf(t, 1f32 / 3f32),
+        false   => t / (3f32 * f32::powf(delta, 2f32)) + 4f32 / 29f32,
+    };
+    let white = W::WHITE_POINT;
+    let [x, y, z] = adaptation::bradford(D65::WHITE_POINT, white, ciexyz);
+    let x_ratio = x / white.x();
+    let y_ratio = y / white.y();
+    let z_ratio = z / white.z();
+    let l = 116f32 * f(y_ratio) - 16f32;
+    let a = 500f32 * (f(x_ratio) - f(y_ratio));
+    let b = 200f32 * (f(y_ratio) - f(z_ratio));
+    Cielab(l, a, b, PhantomData)
+}
+
+//Conversion of a CIELAB color to CIEXYZ values relative to D65, which may fall outside of the
+//CIEXYZ specification for colors outside of the gamut of real colors.
+pub(crate) fn to_ciexyz<W: Illuminant>(cielab: Cielab<W>) -> [f32; 3] {
+    let delta = 6f32 / 29f32;
+    let f = |t| match t > delta {
+        true    => f32::powf(t, 3f32),
+        false   => 3f32 * f32::powf(delta, 2f32) * (t - 4f32 / 29f32),
+    };
+    let p = (cielab.lightness() + 16f32) / 116f32;
+    let white = W::WHITE_POINT;
+    let x = white.x() * f(p + cielab.a() / 500f32);
+    let y = white.y() * f(p);
+    let z = white.z() * f(p - cielab.b() / 200f32);
+    adaptation::bradford(white, D65::WHITE_POINT, [x, y, z])
+}
+
 /// Conversion from CIEXYZ to CIELAB.
+/// The CIEXYZ color, relative to D65, is adapted to the reference white of the CIELAB color
+/// with the Bradford chromatic adaptation transform, as prepress tools do.
 ///
 /// # Examples
 ///
 /// ```
-/// use cowor::{ Cielab, Ciexyz };
+/// use cowor::{ Cielab, Ciexyz, illuminant::D50 };
 /// let cielab: Cielab = Ciexyz::new(0.1, 0.2, 0.3).unwrap().into();
+/// let cielab_d50: Cielab<D50> = Ciexyz::new(0.1, 0.2, 0.3).unwrap().into();
 /// ```
-impl From<Ciexyz> for Cielab {
+impl<W: Illuminant> From<Ciexyz> for Cielab<W> {
     fn from(ciexyz: Ciexyz) -> Self {
-        let delta = 6f32 / 29f32;
-        let f = |t| match t > f32::powf(delta, 3f32) {
-            true    => f32::powf(t, 1f32 / 3f32),
-            false   => t / (3f32 * f32::powf(delta, 2f32)) + 4f32 / 29f32,
-        };
-        let x_ratio = ciexyz.x() / D65.x();
-        let y_ratio = ciexyz.y() / D65.y();
-        let z_ratio = ciexyz.z() / D65.z();
-        let l = 116f32 * f(y_ratio) - 16f32;
-        let a = 500f32 * (f(x_ratio) - f(y_ratio));
-        let b = 200f32 * (f(y_ratio) - f(z_ratio));
-        Self(l, a, b)
+        from_ciexyz(ciexyz.into())
     }
 }
 
-/// Conversion from sRGB to CIEXYZ.
+/// Conversion from sRGB to CIELAB.
 ///
 /// # Examples
 ///
@@ -40,7 +76,7 @@ impl From<Ciexyz> for Cielab {
 /// use cowor::{ Cielab, Srgb };
 /// let cielab: Cielab = Srgb::new(10, 20, 30).into();
 /// ```
-impl From<Srgb> for Cielab {
+impl<W: Illuminant> From<Srgb> for Cielab<W> {
     fn from(srgb: Srgb) -> Self {
         let ciexyz: Ciexyz = srgb.into();
         Self::from(ciexyz)
@@ -55,12 +91,69 @@ impl From<Srgb> for Cielab {
 /// use cowor::{ Cielab, Cielch };
 /// let cielab: Cielab = Cielch::new(10.0, 20.0, 30.0).unwrap().into();
 /// ```
-impl From<Cielch> for Cielab {
-    fn from(cielch: Cielch) -> Self {
+impl<W: Illuminant> From<Cielch<W>> for Cielab<W> {
+    fn from(cielch: Cielch<W>) -> Self {
         let l = cielch.lightness();
         let a = cielch.chroma() * cielch.hue().cos();
         let b = cielch.chroma() * cielch.hue().sin();
-        Self(l, a, b)
+        Self(l, a, b, PhantomData)
+    }
+}
+
+/// Conversion from LMS to CIELAB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the LMS color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielab, Lms, lms::Bradford };
+/// let cielab: Cielab = Lms::<Bradford>::new(0.1, 0.2, 0.3).try_into().unwrap();
+/// ```
+impl<M: ConeFundamentals, W: Illuminant> TryFrom<Lms<M>> for Cielab<W> {
+    type Error = OutOfGamut;
+
+    fn try_from(lms: Lms<M>) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(lms)?;
+        Ok(Self::from(ciexyz))
+    }
+}
+
+/// Conversion from Oklab to CIELAB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklab color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielab, Oklab };
+/// let cielab: Cielab = Oklab::new(0.5, 0.1, -0.1).unwrap().try_into().unwrap();
+/// ```
+impl<W: Illuminant> TryFrom<Oklab> for Cielab<W> {
+    type Error = OutOfGamut;
+
+    fn try_from(oklab: Oklab) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(oklab)?;
+        Ok(Self::from(ciexyz))
+    }
+}
+
+/// Conversion from Oklch to CIELAB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklch color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielab, Oklch };
+/// let cielab: Cielab = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap().try_into().unwrap();
+/// ```
+impl<W: Illuminant> TryFrom<Oklch> for Cielab<W> {
+    type Error = OutOfGamut;
+
+    fn try_from(oklch: Oklch) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(oklch)?;
+        Ok(Self::from(ciexyz))
     }
 }
 
@@ -75,13 +168,13 @@ impl From<Cielch> for Cielab {
 /// use cowor::Cielab;
 /// let cielab: Cielab = [10.0, 20.0, 30.0].try_into().unwrap();
 /// ```
-impl TryFrom<[f32; 3]> for Cielab {
+impl<W: Illuminant> TryFrom<[f32; 3]> for Cielab<W> {
     type Error = OutOfSpecification;
 
     fn try_from(array: [f32; 3]) -> Result<Self, Self::Error> {
         let [l, a, b] = array;
-        match (0f32..100f32).contains(&l) {
-            true    => Ok(Self(l, a, b)),
+        match (0f32..=100f32).contains(&l) {
+            true    => Ok(Self(l, a, b, PhantomData)),
             false   => Err(OutOfSpecification),
         }
     }
@@ -95,9 +188,9 @@ impl TryFrom<[f32; 3]> for Cielab {
 /// use cowor::Cielab;
 /// let [x, y, z]: [f32; 3] = Cielab::new(10.0, 20.0, 30.0).unwrap().into();
 /// ```
-impl From<Cielab> for [f32; 3] {
-    fn from(cielab: Cielab) -> Self {
-        let Cielab(l, a, b) = cielab;
+impl<W> From<Cielab<W>> for [f32; 3] {
+    fn from(cielab: Cielab<W>) -> Self {
+        let Cielab(l, a, b, _) = cielab;
         [l, a, b]
     }
 }
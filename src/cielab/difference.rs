@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tests;
+
+use std::f64::consts::PI;
+use crate::illuminant::Illuminant;
+use super::Cielab;
+
+/// Weighting factors of the CIE94 color difference, which depend on the application.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Cie94Application {
+    /// Graphic arts weights (kL = 1, K1 = 0.045, K2 = 0.015).
+    GraphicArts,
+    /// Textiles weights (kL = 2, K1 = 0.048, K2 = 0.014).
+    Textiles,
+}
+
+impl<W: Illuminant> Cielab<W> {
+    /// CIE76 color difference (ΔE*ab) between two CIELAB colors,
+    /// which is their euclidean distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Cielab;
+    /// let a = Cielab::new(50.0, 0.0, 0.0).unwrap();
+    /// let b = Cielab::new(50.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(a.delta_e_76(&b), 5.0);
+    /// ```
+    pub fn delta_e_76(&self, other: &Self) -> f32 {
+        let dl = self.lightness() - other.lightness();
+        let da = self.a() - other.a();
+        let db = self.b() - other.b();
+        f32::sqrt(dl * dl + da * da + db * db)
+    }
+
+    /// CIE94 color difference (ΔE94) between a reference color (`self`) and a sample color.
+    ///
+    /// The CIE94 color difference isn't symmetric, the chroma of the reference color is used
+    /// to weight the chroma and hue differences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Cielab, cielab::Cie94Application };
+    /// let reference = Cielab::new(50.0, 2.6772, -79.7751).unwrap();
+    /// let sample = Cielab::new(50.0, 0.0, -82.7485).unwrap();
+    /// let delta_e = reference.delta_e_94(&sample, Cie94Application::GraphicArts);
+    /// assert!((delta_e - 1.3950).abs() < 1e-4);
+    /// ```
+    pub fn delta_e_94(&self, sample: &Self, application: Cie94Application) -> f32 {
+        let (kl, k1, k2) = match application {
+            Cie94Application::GraphicArts   => (1f32, 0.045f32, 0.015f32),
+            Cie94Application::Textiles      => (2f32, 0.048f32, 0.014f32),
+        };
+        let c1 = f32::hypot(self.a(), self.b());
+        let c2 = f32::hypot(sample.a(), sample.b());
+        let dl = self.lightness() - sample.lightness();
+        let dc = c1 - c2;
+        let da = self.a() - sample.a();
+        let db = self.b() - sample.b();
+        let dh_squared = (da * da + db * db - dc * dc).max(0f32);
+        let sc = 1f32 + k1 * c1;
+        let sh = 1f32 + k2 * c1;
+        f32::sqrt((dl / kl).powi(2) + (dc / sc).powi(2) + dh_squared / (sh * sh))
+    }
+
+    /// CMC l:c color difference (ΔE CMC) between a reference color (`self`) and a sample color.
+    ///
+    /// The lightness and chroma differences are respectively divided by the `lightness` (l) and
+    /// `chroma` (c) weights. CMC 2:1 is usually used for acceptability and CMC 1:1 for
+    /// perceptibility.
+    ///
+    /// The CMC l:c color difference isn't symmetric, the weights are computed from the reference
+    /// color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Cielab;
+    /// let reference = Cielab::new(50.0, 2.6772, -79.7751).unwrap();
+    /// let sample = Cielab::new(50.0, 0.0, -82.7485).unwrap();
+    /// let delta_e = reference.delta_e_cmc(&sample, 2.0, 1.0);
+    /// assert!((delta_e - 1.7387).abs() < 1e-4);
+    /// assert!((sample.delta_e_cmc(&reference, 2.0, 1.0) - 1.7014).abs() < 1e-4);
+    /// ```
+    pub fn delta_e_cmc(&self, sample: &Self, lightness: f32, chroma: f32) -> f32 {
+        let l1 = self.lightness();
+        let c1 = f32::hypot(self.a(), self.b());
+        let c2 = f32::hypot(sample.a(), sample.b());
+        let h1 = f32::atan2(self.b(), self.a()).to_degrees().rem_euclid(360f32);
+        let dl = l1 - sample.lightness();
+        let dc = c1 - c2;
+        let da = self.a() - sample.a();
+        let db = self.b() - sample.b();
+        let dh_squared = (da * da + db * db - dc * dc).max(0f32);
+        let sl = match l1 < 16f32 {
+            true    => 0.511f32,
+            false   => 0.040975f32 * l1 / (1f32 + 0.01765f32 * l1),
+        };
+        let sc = 0.0638f32 * c1 / (1f32 + 0.0131f32 * c1) + 0.638f32;
+        let f = f32::sqrt(c1.powi(4) / (c1.powi(4) + 1900f32));
+        let t = match (164f32..=345f32).contains(&h1) {
+            true    => 0.56f32 + (0.2f32 * (h1 + 168f32).to_radians().cos()).abs(),
+            false   => 0.36f32 + (0.4f32 * (h1 + 35f32).to_radians().cos()).abs(),
+        };
+        let sh = sc * (f * t + 1f32 - f);
+        f32::sqrt((dl / (lightness * sl)).powi(2) + (dc / (chroma * sc)).powi(2) + dh_squared / (sh * sh))
+    }
+
+    /// CIEDE2000 color difference (ΔE00) between two CIELAB colors.
+    ///
+    /// The lightness, chroma and hue differences are respectively divided by the parametric
+    /// weighting factors `kl`, `kc` and `kh`, which are all 1 under reference conditions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Cielab;
+    /// let a = Cielab::new(50.0, 2.6772, -79.7751).unwrap();
+    /// let b = Cielab::new(50.0, 0.0, -82.7485).unwrap();
+    /// assert!((a.delta_e_2000(&b, 1.0, 1.0, 1.0) - 2.0425).abs() < 1e-4);
+    /// ```
+    pub fn delta_e_2000(&self, other: &Self, kl: f32, kc: f32, kh: f32) -> f32 {
+        //Computed with double precision as the hue computations are very sensitive to rounding
+        //errors when comparing colors of opposite hues.
+        let [l1, a1, b1] = [self.lightness(), self.a(), self.b()].map(f64::from);
+        let [l2, a2, b2] = [other.lightness(), other.a(), other.b()].map(f64::from);
+        let [kl, kc, kh] = [kl, kc, kh].map(f64::from);
+        let pow_25_7 = 25f64.powi(7);
+
+        let c_mean = (f64::hypot(a1, b1) + f64::hypot(a2, b2)) / 2f64;
+        let g = 0.5f64 * (1f64 - f64::sqrt(c_mean.powi(7) / (c_mean.powi(7) + pow_25_7)));
+        let a1 = (1f64 + g) * a1;
+        let a2 = (1f64 + g) * a2;
+        let c1 = f64::hypot(a1, b1);
+        let c2 = f64::hypot(a2, b2);
+        let hue = |a: f64, b: f64| match a == 0f64 && b == 0f64 {
+            true    => 0f64,
+            false   => f64::atan2(b, a).rem_euclid(2f64 * PI),
+        };
+        let h1 = hue(a1, b1);
+        let h2 = hue(a2, b2);
+        //Hue differences of exactly half a turn must not be affected by rounding errors.
+        let half_turn = PI + 1e-12f64;
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = match c1 * c2 == 0f64 {
+            true    => 0f64,
+            false   => match h2 - h1 {
+                d if d.abs() <= half_turn  => d,
+                d if d > 0f64               => d - 2f64 * PI,
+                d                           => d + 2f64 * PI,
+            },
+        };
+        let dh = 2f64 * f64::sqrt(c1 * c2) * f64::sin(dh / 2f64);
+
+        let l_mean = (l1 + l2) / 2f64;
+        let c_mean = (c1 + c2) / 2f64;
+        let h_mean = match c1 * c2 == 0f64 {
+            true    => h1 + h2,
+            false   => match (h1 - h2).abs() <= half_turn {
+                true    => (h1 + h2) / 2f64,
+                false   => match h1 + h2 < 2f64 * PI {
+                    true    => (h1 + h2 + 2f64 * PI) / 2f64,
+                    false   => (h1 + h2 - 2f64 * PI) / 2f64,
+                },
+            },
+        };
+
+        let t = 1f64
+            - 0.17f64 * f64::cos(h_mean - 30f64.to_radians())
+            + 0.24f64 * f64::cos(2f64 * h_mean)
+            + 0.32f64 * f64::cos(3f64 * h_mean + 6f64.to_radians())
+            - 0.20f64 * f64::cos(4f64 * h_mean - 63f64.to_radians());
+        let d_theta = 30f64.to_radians() * f64::exp(-((h_mean.to_degrees() - 275f64) / 25f64).powi(2));
+        let rc = 2f64 * f64::sqrt(c_mean.powi(7) / (c_mean.powi(7) + pow_25_7));
+        let sl = 1f64 + 0.015f64 * (l_mean - 50f64).powi(2) / f64::sqrt(20f64 + (l_mean - 50f64).powi(2));
+        let sc = 1f64 + 0.045f64 * c_mean;
+        let sh = 1f64 + 0.015f64 * c_mean * t;
+        let rt = -f64::sin(2f64 * d_theta) * rc;
+
+        let l_term = dl / (kl * sl);
+        let c_term = dc / (kc * sc);
+        let h_term = dh / (kh * sh);
+        f64::sqrt(l_term * l_term + c_term * c_term + h_term * h_term + rt * c_term * h_term) as f32
+    }
+}
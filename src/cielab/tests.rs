@@ -2,12 +2,14 @@ use super::*;
 use crate::{
     Cielch,
     Ciexyz,
-    ciexyz::D65,
+    ciexyz,
+    illuminant::D50,
+    Srgb,
 };
 
 #[test]
 fn new() {
-    let a = Cielab(10.0, 20.0, 30.0);
+    let a = Cielab::<D65>(10.0, 20.0, 30.0, PhantomData);
     let b = Cielab::new(10.0, 20.0, 30.0).unwrap();
     let c = Cielab::new(-10.0, 20.0, 30.0);
     let d = Cielab::new(110.0, 20.0, 30.0);
@@ -18,25 +20,25 @@ fn new() {
 
 #[test]
 fn lightness() {
-    let a = Cielab(0.0, 20.0, 30.0);
+    let a = Cielab::<D65>(0.0, 20.0, 30.0, PhantomData);
     assert_eq!(a.lightness(), 0.0);
-    let b = Cielab(10.0, 20.0, 30.0);
+    let b = Cielab::<D65>(10.0, 20.0, 30.0, PhantomData);
     assert_eq!(b.lightness(), 10.0);
 }
 
 #[test]
 fn a() {
-    let a = Cielab(10.0, 0.0, 30.0);
+    let a = Cielab::<D65>(10.0, 0.0, 30.0, PhantomData);
     assert_eq!(a.a(), 0.0);
-    let b = Cielab(10.0, 20.0, 30.0);
+    let b = Cielab::<D65>(10.0, 20.0, 30.0, PhantomData);
     assert_eq!(b.a(), 20.0);
 }
 
 #[test]
 fn b() {
-    let a = Cielab(10.0, 20.0, 0.0);
+    let a = Cielab::<D65>(10.0, 20.0, 0.0, PhantomData);
     assert_eq!(a.b(), 0.0);
-    let b = Cielab(10.0, 20.0, 30.0);
+    let b = Cielab::<D65>(10.0, 20.0, 30.0, PhantomData);
     assert_eq!(b.b(), 30.0);
 }
 
@@ -56,5 +58,39 @@ fn from_cielch_precision() {
 
 #[test]
 fn from_d65() {
-    assert_eq!(Cielab::new(100f32, 0f32, 0f32).unwrap(), D65.into());
+    assert_eq!(Cielab::new(100f32, 0f32, 0f32).unwrap(), ciexyz::D65.into());
+}
+
+#[test]
+fn with_reference_white() {
+    let a = Cielab::<D50>(10.0, 20.0, 30.0, PhantomData);
+    let b = Cielab::with_reference_white(10.0, 20.0, 30.0, D50).unwrap();
+    let c = Cielab::with_reference_white(110.0, 20.0, 30.0, D50);
+    assert_eq!(a, b);
+    assert!(c.is_none());
+}
+
+#[test]
+fn from_d50() {
+    let white: Cielab<D50> = Srgb::new(255, 255, 255).into();
+    assert!((white.lightness() - 100f32).abs() < 1e-3);
+    assert!(white.a().abs() < 1e-3 && white.b().abs() < 1e-3);
+    let white: Cielab<D50> = ciexyz::D65.into();
+    assert!((white.lightness() - 100f32).abs() < 1e-3);
+    assert!(white.a().abs() < 1e-3 && white.b().abs() < 1e-3);
+    //Reference values from Bruce Lindbloom, with the Bradford transform.
+    let red: Cielab<D50> = Srgb::new(255, 0, 0).into();
+    assert!((red.lightness() - 54.29).abs() < 0.1);
+    assert!((red.a() - 80.80).abs() < 0.1);
+    assert!((red.b() - 69.89).abs() < 0.1);
+}
+
+#[test]
+fn reference_white_round_trip() {
+    let ciexyz = Ciexyz::new(0.3, 0.4, 0.5).unwrap();
+    let cielab: Cielab<D50> = ciexyz.into();
+    let [x, y, z]: [f32; 3] = Ciexyz::from(cielab).into();
+    assert!((x - 0.3).abs() < 1e-6);
+    assert!((y - 0.4).abs() < 1e-6);
+    assert!((z - 0.5).abs() < 1e-6);
 }
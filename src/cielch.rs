@@ -1,10 +1,20 @@
 mod conversions;
+mod difference;
 #[cfg(test)]
 mod tests;
 
+use std::marker::PhantomData;
+use crate::illuminant::{
+    D65,
+    Illuminant,
+};
+
 /// A struct representing a color in the CIELCh color space.
+///
+/// CIELCh is defined relatively to a reference white, given by the `W` type parameter.
+/// It is D65 by default, the reference white of sRGB.
 #[derive(Debug, Clone, Copy)]
-pub struct Cielch(f32, f32, f32); // lightness, chroma, hue
+pub struct Cielch<W = D65>(f32, f32, f32, PhantomData<W>); // lightness, chroma, hue
 
 impl Cielch {
     /// Creates a Cielch instance from 3 float
@@ -26,8 +36,32 @@ impl Cielch {
     /// assert!(invalid.is_none());
     /// ```
     pub fn new(lightness: f32, chroma: f32, hue: f32) -> Option<Self> {
+        Self::with_reference_white(lightness, chroma, hue, D65)
+    }
+}
+
+impl<W: Illuminant> Cielch<W> {
+    /// Creates a Cielch instance relative to a given reference white from 3 float
+    /// representing the lightness, chroma, and hue components.
+    ///
+    /// The lightness component must between 0 and 100 included
+    /// otherwise it doesn't represent a valid CIELCh color
+    /// and the return value is None.
+    ///
+    /// The hue component is in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Cielch, illuminant::D50 };
+    /// let valid = Cielch::with_reference_white(10.0, 20.0, 30f32.to_radians(), D50); // Ok.
+    /// let invalid = Cielch::with_reference_white(110.0, 20.0, 30f32.to_radians(), D50); // Not ok. The lightness component is greater than 100.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn with_reference_white(lightness: f32, chroma: f32, hue: f32, _reference_white: W) -> Option<Self> {
         match (0f32..=100f32).contains(&lightness) && chroma >= 0.0 {
-            true    => Some(Self(lightness, chroma, hue)),
+            true    => Some(Self(lightness, chroma, hue, PhantomData)),
             false   => None,
         }
     }
@@ -89,7 +123,7 @@ impl Cielch {
 /// assert_eq!(b, c);
 /// assert_eq!(a, a);
 /// ```
-impl PartialEq for Cielch {
+impl<W: Illuminant> PartialEq for Cielch<W> {
     fn eq(&self, other: &Self) -> bool {
         let lhs: [f32; 3] = (*self).into();
         let rhs: [f32; 3] = (*other).into();
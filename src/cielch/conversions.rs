@@ -1,8 +1,14 @@
+use std::marker::PhantomData;
 use super::Cielch;
 use crate::{
-    error::OutOfSpecification,
+    error::{ OutOfGamut, OutOfSpecification },
+    illuminant::Illuminant,
+    lms::ConeFundamentals,
+    Lms,
     Cielab,
     Ciexyz,
+    Oklab,
+    Oklch,
     Srgb,
 };
 
@@ -14,14 +20,14 @@ use crate::{
 /// use cowor::{ Cielab, Cielch };
 /// let cielab: Cielch = Cielab::new(10.0, 20.0, 30.0).unwrap().into();
 /// ```
-impl From<Cielab> for Cielch {
-    fn from(cielab: Cielab) -> Self {
+impl<W: Illuminant> From<Cielab<W>> for Cielch<W> {
+    fn from(cielab: Cielab<W>) -> Self {
         let l = cielab.lightness();
         let a = cielab.a();
         let b = cielab.b();
         let c = f32::sqrt(a * a + b * b);
         let h = f32::atan2(b, a);
-        Self(l, c, h)
+        Self(l, c, h, PhantomData)
     }
 }
 
@@ -33,9 +39,9 @@ impl From<Cielab> for Cielch {
 /// use cowor::{ Ciexyz, Cielch };
 /// let cielab: Cielch = Ciexyz::new(0.1, 0.2, 0.3).unwrap().into();
 /// ```
-impl From<Ciexyz> for Cielch {
+impl<W: Illuminant> From<Ciexyz> for Cielch<W> {
     fn from(ciexyz: Ciexyz) -> Self {
-        let cielab: Cielab = ciexyz.into();
+        let cielab: Cielab<W> = ciexyz.into();
         Self::from(cielab)
     }
 }
@@ -48,13 +54,70 @@ impl From<Ciexyz> for Cielch {
 /// use cowor::{ Srgb, Cielch };
 /// let cielab: Cielch = Srgb::new(10, 20, 30).into();
 /// ```
-impl From<Srgb> for Cielch {
+impl<W: Illuminant> From<Srgb> for Cielch<W> {
     fn from(srgb: Srgb) -> Self {
-        let cielab: Cielab = srgb.into();
+        let cielab: Cielab<W> = srgb.into();
         Self::from(cielab)
     }
 }
 
+/// Conversion from LMS to CIELCh.
+/// The conversion may fail and return an OutOfGamut error
+/// if the LMS color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielch, Lms, lms::Bradford };
+/// let cielch: Cielch = Lms::<Bradford>::new(0.1, 0.2, 0.3).try_into().unwrap();
+/// ```
+impl<M: ConeFundamentals, W: Illuminant> TryFrom<Lms<M>> for Cielch<W> {
+    type Error = OutOfGamut;
+
+    fn try_from(lms: Lms<M>) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(lms)?;
+        Ok(Self::from(ciexyz))
+    }
+}
+
+/// Conversion from Oklab to CIELCh.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklab color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielch, Oklab };
+/// let cielch: Cielch = Oklab::new(0.5, 0.1, -0.1).unwrap().try_into().unwrap();
+/// ```
+impl<W: Illuminant> TryFrom<Oklab> for Cielch<W> {
+    type Error = OutOfGamut;
+
+    fn try_from(oklab: Oklab) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(oklab)?;
+        Ok(Self::from(ciexyz))
+    }
+}
+
+/// Conversion from Oklch to CIELCh.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklch color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielch, Oklch };
+/// let cielch: Cielch = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap().try_into().unwrap();
+/// ```
+impl<W: Illuminant> TryFrom<Oklch> for Cielch<W> {
+    type Error = OutOfGamut;
+
+    fn try_from(oklch: Oklch) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(oklch)?;
+        Ok(Self::from(ciexyz))
+    }
+}
+
 /// New CIELCh color from an array of 3 floats.
 /// Convertion may fail if the resulting color would fall outside
 /// the CIELCh specification. The error type in that case is an
@@ -66,13 +129,13 @@ impl From<Srgb> for Cielch {
 /// use cowor::Cielch;
 /// let cielch: Cielch = [10.0, 20.0, 30f32.to_radians()].try_into().unwrap();
 /// ```
-impl TryFrom<[f32; 3]> for Cielch {
+impl<W: Illuminant> TryFrom<[f32; 3]> for Cielch<W> {
     type Error = OutOfSpecification;
 
     fn try_from(array: [f32; 3]) -> Result<Self, Self::Error> {
         let [l, c, h] = array;
-        match (0f32..100f32).contains(&l) && c >= 0.0 {
-            true    => Ok(Self(l, c, h)),
+        match (0f32..=100f32).contains(&l) && c >= 0.0 {
+            true    => Ok(Self(l, c, h, PhantomData)),
             false   => Err(OutOfSpecification),
         }
     }
@@ -86,9 +149,9 @@ impl TryFrom<[f32; 3]> for Cielch {
 /// use cowor::Cielch;
 /// let [x, y, z]: [f32; 3] = Cielch::new(10.0, 20.0, 30f32.to_radians()).unwrap().into();
 /// ```
-impl From<Cielch> for [f32; 3] {
-    fn from(cielch: Cielch) -> Self {
-        let Cielch(l, c, h) = cielch;
+impl<W> From<Cielch<W>> for [f32; 3] {
+    fn from(cielch: Cielch<W>) -> Self {
+        let Cielch(l, c, h, _) = cielch;
         [l, c, h]
     }
 }
@@ -0,0 +1,32 @@
+use crate::{
+    illuminant::Illuminant,
+    Cielab,
+};
+use super::Cielch;
+
+impl<W: Illuminant> Cielch<W> {
+    /// CMC l:c color difference (ΔE CMC) between a reference color (`self`) and a sample color.
+    ///
+    /// The lightness and chroma differences are respectively divided by the `lightness` (l) and
+    /// `chroma` (c) weights. CMC 2:1 is usually used for acceptability and CMC 1:1 for
+    /// perceptibility.
+    ///
+    /// The CMC l:c color difference isn't symmetric, the weights are computed from the reference
+    /// color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Cielch;
+    /// let reference = Cielch::new(50.0, 30.0, 20f32.to_radians()).unwrap();
+    /// let sample = Cielch::new(52.0, 28.0, 25f32.to_radians()).unwrap();
+    /// let acceptability = reference.delta_e_cmc(&sample, 2.0, 1.0);
+    /// let perceptibility = reference.delta_e_cmc(&sample, 1.0, 1.0);
+    /// assert!(acceptability < perceptibility);
+    /// ```
+    pub fn delta_e_cmc(&self, sample: &Self, lightness: f32, chroma: f32) -> f32 {
+        let reference: Cielab<W> = (*self).into();
+        let sample: Cielab<W> = (*sample).into();
+        reference.delta_e_cmc(&sample, lightness, chroma)
+    }
+}
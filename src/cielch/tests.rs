@@ -1,9 +1,12 @@
 use super::*;
-use crate::Cielab;
+use crate::{
+    Cielab,
+    illuminant::D50,
+};
 
 #[test]
 fn new() {
-    let a = Cielch(10.0, 20.0, 30f32.to_radians());
+    let a = Cielch::<D65>(10.0, 20.0, 30f32.to_radians(), PhantomData);
     let b = Cielch::new(10.0, 20.0, 30f32.to_radians()).unwrap();
     let c = Cielch::new(-10.0, 20.0, 30f32.to_radians());
     let d = Cielch::new(110.0, 20.0, 30f32.to_radians());
@@ -14,25 +17,25 @@ fn new() {
 
 #[test]
 fn lightness() {
-    let a = Cielch(0.0, 20.0, 30f32.to_radians());
+    let a = Cielch::<D65>(0.0, 20.0, 30f32.to_radians(), PhantomData);
     assert_eq!(a.lightness(), 0.0);
-    let b = Cielch(10.0, 20.0, 30f32.to_radians());
+    let b = Cielch::<D65>(10.0, 20.0, 30f32.to_radians(), PhantomData);
     assert_eq!(b.lightness(), 10.0);
 }
 
 #[test]
 fn chroma() {
-    let a = Cielch(10.0, 0.0, 30f32.to_radians());
+    let a = Cielch::<D65>(10.0, 0.0, 30f32.to_radians(), PhantomData);
     assert_eq!(a.chroma(), 0.0);
-    let b = Cielch(10.0, 20.0, 30f32.to_radians());
+    let b = Cielch::<D65>(10.0, 20.0, 30f32.to_radians(), PhantomData);
     assert_eq!(b.chroma(), 20.0);
 }
 
 #[test]
 fn hue() {
-    let a = Cielch(10.0, 20.0, 0f32.to_radians());
+    let a = Cielch::<D65>(10.0, 20.0, 0f32.to_radians(), PhantomData);
     assert_eq!(a.hue(), 0f32.to_radians());
-    let b = Cielch(10.0, 20.0, 30f32.to_radians());
+    let b = Cielch::<D65>(10.0, 20.0, 30f32.to_radians(), PhantomData);
     assert_eq!(b.hue(), 30f32.to_radians());
 }
 
@@ -42,3 +45,20 @@ fn from_cielab_precision() {
     let cielab_black = Cielab::new(0f32, 0f32, 0f32).unwrap();
     assert_eq!(cielch_black, cielab_black.into());
 }
+
+#[test]
+fn with_reference_white() {
+    let a = Cielch::<D50>(10.0, 20.0, 30f32.to_radians(), PhantomData);
+    let b = Cielch::with_reference_white(10.0, 20.0, 30f32.to_radians(), D50).unwrap();
+    let c = Cielch::with_reference_white(110.0, 20.0, 30f32.to_radians(), D50);
+    assert_eq!(a, b);
+    assert!(c.is_none());
+}
+
+#[test]
+fn delta_e_cmc() {
+    let reference: Cielch = Cielab::new(60.2574, -34.0099, 36.2677).unwrap().into();
+    let sample: Cielch = Cielab::new(60.4626, -34.1751, 39.4387).unwrap().into();
+    assert!((reference.delta_e_cmc(&sample, 2.0, 1.0) - 1.4205).abs() < 1e-3);
+    assert!((reference.delta_e_cmc(&sample, 1.0, 1.0) - 1.4282).abs() < 1e-3);
+}
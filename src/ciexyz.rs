@@ -2,7 +2,7 @@ mod conversions;
 #[cfg(test)]
 mod tests;
 
-use conversions::FROM_SRGB_CONVERSION_MATRIX;
+pub(crate) use conversions::FROM_SRGB_CONVERSION_MATRIX;
 
 /// A struct representing a color in the CIEXYZ color space.
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -17,6 +17,37 @@ pub const D65: Ciexyz = {
     Ciexyz(x, y, z)
 };
 
+/// A (incandescent / tungsten) white point.
+pub const A: Ciexyz = Ciexyz(1.09850f32, 1f32, 0.35585f32);
+
+/// C (average / north sky daylight) white point.
+pub const C: Ciexyz = Ciexyz(0.98074f32, 1f32, 1.18232f32);
+
+/// D50 (horizon light) white point, the reference white of ICC profiles and of CSS, of
+/// chromaticity (0.3457, 0.3585). Equivalent to Ciexyz(0.9642957f32, 1f32, 0.8251046f32);
+pub const D50: Ciexyz = {
+    let [x, y] = [0.3457f32, 0.3585f32];
+    Ciexyz(x / y, 1f32, (1f32 - x - y) / y)
+};
+
+/// D55 (mid-morning / mid-afternoon daylight) white point.
+pub const D55: Ciexyz = Ciexyz(0.95682f32, 1f32, 0.92149f32);
+
+/// D75 (north sky daylight) white point.
+pub const D75: Ciexyz = Ciexyz(0.94972f32, 1f32, 1.22638f32);
+
+/// E (equal energy) white point.
+pub const E: Ciexyz = Ciexyz(1f32, 1f32, 1f32);
+
+/// F2 (cool white fluorescent) white point.
+pub const F2: Ciexyz = Ciexyz(0.99187f32, 1f32, 0.67395f32);
+
+/// F7 (broadband daylight fluorescent) white point.
+pub const F7: Ciexyz = Ciexyz(0.95044f32, 1f32, 1.08755f32);
+
+/// F11 (narrow tri-band fluorescent) white point.
+pub const F11: Ciexyz = Ciexyz(1.00966f32, 1f32, 0.64370f32);
+
 impl Ciexyz {
     /// Creates a Ciexyz instance from 3 float
     /// representing the X, Y, and Z components.
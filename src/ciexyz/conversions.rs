@@ -1,16 +1,21 @@
 use crate::{
-    error::OutOfSpecification,
+    cielab,
+    error::{ OutOfGamut, OutOfSpecification },
+    illuminant::Illuminant,
+    lms::ConeFundamentals,
+    matrix,
     Cielab,
     Cielch,
+    oklab,
+    Lms,
+    Oklab,
+    Oklch,
     Srgb
 };
-use super::{
-    Ciexyz,
-    D65,
-};
+use super::Ciexyz;
 
 //Conversion matrix to convert from sRGB to CIEXYZ.
-pub(super) const FROM_SRGB_CONVERSION_MATRIX: [f32; 9] = {
+pub(crate) const FROM_SRGB_CONVERSION_MATRIX: [f32; 9] = {
     let xr = 506752f32 / 1228815f32;
     let xg = 87881f32 / 245763f32;
     let xb = 12673f32 / 70218f32;
@@ -45,25 +50,22 @@ impl From<Srgb> for Ciexyz {
 }
 
 /// Conversion from CIELAB to CIEXYZ.
+/// The resulting CIEXYZ color is adapted from the reference white of the CIELAB color to D65
+/// with the Bradford chromatic adaptation transform. CIELAB colors which don't correspond to
+/// a valid CIEXYZ color are clamped to the CIEXYZ specification.
 ///
 /// # Examples
 ///
 /// ```
 /// use cowor::{ Cielab, Ciexyz };
 /// let ciexyz: Ciexyz = Cielab::new(10.0, 20.0, 30.0).unwrap().into();
+/// let clamped: Ciexyz = Cielab::new(100.0, -100.0, 0.0).unwrap().into();
+/// assert!(clamped.x() >= 0.0 && clamped.y() <= 1.0);
 /// ```
-impl From<Cielab> for Ciexyz {
-    fn from(cielab: Cielab) -> Self {
-        let delta = 6f32 / 29f32;
-        let f = |t| match t > delta {
-            true    => f32::powf(t, 3f32),
-            false   => 3f32 * f32::powf(delta, 2f32) * (t - 4f32 / 29f32),
-        };
-        let p = (cielab.lightness() + 16f32) / 116f32;
-        let x = D65.x() * f(p + cielab.a() / 500f32);
-        let y = D65.y() * f(p);
-        let z = D65.z() * f(p - cielab.b() / 200f32);
-        Self(x, y, z)
+impl<W: Illuminant> From<Cielab<W>> for Ciexyz {
+    fn from(cielab: Cielab<W>) -> Self {
+        let [x, y, z] = cielab::to_ciexyz(cielab);
+        Self(x.max(0f32), y.clamp(0f32, 1f32), z.max(0f32))
     }
 }
 
@@ -75,13 +77,88 @@ impl From<Cielab> for Ciexyz {
 /// use cowor::{ Cielch, Ciexyz };
 /// let ciexyz: Ciexyz = Cielch::new(10.0, 20.0, 30f32.to_radians()).unwrap().into();
 /// ```
-impl From<Cielch> for Ciexyz {
-    fn from(cielch: Cielch) -> Self {
-        let cielab: Cielab = cielch.into();
+impl<W: Illuminant> From<Cielch<W>> for Ciexyz {
+    fn from(cielch: Cielch<W>) -> Self {
+        let cielab: Cielab<W> = cielch.into();
         Self::from(cielab)
     }
 }
 
+/// Conversion from LMS to CIEXYZ.
+/// The conversion may fail and return an OutOfGamut error
+/// if the LMS color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Ciexyz, Lms, lms::Bradford };
+/// let valid = Lms::<Bradford>::new(0.5, 0.5, 0.5); // Corresponds to a valid CIEXYZ color.
+/// let invalid = Lms::<Bradford>::new(-0.5, 0.5, 0.5); // Doesn't correspond to a valid CIEXYZ color.
+/// assert!(Ciexyz::try_from(valid).is_ok());
+/// assert!(Ciexyz::try_from(invalid).is_err());
+/// ```
+impl<M: ConeFundamentals> TryFrom<Lms<M>> for Ciexyz {
+    type Error = OutOfGamut;
+
+    fn try_from(lms: Lms<M>) -> Result<Self, Self::Error> {
+        let [x, y, z] = matrix::transform(M::TO_CIEXYZ, lms.into());
+        //Rounding errors can push colors on the edge of the specification slightly outside of it.
+        let tolerance = 1e-6f32;
+        match x >= -tolerance && (-tolerance..=1f32 + tolerance).contains(&y) && z >= -tolerance {
+            true    => Ok(Self(x.max(0f32), y.clamp(0f32, 1f32), z.max(0f32))),
+            false   => Err(OutOfGamut),
+        }
+    }
+}
+
+/// Conversion from Oklab to CIEXYZ.
+/// The resulting CIEXYZ color is relative to D65.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklab color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Ciexyz, Oklab };
+/// let valid = Oklab::new(0.5, 0.1, -0.1).unwrap(); // Corresponds to a valid CIEXYZ color.
+/// let invalid = Oklab::new(0.5, -0.5, 0.5).unwrap(); // Doesn't correspond to a valid CIEXYZ color.
+/// assert!(Ciexyz::try_from(valid).is_ok());
+/// assert!(Ciexyz::try_from(invalid).is_err());
+/// ```
+impl TryFrom<Oklab> for Ciexyz {
+    type Error = OutOfGamut;
+
+    fn try_from(oklab: Oklab) -> Result<Self, Self::Error> {
+        let [x, y, z] = oklab::to_ciexyz(oklab);
+        //Rounding errors can push colors on the edge of the specification slightly outside of it.
+        let tolerance = 1e-6f32;
+        match x >= -tolerance && (-tolerance..=1f32 + tolerance).contains(&y) && z >= -tolerance {
+            true    => Ok(Self(x.max(0f32), y.clamp(0f32, 1f32), z.max(0f32))),
+            false   => Err(OutOfGamut),
+        }
+    }
+}
+
+/// Conversion from Oklch to CIEXYZ.
+/// The resulting CIEXYZ color is relative to D65.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklch color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Ciexyz, Oklch };
+/// let ciexyz = Ciexyz::try_from(Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap());
+/// assert!(ciexyz.is_ok());
+/// ```
+impl TryFrom<Oklch> for Ciexyz {
+    type Error = OutOfGamut;
+
+    fn try_from(oklch: Oklch) -> Result<Self, Self::Error> {
+        Self::try_from(Oklab::from(oklch))
+    }
+}
+
 /// New CIEXYZ color from an array of 3 floats.
 /// Convertion may fail if the resulting color would fall outside
 /// the CIEXYZ specification. The error type in that case is an
@@ -98,7 +175,7 @@ impl TryFrom<[f32; 3]> for Ciexyz {
 
     fn try_from(array: [f32; 3]) -> Result<Self, Self::Error> {
         let [x, y, z] = array;
-        match x > 0.0 && (0f32..1f32).contains(&y) && z > 0.0 {
+        match x >= 0f32 && (0f32..=1f32).contains(&y) && z >= 0f32 {
             true    => Ok(Self(x, y, z)),
             false   => Err(OutOfSpecification),
         }
@@ -1,4 +1,8 @@
 use crate::{
+    illuminant::D50,
+    interpolation::Mix,
+    matrix,
+    Alpha,
     Cielab,
     Srgb,
 };
@@ -53,7 +57,23 @@ fn from_cielab_precision() {
     assert_eq!(ciexyz_black, cielab_black.into());
 }
 
+#[test]
+fn from_cielab_outside_of_specification() {
+    //Adapting this color from D50 to D65 gives a negative X and a Y greater than 1.
+    let ciexyz = Ciexyz::from(Cielab::with_reference_white(100f32, -100f32, 0f32, D50).unwrap());
+    assert!(Ciexyz::new(ciexyz.x(), ciexyz.y(), ciexyz.z()).is_some());
+    let black = Ciexyz::new(0f32, 0f32, 0f32).unwrap();
+    let mixed = ciexyz.mix(&black, 0.5);
+    assert_eq!(Alpha::opaque(ciexyz).mix(&Alpha::opaque(black), 0.5).color(), mixed);
+}
+
 #[test]
 fn d65() {
     assert_eq!(D65, Ciexyz::from(Srgb::new(255, 255, 255)));
 }
+
+#[test]
+fn from_srgb_conversion_matrix() {
+    let derived = matrix::rgb_to_ciexyz([0.64, 0.33], [0.30, 0.60], [0.15, 0.06], [0.3127, 0.3290]).unwrap();
+    assert!((0..9).all(|i| (derived[i] - FROM_SRGB_CONVERSION_MATRIX[i]).abs() < 1e-6));
+}
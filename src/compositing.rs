@@ -0,0 +1,174 @@
+//! Compositing and blending of sRGB colors with alpha channels, following the W3C Compositing
+//! and Blending specification.
+
+mod blending;
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    srgb::{
+        gamma_compress,
+        gamma_expand,
+    },
+    Alpha,
+    Srgb,
+    Srgba,
+};
+
+pub use blending::BlendMode;
+
+/// The Porter-Duff compositing operators, which decide how much of the source and of the
+/// destination (the backdrop) are kept where they overlap.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Operator {
+    /// Neither the source nor the destination is kept.
+    Clear,
+    /// Only the source is kept.
+    Copy,
+    /// Only the destination is kept.
+    Destination,
+    /// The source is placed over the destination.
+    #[default]
+    SourceOver,
+    /// The destination is placed over the source.
+    DestinationOver,
+    /// The source is kept where it overlaps the destination.
+    SourceIn,
+    /// The destination is kept where it overlaps the source.
+    DestinationIn,
+    /// The source is kept where it doesn't overlap the destination.
+    SourceOut,
+    /// The destination is kept where it doesn't overlap the source.
+    DestinationOut,
+    /// The source is kept where it overlaps the destination, and the destination elsewhere.
+    SourceAtop,
+    /// The destination is kept where it overlaps the source, and the source elsewhere.
+    DestinationAtop,
+    /// The source and the destination are kept where they don't overlap.
+    Xor,
+}
+
+/// The color components colors are composited on.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum CompositingSpace {
+    /// Linear sRGB components, the physically correct way of compositing colors.
+    #[default]
+    Linear,
+    /// Gamma compressed sRGB components, as done by most legacy renderers and by CSS.
+    Gamma,
+}
+
+impl CompositingSpace {
+    //Components of an sRGB color in this space.
+    fn components(&self, srgb: Srgb) -> [f32; 3] {
+        let components: [f32; 3] = srgb.into();
+        match self {
+            CompositingSpace::Linear    => components.map(gamma_expand),
+            CompositingSpace::Gamma     => components,
+        }
+    }
+
+    //sRGB color from its components in this space.
+    fn srgb(&self, components: [f32; 3]) -> Srgb {
+        let components = components.map(|x| x.clamp(0f32, 1f32));
+        match self {
+            CompositingSpace::Linear    => components.map(gamma_compress).into(),
+            CompositingSpace::Gamma     => components.into(),
+        }
+    }
+}
+
+impl Operator {
+    /// All the Porter-Duff operators.
+    pub const ALL: [Operator; 12] = [
+        Operator::Clear,
+        Operator::Copy,
+        Operator::Destination,
+        Operator::SourceOver,
+        Operator::DestinationOver,
+        Operator::SourceIn,
+        Operator::DestinationIn,
+        Operator::SourceOut,
+        Operator::DestinationOut,
+        Operator::SourceAtop,
+        Operator::DestinationAtop,
+        Operator::Xor,
+    ];
+
+    /// Fractions of the source and of the destination kept by the operator, given their alphas.
+    /// These are the Fa and Fb factors of the Porter-Duff equations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::compositing::Operator;
+    /// assert_eq!(Operator::SourceOver.fractions(0.25, 1.0), (1.0, 0.75));
+    /// assert_eq!(Operator::Xor.fractions(0.25, 0.5), (0.5, 0.75));
+    /// ```
+    pub fn fractions(&self, source_alpha: f32, destination_alpha: f32) -> (f32, f32) {
+        let (a_s, a_b) = (source_alpha, destination_alpha);
+        match self {
+            Operator::Clear             => (0f32, 0f32),
+            Operator::Copy              => (1f32, 0f32),
+            Operator::Destination       => (0f32, 1f32),
+            Operator::SourceOver        => (1f32, 1f32 - a_s),
+            Operator::DestinationOver   => (1f32 - a_b, 1f32),
+            Operator::SourceIn          => (a_b, 0f32),
+            Operator::DestinationIn     => (0f32, a_s),
+            Operator::SourceOut         => (1f32 - a_b, 0f32),
+            Operator::DestinationOut    => (0f32, 1f32 - a_s),
+            Operator::SourceAtop        => (a_b, 1f32 - a_s),
+            Operator::DestinationAtop   => (1f32 - a_b, a_s),
+            Operator::Xor               => (1f32 - a_b, 1f32 - a_s),
+        }
+    }
+
+    /// Composites a source color onto a destination color.
+    ///
+    /// The colors are composited on their premultiplied components in the given space.
+    /// A fully transparent result is transparent black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ compositing::{ CompositingSpace, Operator }, Alpha, Srgb };
+    /// let source = Alpha::new(Srgb::new(255, 0, 0), 0.5).unwrap();
+    /// let destination = Alpha::opaque(Srgb::new(0, 0, 255));
+    /// let linear = Operator::SourceOver.composite(source, destination, CompositingSpace::Linear);
+    /// let gamma = Operator::SourceOver.composite(source, destination, CompositingSpace::Gamma);
+    /// assert_eq!(linear.color(), Srgb::new(188, 0, 188));
+    /// assert_eq!(gamma.color(), Srgb::new(128, 0, 128));
+    /// assert_eq!(linear.alpha(), 1.0);
+    /// ```
+    pub fn composite(&self, source: Srgba, destination: Srgba, space: CompositingSpace) -> Srgba {
+        let (a_s, a_b) = (source.alpha(), destination.alpha());
+        let (f_a, f_b) = self.fractions(a_s, a_b);
+        let c_s = space.components(source.color());
+        let c_b = space.components(destination.color());
+        let alpha = (a_s * f_a + a_b * f_b).clamp(0f32, 1f32);
+        let components = match alpha == 0f32 {
+            true    => [0f32; 3],
+            false   => [0, 1, 2].map(|i| (a_s * f_a * c_s[i] + a_b * f_b * c_b[i]) / alpha),
+        };
+        Alpha::new(space.srgb(components), alpha).unwrap()
+    }
+}
+
+/// Composites a layer of source pixels onto a layer of destination pixels, replacing the
+/// destination pixels with the result. Extra pixels of the longer layer are left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ compositing::{ composite_pixels, CompositingSpace, Operator }, Srgba };
+/// let source: Vec<Srgba> = vec![[255, 0, 0, 255].into(), [0, 0, 0, 0].into()];
+/// let mut destination: Vec<Srgba> = vec![[0, 0, 255, 255].into(); 2];
+/// composite_pixels(Operator::SourceOver, &source, &mut destination, CompositingSpace::Linear);
+/// assert_eq!(<[u8; 4]>::from(destination[0]), [255, 0, 0, 255]);
+/// assert_eq!(<[u8; 4]>::from(destination[1]), [0, 0, 255, 255]);
+/// ```
+pub fn composite_pixels<P: Copy + Into<Srgba> + From<Srgba>>(operator: Operator, source: &[P], destination: &mut [P], space: CompositingSpace) {
+    for (source, destination) in source.iter().zip(destination.iter_mut()) {
+        *destination = operator.composite((*source).into(), (*destination).into(), space).into();
+    }
+}
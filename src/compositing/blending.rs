@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    Alpha,
+    Srgb,
+    Srgba,
+};
+use super::{
+    CompositingSpace,
+    Operator,
+};
+
+/// The blend modes, which mix the color of a source with the color of the backdrop it is
+/// placed onto. Separable blend modes mix each component on its own, non-separable blend modes
+/// (`Hue`, `Saturation`, `Color` and `Luminosity`) mix the components together.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum BlendMode {
+    /// The source color.
+    #[default]
+    Normal,
+    /// The product of the colors, always darker.
+    Multiply,
+    /// The complement of the product of the complements of the colors, always lighter.
+    Screen,
+    /// Multiplies or screens the colors depending on the backdrop.
+    Overlay,
+    /// The darker of the colors, for each component.
+    Darken,
+    /// The lighter of the colors, for each component.
+    Lighten,
+    /// Brightens the backdrop to reflect the source.
+    ColorDodge,
+    /// Darkens the backdrop to reflect the source.
+    ColorBurn,
+    /// Multiplies or screens the colors depending on the source.
+    HardLight,
+    /// Darkens or lightens the backdrop depending on the source, a softer version of `HardLight`.
+    SoftLight,
+    /// The absolute difference of the colors.
+    Difference,
+    /// Similar to `Difference` with a lower contrast.
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the backdrop.
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the backdrop.
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the backdrop.
+    Color,
+    /// The luminosity of the source with the hue and saturation of the backdrop.
+    Luminosity,
+}
+
+impl BlendMode {
+    /// All the blend modes.
+    pub const ALL: [BlendMode; 16] = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Overlay,
+        BlendMode::Darken,
+        BlendMode::Lighten,
+        BlendMode::ColorDodge,
+        BlendMode::ColorBurn,
+        BlendMode::HardLight,
+        BlendMode::SoftLight,
+        BlendMode::Difference,
+        BlendMode::Exclusion,
+        BlendMode::Hue,
+        BlendMode::Saturation,
+        BlendMode::Color,
+        BlendMode::Luminosity,
+    ];
+
+    /// Mixes the color of a source with the color of its backdrop, ignoring their alphas.
+    /// This is the B(Cb, Cs) blending function of the W3C specification, on the components
+    /// of the colors in the given space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ compositing::{ BlendMode, CompositingSpace }, Srgb };
+    /// let source = Srgb::new(255, 128, 0);
+    /// let backdrop = Srgb::new(128, 128, 128);
+    /// let multiplied = BlendMode::Multiply.blend(source, backdrop, CompositingSpace::Gamma);
+    /// assert_eq!(multiplied, Srgb::new(128, 64, 0));
+    /// ```
+    pub fn blend(&self, source: Srgb, backdrop: Srgb, space: CompositingSpace) -> Srgb {
+        space.srgb(self.blend_components(space.components(source), space.components(backdrop)))
+    }
+
+    /// Blends a source color onto a backdrop color, then composites the result over the backdrop.
+    ///
+    /// Where the backdrop is transparent the source keeps its own color. Blending on gamma
+    /// compressed components reproduces CSS `mix-blend-mode` and the layer blends of image
+    /// editors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ compositing::{ BlendMode, CompositingSpace }, Alpha, Srgb };
+    /// let source = Alpha::new(Srgb::new(255, 255, 255), 0.5).unwrap();
+    /// let backdrop = Alpha::opaque(Srgb::new(255, 0, 0));
+    /// let screened = BlendMode::Screen.composite(source, backdrop, CompositingSpace::Gamma);
+    /// assert_eq!(screened.color(), Srgb::new(255, 128, 128));
+    /// assert_eq!(screened.alpha(), 1.0);
+    /// ```
+    pub fn composite(&self, source: Srgba, backdrop: Srgba, space: CompositingSpace) -> Srgba {
+        let c_s = space.components(source.color());
+        let c_b = space.components(backdrop.color());
+        let blended = self.blend_components(c_s, c_b);
+        let a_b = backdrop.alpha();
+        let mixed = [0, 1, 2].map(|i| (1f32 - a_b) * c_s[i] + a_b * blended[i]);
+        let source = Alpha::new(space.srgb(mixed), source.alpha()).unwrap();
+        Operator::SourceOver.composite(source, backdrop, space)
+    }
+
+    //Blending function on the components of the source and of the backdrop.
+    fn blend_components(&self, source: [f32; 3], backdrop: [f32; 3]) -> [f32; 3] {
+        let separable = |function: fn(f32, f32) -> f32| [0, 1, 2].map(|i| function(source[i], backdrop[i]));
+        match self {
+            BlendMode::Normal       => source,
+            BlendMode::Multiply     => separable(multiply),
+            BlendMode::Screen       => separable(screen),
+            BlendMode::Overlay      => separable(|s, b| hard_light(b, s)),
+            BlendMode::Darken       => separable(f32::min),
+            BlendMode::Lighten      => separable(f32::max),
+            BlendMode::ColorDodge   => separable(color_dodge),
+            BlendMode::ColorBurn    => separable(color_burn),
+            BlendMode::HardLight    => separable(hard_light),
+            BlendMode::SoftLight    => separable(soft_light),
+            BlendMode::Difference   => separable(|s, b| (b - s).abs()),
+            BlendMode::Exclusion    => separable(|s, b| b + s - 2f32 * b * s),
+            BlendMode::Hue          => set_luminosity(set_saturation(source, saturation(backdrop)), luminosity(backdrop)),
+            BlendMode::Saturation   => set_luminosity(set_saturation(backdrop, saturation(source)), luminosity(backdrop)),
+            BlendMode::Color        => set_luminosity(source, luminosity(backdrop)),
+            BlendMode::Luminosity   => set_luminosity(backdrop, luminosity(source)),
+        }
+    }
+}
+
+//The separable blending functions take the source component first and the backdrop component
+//second.
+
+fn multiply(source: f32, backdrop: f32) -> f32 {
+    source * backdrop
+}
+
+fn screen(source: f32, backdrop: f32) -> f32 {
+    source + backdrop - source * backdrop
+}
+
+fn color_dodge(source: f32, backdrop: f32) -> f32 {
+    match (backdrop, source) {
+        (0f32, _)   => 0f32,
+        (_, 1f32)   => 1f32,
+        (b, s)      => (b / (1f32 - s)).min(1f32),
+    }
+}
+
+fn color_burn(source: f32, backdrop: f32) -> f32 {
+    match (backdrop, source) {
+        (1f32, _)   => 1f32,
+        (_, 0f32)   => 0f32,
+        (b, s)      => 1f32 - ((1f32 - b) / s).min(1f32),
+    }
+}
+
+fn hard_light(source: f32, backdrop: f32) -> f32 {
+    match source <= 0.5f32 {
+        true    => multiply(2f32 * source, backdrop),
+        false   => screen(2f32 * source - 1f32, backdrop),
+    }
+}
+
+fn soft_light(source: f32, backdrop: f32) -> f32 {
+    let b = backdrop;
+    match source <= 0.5f32 {
+        true    => b - (1f32 - 2f32 * source) * b * (1f32 - b),
+        false   => {
+            let d = match b <= 0.25f32 {
+                true    => ((16f32 * b - 12f32) * b + 4f32) * b,
+                false   => b.sqrt(),
+            };
+            b + (2f32 * source - 1f32) * (d - b)
+        },
+    }
+}
+
+//Luminosity of a color as defined for the non-separable blend modes.
+fn luminosity(color: [f32; 3]) -> f32 {
+    let [r, g, b] = color;
+    0.3f32 * r + 0.59f32 * g + 0.11f32 * b
+}
+
+//Brings the components of a color back into [0, 1] while keeping its luminosity.
+fn clip_color(color: [f32; 3]) -> [f32; 3] {
+    let l = luminosity(color);
+    let min = color.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = color.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let color = match min < 0f32 {
+        true    => color.map(|x| l + (x - l) * l / (l - min)),
+        false   => color,
+    };
+    match max > 1f32 {
+        true    => color.map(|x| l + (x - l) * (1f32 - l) / (max - l)),
+        false   => color,
+    }
+}
+
+fn set_luminosity(color: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - luminosity(color);
+    clip_color(color.map(|x| x + d))
+}
+
+fn saturation(color: [f32; 3]) -> f32 {
+    let min = color.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = color.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    max - min
+}
+
+fn set_saturation(color: [f32; 3], s: f32) -> [f32; 3] {
+    let min = color.iter().copied().fold(f32::INFINITY, f32::min);
+    let range = saturation(color);
+    match range > 0f32 {
+        true    => color.map(|x| (x - min) * s / range),
+        false   => [0f32; 3],
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+fn blend(mode: BlendMode, source: [u8; 3], backdrop: [u8; 3]) -> [u8; 3] {
+    mode.blend(source.into(), backdrop.into(), CompositingSpace::Gamma).into()
+}
+
+#[test]
+fn separable_blend_modes() {
+    let source = [255, 128, 0];
+    let backdrop = [100, 100, 100];
+    let expected = [
+        (BlendMode::Normal, [255, 128, 0]),
+        (BlendMode::Multiply, [100, 50, 0]),
+        (BlendMode::Screen, [255, 178, 100]),
+        (BlendMode::Overlay, [200, 100, 0]),
+        (BlendMode::Darken, [100, 100, 0]),
+        (BlendMode::Lighten, [255, 128, 100]),
+        (BlendMode::ColorDodge, [255, 201, 100]),
+        (BlendMode::ColorBurn, [100, 0, 0]),
+        (BlendMode::HardLight, [255, 101, 0]),
+        (BlendMode::SoftLight, [160, 100, 39]),
+        (BlendMode::Difference, [155, 28, 100]),
+        (BlendMode::Exclusion, [155, 128, 100]),
+    ];
+    for (mode, expected) in expected {
+        assert_eq!(blend(mode, source, backdrop), expected, "{:?}", mode);
+    }
+}
+
+#[test]
+fn neutral_sources() {
+    let backdrop = [30, 140, 220];
+    assert_eq!(blend(BlendMode::Multiply, [255, 255, 255], backdrop), backdrop);
+    assert_eq!(blend(BlendMode::Screen, [0, 0, 0], backdrop), backdrop);
+    assert_eq!(blend(BlendMode::ColorDodge, [0, 0, 0], backdrop), backdrop);
+    assert_eq!(blend(BlendMode::ColorBurn, [255, 255, 255], backdrop), backdrop);
+    assert_eq!(blend(BlendMode::Difference, [0, 0, 0], backdrop), backdrop);
+    assert_eq!(blend(BlendMode::Difference, [255, 255, 255], backdrop), [225, 115, 35]);
+    assert_eq!(blend(BlendMode::Exclusion, [0, 0, 0], backdrop), backdrop);
+}
+
+#[test]
+fn non_separable_blend_modes() {
+    let red = [255, 0, 0];
+    let gray = [128, 128, 128];
+    assert_eq!(blend(BlendMode::Color, red, gray), [255, 74, 74]);
+    assert_eq!(blend(BlendMode::Hue, red, gray), gray);
+    assert_eq!(blend(BlendMode::Saturation, gray, red), [77, 77, 77]);
+    assert_eq!(blend(BlendMode::Luminosity, gray, gray), gray);
+    assert_eq!(blend(BlendMode::Luminosity, [0, 0, 0], red), [0, 0, 0]);
+    //The luminosity of the backdrop is kept by the hue, saturation and color blend modes.
+    let backdrop = [40, 180, 90];
+    for mode in [BlendMode::Hue, BlendMode::Saturation, BlendMode::Color] {
+        let blended = blend(mode, [200, 30, 160], backdrop).map(|x| x as f32 / 255f32);
+        let expected = luminosity(backdrop.map(|x| x as f32 / 255f32));
+        assert!((luminosity(blended) - expected).abs() < 0.01, "{:?}", mode);
+    }
+}
+
+#[test]
+fn composite() {
+    let source = Alpha::new(Srgb::new(255, 128, 0), 0.6).unwrap();
+    let backdrop = Alpha::opaque(Srgb::new(100, 100, 100));
+    for space in [CompositingSpace::Linear, CompositingSpace::Gamma] {
+        let normal = BlendMode::Normal.composite(source, backdrop, space);
+        assert_eq!(normal, Operator::SourceOver.composite(source, backdrop, space));
+    }
+    let multiplied = BlendMode::Multiply.composite(source, backdrop, CompositingSpace::Gamma);
+    assert_eq!(multiplied.color(), Srgb::new(100, 70, 40));
+    assert_eq!(multiplied.alpha(), 1.0);
+
+    //A transparent backdrop leaves the source as it is.
+    let transparent = Alpha::new(Srgb::new(100, 100, 100), 0.0).unwrap();
+    for mode in BlendMode::ALL {
+        let composited = mode.composite(source, transparent, CompositingSpace::Gamma);
+        assert_eq!(composited.color(), Srgb::new(255, 128, 0), "{:?}", mode);
+        assert_eq!(composited.alpha(), 0.6);
+    }
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+fn srgba(rgba: [u8; 4]) -> Srgba {
+    rgba.into()
+}
+
+#[test]
+fn opaque_operators() {
+    let source = srgba([255, 0, 0, 255]);
+    let destination = srgba([0, 0, 255, 255]);
+    let expected = [
+        (Operator::Clear, [0, 0, 0, 0]),
+        (Operator::Copy, [255, 0, 0, 255]),
+        (Operator::Destination, [0, 0, 255, 255]),
+        (Operator::SourceOver, [255, 0, 0, 255]),
+        (Operator::DestinationOver, [0, 0, 255, 255]),
+        (Operator::SourceIn, [255, 0, 0, 255]),
+        (Operator::DestinationIn, [0, 0, 255, 255]),
+        (Operator::SourceOut, [0, 0, 0, 0]),
+        (Operator::DestinationOut, [0, 0, 0, 0]),
+        (Operator::SourceAtop, [255, 0, 0, 255]),
+        (Operator::DestinationAtop, [0, 0, 255, 255]),
+        (Operator::Xor, [0, 0, 0, 0]),
+    ];
+    for (operator, expected) in expected {
+        for space in [CompositingSpace::Linear, CompositingSpace::Gamma] {
+            let composited = operator.composite(source, destination, space);
+            assert_eq!(<[u8; 4]>::from(composited), expected, "{:?} {:?}", operator, space);
+        }
+    }
+}
+
+#[test]
+fn translucent_operators() {
+    let source = srgba([255, 0, 0, 153]);
+    let destination = srgba([0, 0, 255, 102]);
+    let alphas = [
+        (Operator::SourceOver, 0.76),
+        (Operator::DestinationOver, 0.76),
+        (Operator::SourceIn, 0.24),
+        (Operator::DestinationIn, 0.24),
+        (Operator::SourceOut, 0.36),
+        (Operator::DestinationOut, 0.16),
+        (Operator::SourceAtop, 0.4),
+        (Operator::DestinationAtop, 0.6),
+        (Operator::Xor, 0.52),
+    ];
+    for (operator, alpha) in alphas {
+        let composited = operator.composite(source, destination, CompositingSpace::Gamma);
+        assert!((composited.alpha() - alpha).abs() < 1e-6, "{:?} {}", operator, composited.alpha());
+        //Both colors are weighted by the coverage they keep.
+        let (f_a, f_b) = operator.fractions(0.6, 0.4);
+        let weight = 0.6 * f_a / (0.6 * f_a + 0.4 * f_b);
+        let [red, green, blue]: [u8; 3] = composited.color().into();
+        assert_eq!(red, (weight * 255f32).round() as u8, "{:?}", operator);
+        assert_eq!(green, 0);
+        assert_eq!(blue, ((1f32 - weight) * 255f32).round() as u8, "{:?}", operator);
+    }
+}
+
+#[test]
+fn linear_light() {
+    let source = srgba([255, 255, 255, 128]);
+    let destination = srgba([0, 0, 0, 255]);
+    let linear = Operator::SourceOver.composite(source, destination, CompositingSpace::Linear);
+    let gamma = Operator::SourceOver.composite(source, destination, CompositingSpace::Gamma);
+    assert_eq!(linear.color(), Srgb::new(188, 188, 188));
+    assert_eq!(gamma.color(), Srgb::new(128, 128, 128));
+}
+
+#[test]
+fn transparent_inputs() {
+    let transparent = srgba([10, 20, 30, 0]);
+    let color = srgba([200, 100, 50, 128]);
+    for operator in Operator::ALL {
+        let composited = operator.composite(transparent, transparent, CompositingSpace::Linear);
+        assert_eq!(<[u8; 4]>::from(composited), [0, 0, 0, 0]);
+    }
+    let composited = Operator::SourceOver.composite(transparent, color, CompositingSpace::Linear);
+    assert_eq!(<[u8; 4]>::from(composited), [200, 100, 50, 128]);
+    let composited = Operator::SourceOver.composite(color, transparent, CompositingSpace::Linear);
+    assert_eq!(<[u8; 4]>::from(composited), [200, 100, 50, 128]);
+}
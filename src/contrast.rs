@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests;
+
+pub mod apca;
+pub mod wcag;
+
+use crate::{
+    difference::{
+        Ciede2000,
+        ColorDifference,
+    },
+    illuminant::D65,
+    Cielch,
+    Srgb,
+};
+
+/// A minimum contrast between a foreground color and a background color.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Target {
+    /// Minimum WCAG 2.x contrast ratio.
+    Wcag(f32),
+    /// Minimum magnitude of the APCA lightness contrast (Lc).
+    Apca(f32),
+}
+
+impl Target {
+    /// Whether a foreground color on a background color meets the target contrast.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ contrast::Target, Srgb };
+    /// let black = Srgb::new(0, 0, 0);
+    /// let white = Srgb::new(255, 255, 255);
+    /// assert!(Target::Wcag(7.0).is_met(black, white));
+    /// assert!(Target::Apca(90.0).is_met(white, black));
+    /// assert!(!Target::Apca(90.0).is_met(white, white));
+    /// ```
+    pub fn is_met(&self, foreground: Srgb, background: Srgb) -> bool {
+        match *self {
+            Target::Wcag(ratio)     => wcag::contrast_ratio(foreground, background) >= ratio,
+            Target::Apca(contrast)  => apca::lightness_contrast(foreground, background).abs() >= contrast,
+        }
+    }
+}
+
+/// Finds the color closest to a foreground color that meets a target contrast on a background
+/// color.
+///
+/// Only the CIELCh lightness of the foreground color is changed, darkening or lightening it,
+/// its hue being kept. Its chroma is only reduced when needed to stay in the sRGB gamut.
+/// Out of the darker and lighter colors meeting the target, the one with the smallest CIEDE2000
+/// difference to the foreground color is chosen. The contrast is checked on the color rounded
+/// to 8 bit components.
+///
+/// The return value is the foreground color itself if it already meets the target, and None if
+/// no color meets it.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ contrast::{ adjust_foreground, Target, wcag::{ Level, TextSize } }, Srgb };
+/// let orange = Srgb::new(0xff, 0x88, 0x00);
+/// let white = Srgb::new(0xff, 0xff, 0xff);
+/// let target = Target::Wcag(Level::AA.minimum_ratio(TextSize::Normal));
+/// let adjusted = adjust_foreground(orange, white, target).unwrap();
+/// assert!(target.is_met(adjusted, white));
+/// ```
+pub fn adjust_foreground(foreground: Srgb, background: Srgb, target: Target) -> Option<Srgb> {
+    if target.is_met(foreground, background) {
+        return Some(foreground);
+    }
+    let [lightness, chroma, hue]: [f32; 3] = Cielch::<D65>::from(foreground).into();
+    let step = 0.1f32;
+    let darker = (1..).map(|i| lightness - i as f32 * step).take_while(|l| *l >= 0f32).chain([0f32]);
+    let lighter = (1..).map(|i| lightness + i as f32 * step).take_while(|l| *l <= 100f32).chain([100f32]);
+    let first_meeting_target = |mut lightnesses: Box<dyn Iterator<Item = f32>>| {
+        lightnesses.find_map(|l| {
+            let candidate = Srgb::from_cielch_reducing_chroma(Cielch::new(l, chroma, hue)?);
+            let candidate = Srgb::from(<[u8; 3]>::from(candidate));
+            match target.is_met(candidate, background) {
+                true    => Some(candidate),
+                false   => None,
+            }
+        })
+    };
+    [first_meeting_target(Box::new(darker)), first_meeting_target(Box::new(lighter))]
+        .into_iter()
+        .flatten()
+        .min_by(|a, b| {
+            let a = foreground.difference(a, Ciede2000::default());
+            let b = foreground.difference(b, Ciede2000::default());
+            a.total_cmp(&b)
+        })
+}
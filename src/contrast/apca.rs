@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Srgb;
+
+//Constants of the APCA 0.0.98G-4g algorithm.
+const MAIN_TRC: f32 = 2.4;
+const RED_COEFFICIENT: f32 = 0.2126729;
+const GREEN_COEFFICIENT: f32 = 0.7151522;
+const BLUE_COEFFICIENT: f32 = 0.0721750;
+const NORMAL_BACKGROUND_EXPONENT: f32 = 0.56;
+const NORMAL_TEXT_EXPONENT: f32 = 0.57;
+const REVERSE_TEXT_EXPONENT: f32 = 0.62;
+const REVERSE_BACKGROUND_EXPONENT: f32 = 0.65;
+const BLACK_THRESHOLD: f32 = 0.022;
+const BLACK_CLAMP: f32 = 1.414;
+const SCALE: f32 = 1.14;
+const LOW_OFFSET: f32 = 0.027;
+const LOW_CLIP: f32 = 0.1;
+const DELTA_Y_MINIMUM: f32 = 0.0005;
+
+//Minimum font sizes in pixels, for font weights from 100 to 900, by ascending lightness contrast.
+//999 means that the contrast is too low for anything, 777 that it is too low for text.
+const FONT_LOOKUP_TABLE: [(f32, [f32; 9]); 24] = [
+    (0f32, [999f32, 999f32, 999f32, 999f32, 999f32, 999f32, 999f32, 999f32, 999f32]),
+    (10f32, [999f32, 999f32, 999f32, 999f32, 999f32, 999f32, 999f32, 999f32, 999f32]),
+    (15f32, [777f32, 777f32, 777f32, 777f32, 777f32, 777f32, 777f32, 777f32, 777f32]),
+    (20f32, [777f32, 777f32, 777f32, 777f32, 777f32, 777f32, 777f32, 777f32, 777f32]),
+    (25f32, [777f32, 777f32, 777f32, 120f32, 120f32, 108f32, 96f32, 96f32, 96f32]),
+    (30f32, [777f32, 777f32, 120f32, 108f32, 108f32, 96f32, 72f32, 72f32, 72f32]),
+    (35f32, [777f32, 120f32, 108f32, 96f32, 72f32, 60f32, 48f32, 48f32, 48f32]),
+    (40f32, [120f32, 108f32, 96f32, 60f32, 48f32, 42f32, 32f32, 32f32, 32f32]),
+    (45f32, [108f32, 96f32, 72f32, 42f32, 32f32, 28f32, 24f32, 24f32, 24f32]),
+    (50f32, [96f32, 72f32, 60f32, 32f32, 28f32, 24f32, 21f32, 21f32, 21f32]),
+    (55f32, [80f32, 60f32, 48f32, 28f32, 24f32, 21f32, 18f32, 18f32, 18f32]),
+    (60f32, [72f32, 48f32, 42f32, 24f32, 21f32, 18f32, 16f32, 16f32, 18f32]),
+    (65f32, [68f32, 46f32, 32f32, 21.75f32, 19f32, 17f32, 15f32, 16f32, 18f32]),
+    (70f32, [64f32, 44f32, 28f32, 19.5f32, 18f32, 16f32, 14.5f32, 16f32, 18f32]),
+    (75f32, [60f32, 42f32, 24f32, 18f32, 16f32, 15f32, 14f32, 16f32, 18f32]),
+    (80f32, [56f32, 38.25f32, 23f32, 17.25f32, 15.81f32, 14.81f32, 14f32, 16f32, 18f32]),
+    (85f32, [52f32, 34.5f32, 22f32, 16.5f32, 15.625f32, 14.625f32, 14f32, 16f32, 18f32]),
+    (90f32, [48f32, 32f32, 21f32, 16f32, 15.5f32, 14.5f32, 14f32, 16f32, 18f32]),
+    (95f32, [45f32, 28f32, 19.5f32, 15.5f32, 15f32, 14f32, 13.5f32, 16f32, 18f32]),
+    (100f32, [42f32, 26.5f32, 18.5f32, 15f32, 14.5f32, 13.5f32, 13f32, 16f32, 18f32]),
+    (105f32, [39f32, 25f32, 18f32, 14.5f32, 14f32, 13f32, 12f32, 16f32, 18f32]),
+    (110f32, [36f32, 24f32, 18f32, 14f32, 13f32, 12f32, 11f32, 16f32, 18f32]),
+    (115f32, [34f32, 22f32, 16.5f32, 13.5f32, 12.5f32, 11.5f32, 10.5f32, 16f32, 18f32]),
+    (120f32, [32f32, 20f32, 15f32, 13f32, 12f32, 11f32, 10f32, 16f32, 18f32]),
+];
+
+/// Screen luminance of a sRGB color as estimated by APCA, including the soft clamp of
+/// near-black colors.
+/// Value is normalized (between 0 and 1 included).
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ contrast::apca::screen_luminance, Srgb };
+/// assert!(screen_luminance(Srgb::new(0, 0, 0)) > 0.0); // Soft clamped.
+/// assert!((screen_luminance(Srgb::new(255, 255, 255)) - 1.0).abs() < 1e-6);
+/// ```
+pub fn screen_luminance(srgb: Srgb) -> f32 {
+    let y = RED_COEFFICIENT * srgb.red().powf(MAIN_TRC)
+        + GREEN_COEFFICIENT * srgb.green().powf(MAIN_TRC)
+        + BLUE_COEFFICIENT * srgb.blue().powf(MAIN_TRC);
+    match y < BLACK_THRESHOLD {
+        true    => y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP),
+        false   => y,
+    }
+}
+
+/// Lightness contrast (Lc) of a text color on a background color, as defined by the
+/// Accessible Perceptual Contrast Algorithm (APCA 0.0.98G-4g) of the WCAG 3 draft.
+///
+/// The value is roughly between -108 and 106. Its sign gives the polarity of the contrast:
+/// positive for dark text on a light background and negative for light text on a dark
+/// background. Unlike WCAG 2.x contrast ratios, swapping the colors gives a different magnitude.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ contrast::apca::lightness_contrast, Srgb };
+/// let gray = Srgb::new(0x88, 0x88, 0x88);
+/// let white = Srgb::new(0xff, 0xff, 0xff);
+/// assert!((lightness_contrast(gray, white) - 63.06).abs() < 0.01);
+/// assert!((lightness_contrast(white, gray) + 68.54).abs() < 0.01);
+/// ```
+pub fn lightness_contrast(text: Srgb, background: Srgb) -> f32 {
+    let text = screen_luminance(text);
+    let background = screen_luminance(background);
+    if (background - text).abs() < DELTA_Y_MINIMUM {
+        return 0f32;
+    }
+    let contrast = match background > text {
+        true    => {
+            let sapc = (background.powf(NORMAL_BACKGROUND_EXPONENT) - text.powf(NORMAL_TEXT_EXPONENT)) * SCALE;
+            match sapc < LOW_CLIP {
+                true    => 0f32,
+                false   => sapc - LOW_OFFSET,
+            }
+        },
+        false   => {
+            let sapc = (background.powf(REVERSE_BACKGROUND_EXPONENT) - text.powf(REVERSE_TEXT_EXPONENT)) * SCALE;
+            match sapc > -LOW_CLIP {
+                true    => 0f32,
+                false   => sapc + LOW_OFFSET,
+            }
+        },
+    };
+    contrast * 100f32
+}
+
+/// Minimum font size, in CSS pixels, for text of a given font weight to be readable with a
+/// given lightness contrast, according to the APCA font lookup table.
+///
+/// Only the magnitude of the lightness contrast is used. The font weight must be a multiple of
+/// 100 between 100 and 900 included. The return value is None if the font weight is invalid or
+/// if the contrast is too low for text of that weight.
+/// Lightness contrasts between two rows of the table use the row of the lower contrast.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::contrast::apca::minimum_font_size;
+/// assert_eq!(minimum_font_size(90.0, 400), Some(16.0));
+/// assert_eq!(minimum_font_size(-62.0, 700), Some(16.0));
+/// assert_eq!(minimum_font_size(20.0, 400), None);
+/// ```
+pub fn minimum_font_size(lightness_contrast: f32, font_weight: u16) -> Option<f32> {
+    let column = match (100..=900).contains(&font_weight) && font_weight.is_multiple_of(100) {
+        true    => usize::from(font_weight / 100 - 1),
+        false   => return None,
+    };
+    let (_, sizes) = FONT_LOOKUP_TABLE.iter()
+        .rev()
+        .find(|(contrast, _)| *contrast <= lightness_contrast.abs())?;
+    match sizes[column] {
+        size if size >= 777f32  => None,
+        size                    => Some(size),
+    }
+}
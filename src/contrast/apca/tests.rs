@@ -0,0 +1,50 @@
+use super::*;
+
+//Reference values from the APCA reference implementation.
+#[test]
+fn lightness_contrast_reference() {
+    let pairs = [
+        (0x888888, 0xffffff, 63.056_47),
+        (0xffffff, 0x888888, -68.541_46),
+        (0x000000, 0xaaaaaa, 58.146_26),
+        (0xaaaaaa, 0x000000, -56.241_13),
+        (0x000000, 0xffffff, 106.040_67),
+        (0xffffff, 0x000000, -107.884_73),
+    ];
+    for (text, background, expected) in pairs {
+        let [text, background] = [text, background].map(|x: u32| {
+            let [_, r, g, b] = x.to_be_bytes();
+            Srgb::new(r, g, b)
+        });
+        assert!((lightness_contrast(text, background) - expected).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn lightness_contrast_low() {
+    let a = Srgb::new(0x80, 0x80, 0x80);
+    let b = Srgb::new(0x82, 0x82, 0x82);
+    assert_eq!(lightness_contrast(a, a), 0.0);
+    assert_eq!(lightness_contrast(a, b), 0.0);
+    assert_eq!(lightness_contrast(b, a), 0.0);
+}
+
+#[test]
+fn soft_clamp() {
+    let black = screen_luminance(Srgb::new(0, 0, 0));
+    assert!((black - BLACK_THRESHOLD.powf(BLACK_CLAMP)).abs() < 1e-9);
+    assert!(screen_luminance(Srgb::new(1, 1, 1)) > black);
+}
+
+#[test]
+fn minimum_font_size_lookup() {
+    assert_eq!(minimum_font_size(75.0, 400), Some(18.0));
+    assert_eq!(minimum_font_size(79.9, 400), Some(18.0));
+    assert_eq!(minimum_font_size(-75.0, 300), Some(24.0));
+    assert_eq!(minimum_font_size(45.0, 700), Some(24.0));
+    assert_eq!(minimum_font_size(120.0, 100), Some(32.0));
+    assert_eq!(minimum_font_size(30.0, 200), None);
+    assert_eq!(minimum_font_size(5.0, 900), None);
+    assert_eq!(minimum_font_size(90.0, 450), None);
+    assert_eq!(minimum_font_size(90.0, 1000), None);
+}
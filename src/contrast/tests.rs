@@ -0,0 +1,47 @@
+use super::*;
+use crate::Cielch;
+
+#[test]
+fn already_met() {
+    let black = Srgb::new(0, 0, 0);
+    let white = Srgb::new(255, 255, 255);
+    assert_eq!(adjust_foreground(black, white, Target::Wcag(21.0)), Some(black));
+}
+
+#[test]
+fn gray_on_white() {
+    let gray = Srgb::new(0x77, 0x77, 0x77);
+    let white = Srgb::new(0xff, 0xff, 0xff);
+    let adjusted = adjust_foreground(gray, white, Target::Wcag(4.5)).unwrap();
+    assert!(Target::Wcag(4.5).is_met(adjusted, white));
+    assert_eq!(adjusted, Srgb::new(0x76, 0x76, 0x76));
+}
+
+#[test]
+fn keeps_hue() {
+    let orange = Srgb::new(0xff, 0x88, 0x00);
+    let white = Srgb::new(0xff, 0xff, 0xff);
+    let adjusted = adjust_foreground(orange, white, Target::Wcag(4.5)).unwrap();
+    let [original, adjusted_lch]: [Cielch; 2] = [orange, adjusted].map(Cielch::from);
+    assert!(Target::Wcag(4.5).is_met(adjusted, white));
+    assert!(adjusted_lch.lightness() < original.lightness());
+    assert!((adjusted_lch.hue() - original.hue()).abs() < 2f32.to_radians());
+}
+
+#[test]
+fn lighter_on_dark_background() {
+    let blue = Srgb::new(0x20, 0x30, 0x90);
+    let black = Srgb::new(0x10, 0x10, 0x10);
+    let target = Target::Apca(60.0);
+    let adjusted = adjust_foreground(blue, black, target).unwrap();
+    assert!(target.is_met(adjusted, black));
+    let [original, adjusted]: [Cielch; 2] = [blue, adjusted].map(Cielch::from);
+    assert!(adjusted.lightness() > original.lightness());
+}
+
+#[test]
+fn unreachable() {
+    let gray = Srgb::new(0x80, 0x80, 0x80);
+    assert_eq!(adjust_foreground(gray, gray, Target::Wcag(22.0)), None);
+    assert_eq!(adjust_foreground(gray, Srgb::new(0x70, 0x70, 0x70), Target::Wcag(18.0)), None);
+}
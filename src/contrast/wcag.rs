@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Srgb;
+
+/// A WCAG 2.x conformance level.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Level {
+    /// Level AA, the minimum contrast (success criterion 1.4.3).
+    AA,
+    /// Level AAA, the enhanced contrast (success criterion 1.4.6).
+    AAA,
+}
+
+/// Size of a text, as defined by WCAG 2.x.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TextSize {
+    /// Text smaller than large text.
+    Normal,
+    /// Text of at least 18 points, or at least 14 points and bold.
+    Large,
+}
+
+impl Level {
+    /// Minimum contrast ratio required by a conformance level for a given text size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::contrast::wcag::{ Level, TextSize };
+    /// assert_eq!(Level::AA.minimum_ratio(TextSize::Normal), 4.5);
+    /// assert_eq!(Level::AAA.minimum_ratio(TextSize::Large), 4.5);
+    /// ```
+    pub fn minimum_ratio(&self, text_size: TextSize) -> f32 {
+        match (self, text_size) {
+            (Level::AA, TextSize::Normal)   => 4.5f32,
+            (Level::AA, TextSize::Large)    => 3f32,
+            (Level::AAA, TextSize::Normal)  => 7f32,
+            (Level::AAA, TextSize::Large)   => 4.5f32,
+        }
+    }
+}
+
+/// Contrast ratio between two sRGB colors, as defined by WCAG 2.x.
+/// Value is between 1 and 21 included and doesn't depend on the order of the colors.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ contrast::wcag::contrast_ratio, Srgb };
+/// let black = Srgb::new(0, 0, 0);
+/// let white = Srgb::new(255, 255, 255);
+/// assert_eq!(contrast_ratio(black, white), 21.0);
+/// assert_eq!(contrast_ratio(white, white), 1.0);
+/// ```
+pub fn contrast_ratio(a: Srgb, b: Srgb) -> f32 {
+    let a = a.relative_luminance();
+    let b = b.relative_luminance();
+    //Same as (lighter + 0.05) / (darker + 0.05) but without the rounding error of 0.05.
+    (20f32 * a.max(b) + 1f32) / (20f32 * a.min(b) + 1f32)
+}
+
+/// Whether a text color on a background color meets the contrast required by a conformance
+/// level for a given text size.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ contrast::wcag::{ passes, Level, TextSize }, Srgb };
+/// let gray = Srgb::new(0x77, 0x77, 0x77);
+/// let white = Srgb::new(0xff, 0xff, 0xff);
+/// assert!(!passes(gray, white, Level::AA, TextSize::Normal));
+/// assert!(passes(gray, white, Level::AA, TextSize::Large));
+/// ```
+pub fn passes(text: Srgb, background: Srgb, level: Level, text_size: TextSize) -> bool {
+    contrast_ratio(text, background) >= level.minimum_ratio(text_size)
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn contrast_ratio_bounds() {
+    let black = Srgb::new(0, 0, 0);
+    let white = Srgb::new(255, 255, 255);
+    assert_eq!(contrast_ratio(black, white), 21.0);
+    assert_eq!(contrast_ratio(white, black), 21.0);
+    assert_eq!(contrast_ratio(black, black), 1.0);
+}
+
+#[test]
+fn contrast_ratio_grays() {
+    let white = Srgb::new(0xff, 0xff, 0xff);
+    let passing = Srgb::new(0x76, 0x76, 0x76);
+    let failing = Srgb::new(0x77, 0x77, 0x77);
+    assert!((contrast_ratio(passing, white) - 4.54).abs() < 0.01);
+    assert!((contrast_ratio(failing, white) - 4.48).abs() < 0.01);
+}
+
+#[test]
+fn passes_levels() {
+    let white = Srgb::new(0xff, 0xff, 0xff);
+    let gray = Srgb::new(0x76, 0x76, 0x76);
+    assert!(passes(gray, white, Level::AA, TextSize::Normal));
+    assert!(passes(gray, white, Level::AA, TextSize::Large));
+    assert!(!passes(gray, white, Level::AAA, TextSize::Normal));
+    assert!(passes(gray, white, Level::AAA, TextSize::Large));
+    let dark_gray = Srgb::new(0x59, 0x59, 0x59);
+    assert!(passes(dark_gray, white, Level::AAA, TextSize::Normal));
+}
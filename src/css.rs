@@ -0,0 +1,496 @@
+//! Parsing and serialization of CSS Color Level 4 colors.
+//!
+//! A `CssColor` holds a color in the color space it was written in: the legacy sRGB syntaxes
+//! (hexadecimal colors, named colors, `rgb()`, `hsl()`, `hwb()`), `lab()` and `lch()` relative to D50,
+//! `oklab()`, `oklch()` and the `color()` function with its predefined color spaces.
+//!
+//! Relative colors of CSS Color 5 are resolved to concrete colors: the channels of their origin
+//! color are bound to channel keywords (`r`, `l`, `h`, `alpha`, …) which components can use, on
+//! their own or in `calc()` expressions. Origin colors can be given by `var()` functions resolved
+//! with `CssColor::parse_with_variables`.
+//!
+//! # Examples
+//!
+//! ```
+//! use cowor::{ css::CssColor, Srgb, Srgba };
+//! let color: CssColor = "hsl(120deg 100% 50% / 50%)".parse().unwrap();
+//! assert_eq!(color.to_string(), "rgba(0, 255, 0, 0.5)");
+//! let color: CssColor = "oklch(62.8% 0.2577 29.23deg)".parse().unwrap();
+//! assert_eq!(Srgba::from(color).color(), Srgb::new(255, 0, 0));
+//! let color: CssColor = "hsl(from #ff0000 calc(h + 120) s l)".parse().unwrap();
+//! assert_eq!(Srgba::from(color).color(), Srgb::new(0, 255, 0));
+//! ```
+
+mod calc;
+mod parse;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fmt,
+    str::FromStr,
+};
+use crate::{
+    adaptation::ChromaticAdaptation,
+    alpha::{ Cielaba, Cielcha },
+    cielab,
+    ciexyz::{
+        self,
+        FROM_SRGB_CONVERSION_MATRIX,
+    },
+    error::ParseColorError,
+    illuminant::D50,
+    lms::Bradford,
+    matrix,
+    oklab,
+    srgb::{ gamma_compress, gamma_expand },
+    Alpha,
+    Cielab,
+    Ciexyz,
+    Oklab,
+    Oklch,
+    Srgb,
+    Srgba,
+};
+
+//Chroma of CIELAB colors above which they are limited before being converted, the chroma of 100%
+//in CSS, greater than the chroma of any sRGB color.
+const MAX_LAB_CHROMA: f32 = 150f32;
+
+//Chromaticity of the D65 white point, as used by CSS Color 4.
+const D65_CHROMATICITY: [f32; 2] = [0.3127f32, 0.3290f32];
+
+//Constants of the transfer function of Rec. 2020.
+const REC2020_ALPHA: f32 = 1.099_296_8f32;
+const REC2020_BETA: f32 = 0.018_053_97f32;
+
+/// A predefined color space of the CSS `color()` function.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PredefinedSpace {
+    /// sRGB (`srgb`).
+    Srgb,
+    /// sRGB without gamma compression (`srgb-linear`).
+    SrgbLinear,
+    /// Display P3 (`display-p3`).
+    DisplayP3,
+    /// Adobe RGB (1998) (`a98-rgb`).
+    A98Rgb,
+    /// ProPhoto RGB, relative to D50 (`prophoto-rgb`).
+    ProphotoRgb,
+    /// ITU-R BT.2020 (`rec2020`).
+    Rec2020,
+    /// CIEXYZ relative to D50 (`xyz-d50`).
+    XyzD50,
+    /// CIEXYZ relative to D65 (`xyz-d65`, or `xyz`).
+    XyzD65,
+}
+
+impl PredefinedSpace {
+    /// All the predefined color spaces.
+    pub const ALL: [PredefinedSpace; 8] = [
+        PredefinedSpace::Srgb,
+        PredefinedSpace::SrgbLinear,
+        PredefinedSpace::DisplayP3,
+        PredefinedSpace::A98Rgb,
+        PredefinedSpace::ProphotoRgb,
+        PredefinedSpace::Rec2020,
+        PredefinedSpace::XyzD50,
+        PredefinedSpace::XyzD65,
+    ];
+
+    /// Name of the color space in the `color()` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::css::PredefinedSpace;
+    /// assert_eq!(PredefinedSpace::DisplayP3.name(), "display-p3");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            PredefinedSpace::Srgb           => "srgb",
+            PredefinedSpace::SrgbLinear     => "srgb-linear",
+            PredefinedSpace::DisplayP3      => "display-p3",
+            PredefinedSpace::A98Rgb         => "a98-rgb",
+            PredefinedSpace::ProphotoRgb    => "prophoto-rgb",
+            PredefinedSpace::Rec2020        => "rec2020",
+            PredefinedSpace::XyzD50         => "xyz-d50",
+            PredefinedSpace::XyzD65         => "xyz-d65",
+        }
+    }
+
+    /// Whether the color space is an RGB color space, whose components range from 0 to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::css::PredefinedSpace;
+    /// assert!(PredefinedSpace::Rec2020.is_rgb());
+    /// assert!(!PredefinedSpace::XyzD50.is_rgb());
+    /// ```
+    pub fn is_rgb(&self) -> bool {
+        !matches!(self, PredefinedSpace::XyzD50 | PredefinedSpace::XyzD65)
+    }
+
+    /// Converts components in the color space to CIEXYZ values relative to D65.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ css::PredefinedSpace, Ciexyz, Srgb };
+    /// let xyz = PredefinedSpace::Srgb.to_ciexyz([1.0, 0.0, 0.0]);
+    /// let expected: [f32; 3] = Ciexyz::from(Srgb::new(255, 0, 0)).into();
+    /// assert!((0..3).all(|i| (xyz[i] - expected[i]).abs() < 1e-6));
+    /// ```
+    pub fn to_ciexyz(&self, components: [f32; 3]) -> [f32; 3] {
+        matrix::transform(self.matrix(), components.map(|x| self.expand(x)))
+    }
+
+    /// Converts CIEXYZ values relative to D65 to components in the color space.
+    /// Components of colors outside of the gamut of an RGB color space fall outside of the range
+    /// from 0 to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::css::PredefinedSpace;
+    /// let p3 = PredefinedSpace::DisplayP3;
+    /// let [r, g, b] = p3.from_ciexyz(p3.to_ciexyz([0.2, 0.4, 0.6]));
+    /// assert!((r - 0.2).abs() < 1e-4 && (g - 0.4).abs() < 1e-4 && (b - 0.6).abs() < 1e-4);
+    /// ```
+    pub fn from_ciexyz(&self, xyz: [f32; 3]) -> [f32; 3] {
+        let linear = matrix::transform(matrix::inverse(self.matrix()).unwrap(), xyz);
+        linear.map(|x| self.compress(x))
+    }
+
+    //Matrix from the linear components of the color space to CIEXYZ relative to D65.
+    fn matrix(&self) -> [f32; 9] {
+        let rgb = |red, green, blue| matrix::rgb_to_ciexyz(red, green, blue, D65_CHROMATICITY).unwrap();
+        let d50_to_d65 = ChromaticAdaptation::<Bradford>::new(ciexyz::D50, ciexyz::D65).matrix();
+        match self {
+            PredefinedSpace::Srgb | PredefinedSpace::SrgbLinear => FROM_SRGB_CONVERSION_MATRIX,
+            PredefinedSpace::DisplayP3                          => rgb([0.680f32, 0.320f32], [0.265f32, 0.690f32], [0.150f32, 0.060f32]),
+            PredefinedSpace::A98Rgb                             => rgb([0.64f32, 0.33f32], [0.21f32, 0.71f32], [0.15f32, 0.06f32]),
+            PredefinedSpace::ProphotoRgb                        => {
+                let to_d50 = matrix::rgb_to_ciexyz(
+                    [0.734_699f32, 0.265_301f32],
+                    [0.159_597f32, 0.840_403f32],
+                    [0.036_598f32, 0.000_105f32],
+                    chromaticity(ciexyz::D50),
+                ).unwrap();
+                matrix::multiply(d50_to_d65, to_d50)
+            },
+            PredefinedSpace::Rec2020                            => rgb([0.708f32, 0.292f32], [0.170f32, 0.797f32], [0.131f32, 0.046f32]),
+            PredefinedSpace::XyzD50                             => d50_to_d65,
+            PredefinedSpace::XyzD65                             => [1f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 1f32],
+        }
+    }
+
+    //Transfer function from the components of the color space to linear components. Negative
+    //components are expanded symmetrically.
+    fn expand(&self, x: f32) -> f32 {
+        let magnitude = x.abs();
+        let expanded = match self {
+            PredefinedSpace::Srgb | PredefinedSpace::DisplayP3  => gamma_expand(magnitude),
+            PredefinedSpace::A98Rgb                             => magnitude.powf(563f32 / 256f32),
+            PredefinedSpace::ProphotoRgb                        => match magnitude < 16f32 / 512f32 {
+                true    => magnitude / 16f32,
+                false   => magnitude.powf(1.8f32),
+            },
+            PredefinedSpace::Rec2020                            => match magnitude < REC2020_BETA * 4.5f32 {
+                true    => magnitude / 4.5f32,
+                false   => ((magnitude + REC2020_ALPHA - 1f32) / REC2020_ALPHA).powf(1f32 / 0.45f32),
+            },
+            _                                                   => magnitude,
+        };
+        expanded.copysign(x)
+    }
+
+    //Transfer function from linear components to the components of the color space. Negative
+    //components are compressed symmetrically.
+    fn compress(&self, x: f32) -> f32 {
+        let magnitude = x.abs();
+        let compressed = match self {
+            PredefinedSpace::Srgb | PredefinedSpace::DisplayP3  => gamma_compress(magnitude),
+            PredefinedSpace::A98Rgb                             => magnitude.powf(256f32 / 563f32),
+            PredefinedSpace::ProphotoRgb                        => match magnitude < 1f32 / 512f32 {
+                true    => magnitude * 16f32,
+                false   => magnitude.powf(1f32 / 1.8f32),
+            },
+            PredefinedSpace::Rec2020                            => match magnitude < REC2020_BETA {
+                true    => magnitude * 4.5f32,
+                false   => REC2020_ALPHA * magnitude.powf(0.45f32) - (REC2020_ALPHA - 1f32),
+            },
+            _                                                   => magnitude,
+        };
+        compressed.copysign(x)
+    }
+}
+
+/// A color as written in CSS, in the color space of the syntax used to write it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CssColor {
+    /// An sRGB color written with a legacy syntax: a hexadecimal color, a named color,
+    /// `transparent` or the `rgb()`, `rgba()`, `hsl()`, `hsla()` or `hwb()` functions.
+    Rgb(Srgba),
+    /// A CIELAB color relative to D50 written with the `lab()` function.
+    Lab(Cielaba<D50>),
+    /// A CIELCh color relative to D50 written with the `lch()` function.
+    Lch(Cielcha<D50>),
+    /// An Oklab color written with the `oklab()` function.
+    Oklab(Alpha<Oklab>),
+    /// An Oklch color written with the `oklch()` function.
+    Oklch(Alpha<Oklch>),
+    /// A color written with the `color()` function in a predefined color space, with its
+    /// components in the color space, which may fall outside of its gamut, and its alpha.
+    Color(PredefinedSpace, [f32; 3], f32),
+}
+
+impl CssColor {
+    /// Alpha of a color, between 0 (fully transparent) and 1 (fully opaque) included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::css::CssColor;
+    /// let color: CssColor = "lab(50% 20 -30 / 0.25)".parse().unwrap();
+    /// assert_eq!(color.alpha(), 0.25);
+    /// ```
+    pub fn alpha(&self) -> f32 {
+        match self {
+            CssColor::Rgb(color)        => color.alpha(),
+            CssColor::Lab(color)        => color.alpha(),
+            CssColor::Lch(color)        => color.alpha(),
+            CssColor::Oklab(color)      => color.alpha(),
+            CssColor::Oklch(color)      => color.alpha(),
+            CssColor::Color(_, _, alpha)    => *alpha,
+        }
+    }
+
+    /// Whether a color is written with a legacy syntax, which CSS interpolates in sRGB by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::css::CssColor;
+    /// assert!("#f80".parse::<CssColor>().unwrap().is_legacy());
+    /// assert!(!"oklab(0.5 0.1 0)".parse::<CssColor>().unwrap().is_legacy());
+    /// ```
+    pub fn is_legacy(&self) -> bool {
+        matches!(self, CssColor::Rgb(_))
+    }
+
+    /// Parses a CSS color like `parse`, resolving `var()` functions with a function returning
+    /// the color of a custom property from its name, including its leading `--`, or None if it
+    /// isn't defined. Undefined custom properties without a fallback give an UnknownVariable
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ css::CssColor, error::ParseColorError, Srgb, Srgba };
+    /// let brand: CssColor = "#336699".parse().unwrap();
+    /// let variables = |name: &str| match name {
+    ///     "--brand"   => Some(brand),
+    ///     _           => None,
+    /// };
+    /// let color = CssColor::parse_with_variables("rgb(from var(--brand) r g calc(b / 3))", variables).unwrap();
+    /// assert_eq!(color.to_string(), "rgb(51, 102, 51)");
+    /// let fallback = CssColor::parse_with_variables("var(--accent, #f80)", variables).unwrap();
+    /// assert_eq!(Srgba::from(fallback).color(), Srgb::new(255, 136, 0));
+    /// let error = CssColor::parse_with_variables("var(--accent)", variables);
+    /// assert_eq!(error, Err(ParseColorError::UnknownVariable(String::from("--accent"))));
+    /// ```
+    pub fn parse_with_variables<F: Fn(&str) -> Option<CssColor>>(css: &str, variables: F) -> Result<Self, ParseColorError> {
+        parse::parse(css, &variables)
+    }
+
+    //CIEXYZ values relative to D65 of a color, without its alpha.
+    fn to_ciexyz(self) -> [f32; 3] {
+        match self {
+            CssColor::Rgb(color)        => Ciexyz::from(color.color()).into(),
+            CssColor::Lab(color)        => lab_to_ciexyz(color.color()),
+            CssColor::Lch(color)        => lab_to_ciexyz(color.color().into()),
+            CssColor::Oklab(color)      => oklab::to_ciexyz(color.color()),
+            CssColor::Oklch(color)      => oklab::to_ciexyz(color.color().into()),
+            CssColor::Color(space, components, _)   => space.to_ciexyz(components),
+        }
+    }
+
+    //Oklab components of a color, without its alpha.
+    fn to_oklab(self) -> [f32; 3] {
+        match self {
+            CssColor::Oklab(color)      => color.color().into(),
+            CssColor::Oklch(color)      => Oklab::from(color.color()).into(),
+            _                           => oklab::from_ciexyz(self.to_ciexyz()),
+        }
+    }
+}
+
+/// Parses a CSS color: a hexadecimal color, a named color, `transparent` or a color function of
+/// CSS Color 4, in its modern or legacy syntax, or a relative color of CSS Color 5.
+///
+/// Components may be numbers, percentages, angles (`deg`, `grad`, `rad`, `turn`) for hues,
+/// or `none` which is taken as 0. Components of relative colors may also be channel keywords and
+/// `calc()` expressions. The components of `rgb()`, `hsl()` and `hwb()`, lightnesses and chromas
+/// are clamped to their range, other components are kept as they are, as in CSS.
+/// `var()` functions give an UnknownVariable error, use `CssColor::parse_with_variables` to
+/// resolve them.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ css::CssColor, error::ParseColorError };
+/// assert!("rgb(10, 20, 30)".parse::<CssColor>().is_ok());
+/// assert!("CornflowerBlue".parse::<CssColor>().is_ok());
+/// assert!("rgb(10 20 30 / 50%)".parse::<CssColor>().is_ok());
+/// assert!("lch(50% 40 0.25turn)".parse::<CssColor>().is_ok());
+/// assert!("color(display-p3 1 0.5 none)".parse::<CssColor>().is_ok());
+/// assert!("oklch(from lab(50 20 30) l calc(c * 2) h / calc(alpha / 2))".parse::<CssColor>().is_ok());
+/// assert_eq!("foo(1 2 3)".parse::<CssColor>(), Err(ParseColorError::UnknownColor(String::from("foo"))));
+/// ```
+impl FromStr for CssColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse(s, &|_| None)
+    }
+}
+
+/// Serializes a CSS color in its canonical form.
+///
+/// Legacy sRGB colors are written with the `rgb()` function, or `rgba()` if they aren't fully
+/// opaque, other colors with the function of their color space and an alpha only if they aren't
+/// fully opaque. Hues are written in degrees.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::css::CssColor;
+/// let color: CssColor = "#0a141e80".parse().unwrap();
+/// assert_eq!(color.to_string(), "rgba(10, 20, 30, 0.50196)");
+/// let color: CssColor = "lch(50% 40 0.25turn)".parse().unwrap();
+/// assert_eq!(color.to_string(), "lch(50 40 90)");
+/// let color: CssColor = "color(display-p3 100% 50% 0 / 0.5)".parse().unwrap();
+/// assert_eq!(color.to_string(), "color(display-p3 1 0.5 0 / 0.5)");
+/// ```
+impl fmt::Display for CssColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let alpha = match self.alpha() {
+            1f32    => String::new(),
+            alpha   => format!(" / {}", number(alpha)),
+        };
+        match self {
+            CssColor::Rgb(color)            => {
+                let [r, g, b]: [u8; 3] = color.color().into();
+                match color.alpha() {
+                    1f32    => write!(f, "rgb({r}, {g}, {b})"),
+                    alpha   => write!(f, "rgba({r}, {g}, {b}, {})", number(alpha)),
+                }
+            },
+            CssColor::Lab(color)            => {
+                let [l, a, b]: [f32; 3] = color.color().into();
+                write!(f, "lab({} {} {}{alpha})", number(l), number(a), number(b))
+            },
+            CssColor::Lch(color)            => {
+                let [l, c, h]: [f32; 3] = color.color().into();
+                write!(f, "lch({} {} {}{alpha})", number(l), number(c), degrees(h))
+            },
+            CssColor::Oklab(color)          => {
+                let [l, a, b]: [f32; 3] = color.color().into();
+                write!(f, "oklab({} {} {}{alpha})", number(l), number(a), number(b))
+            },
+            CssColor::Oklch(color)          => {
+                let [l, c, h]: [f32; 3] = color.color().into();
+                write!(f, "oklch({} {} {}{alpha})", number(l), number(c), degrees(h))
+            },
+            CssColor::Color(space, [c1, c2, c3], _) => {
+                write!(f, "color({} {} {} {}{alpha})", space.name(), number(*c1), number(*c2), number(*c3))
+            },
+        }
+    }
+}
+
+/// Conversion from a CSS color to sRGB with an alpha channel.
+/// The chroma of colors outside of the sRGB gamut is reduced in Oklch, at constant lightness and
+/// hue, until they fit in it.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ css::CssColor, Srgb, Srgba };
+/// let color: CssColor = "color(display-p3 0 1 0)".parse().unwrap();
+/// let srgba = Srgba::from(color);
+/// assert_eq!(srgba.alpha(), 1.0);
+/// assert!(srgba.color().green_as_u8() > 200);
+/// ```
+impl From<CssColor> for Srgba {
+    fn from(color: CssColor) -> Self {
+        match color {
+            CssColor::Rgb(srgba)    => srgba,
+            _                       => {
+                let srgb = Srgb::from_oklab_reducing_chroma(color.to_oklab());
+                Alpha::new(srgb, color.alpha()).unwrap()
+            },
+        }
+    }
+}
+
+/// Conversion from sRGB with an alpha channel to a legacy CSS color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ css::CssColor, Alpha, Srgb };
+/// let color = CssColor::from(Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap());
+/// assert_eq!(color.to_string(), "rgba(10, 20, 30, 0.5)");
+/// ```
+impl From<Srgba> for CssColor {
+    fn from(srgba: Srgba) -> Self {
+        CssColor::Rgb(srgba)
+    }
+}
+
+/// Conversion from sRGB to a fully opaque legacy CSS color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ css::CssColor, Srgb };
+/// assert_eq!(CssColor::from(Srgb::new(10, 20, 30)).to_string(), "rgb(10, 20, 30)");
+/// ```
+impl From<Srgb> for CssColor {
+    fn from(srgb: Srgb) -> Self {
+        CssColor::Rgb(Alpha::opaque(srgb))
+    }
+}
+
+//Number rounded to 5 decimals, without negative zero. Numbers too large to be rounded are
+//written as they are.
+fn number(x: f32) -> String {
+    let rounded = (x * 1e5f32).round() / 1e5f32;
+    match rounded.is_finite() {
+        true    => format!("{}", rounded + 0f32),
+        false   => format!("{x}"),
+    }
+}
+
+//Hue in radians written in degrees between 0 and 360.
+fn degrees(hue: f32) -> String {
+    let degrees = (hue.to_degrees().rem_euclid(360f32) * 1e5f32).round() / 1e5f32;
+    number(degrees.rem_euclid(360f32))
+}
+
+//Chromaticity of a white point.
+fn chromaticity(white: Ciexyz) -> [f32; 2] {
+    let sum = white.x() + white.y() + white.z();
+    [white.x() / sum, white.y() / sum]
+}
+
+//CIEXYZ values relative to D65 of a CIELAB color. The chroma of colors far outside of the sRGB
+//gamut is first limited, so that their CIEXYZ values don't overflow.
+fn lab_to_ciexyz(cielab: Cielab<D50>) -> [f32; 3] {
+    let scale = (MAX_LAB_CHROMA / cielab.a().hypot(cielab.b())).min(1f32);
+    let cielab = Cielab::with_reference_white(cielab.lightness(), cielab.a() * scale, cielab.b() * scale, D50).unwrap();
+    cielab::to_ciexyz(cielab)
+}
@@ -0,0 +1,191 @@
+//Evaluation of the components of color functions: numbers with their units and `calc()`
+//expressions made of them, of channel keywords and of the `pi` and `e` constants.
+
+//A component of a color function. Angles are in degrees.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Component {
+    None,
+    Number(f32),
+    Percentage(f32),
+    Angle(f32),
+}
+
+//A token of a `calc()` expression.
+#[derive(Debug, Clone, Copy)]
+enum Token {
+    Value(Component),
+    Operator(char),
+    Open,
+    Close,
+}
+
+//Parses a number followed by an optional unit: `%` or an angle unit. Numbers too large to be
+//converted to degrees are rejected.
+pub(super) fn dimension(token: &str) -> Option<Component> {
+    let length = token.trim_end_matches(|x: char| x.is_ascii_alphabetic() || x == '%').len();
+    let (number, unit) = token.split_at(length);
+    if !number.chars().all(|x| x.is_ascii_digit() || "+-.eE".contains(x)) {
+        return None;
+    }
+    let number = number.parse::<f32>().ok().filter(|x| x.is_finite())?;
+    let component = match unit.to_ascii_lowercase().as_str() {
+        ""      => Component::Number(number),
+        "%"     => Component::Percentage(number),
+        "deg"   => Component::Angle(number),
+        "grad"  => Component::Angle(number * 0.9f32),
+        "rad"   => Component::Angle(number.to_degrees()),
+        "turn"  => Component::Angle(number * 360f32),
+        _       => return None,
+    };
+    finite(component)
+}
+
+//The component if its value is a finite number.
+pub(super) fn finite(component: Component) -> Option<Component> {
+    match component {
+        Component::Number(x) | Component::Percentage(x) | Component::Angle(x) if !x.is_finite() => None,
+        _                                                                                       => Some(component),
+    }
+}
+
+//Value of a keyword: a channel keyword of a relative color or a constant.
+pub(super) fn keyword(keyword: &str, channels: &[(&str, f32)]) -> Option<f32> {
+    let keyword = keyword.to_ascii_lowercase();
+    match keyword.as_str() {
+        "pi"    => Some(std::f32::consts::PI),
+        "e"     => Some(std::f32::consts::E),
+        _       => channels.iter().find(|(name, _)| *name == keyword).map(|(_, value)| *value),
+    }
+}
+
+//Evaluates the expression inside of a `calc()` function. Only values of the same type can be
+//added or subtracted, and values can only be multiplied or divided by numbers. Expressions
+//overflowing to an infinite value or giving NaN are rejected.
+pub(super) fn evaluate(expression: &str, channels: &[(&str, f32)]) -> Option<Component> {
+    let tokens = tokenize(expression, channels)?;
+    let mut position = 0;
+    let value = sum(&tokens, &mut position)?;
+    match position == tokens.len() {
+        true    => finite(value),
+        false   => None,
+    }
+}
+
+//Splits an expression into tokens. Signs are operators when they follow a value or a closing
+//parenthesis, and part of a number otherwise.
+fn tokenize(expression: &str, channels: &[(&str, f32)]) -> Option<Vec<Token>> {
+    let characters: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < characters.len() {
+        let character = characters[i];
+        let after_operand = matches!(tokens.last(), Some(Token::Value(_) | Token::Close));
+        match character {
+            _ if character.is_whitespace()              => i += 1,
+            '(' | ')' | '*' | '/'                       => {
+                tokens.push(match character {
+                    '(' => Token::Open,
+                    ')' => Token::Close,
+                    _   => Token::Operator(character),
+                });
+                i += 1;
+            },
+            '+' | '-' if after_operand                  => {
+                tokens.push(Token::Operator(character));
+                i += 1;
+            },
+            _                                           => {
+                let start = i;
+                i += 1;
+                while i < characters.len() && (characters[i].is_ascii_alphanumeric() || ".%".contains(characters[i])
+                    || ("+-".contains(characters[i]) && characters[i - 1].eq_ignore_ascii_case(&'e') && characters[start].is_ascii_digit())) {
+                    i += 1;
+                }
+                let word: String = characters[start..i].iter().collect();
+                //Nested parentheses may be written as a nested `calc()`.
+                if i < characters.len() && characters[i] == '(' {
+                    match word.eq_ignore_ascii_case("calc") {
+                        true    => continue,
+                        false   => return None,
+                    }
+                }
+                let (sign, unsigned) = match word.strip_prefix('-') {
+                    Some(unsigned)  => (-1f32, unsigned),
+                    None            => (1f32, word.strip_prefix('+').unwrap_or(&word)),
+                };
+                let value = match unsigned.starts_with(|x: char| x.is_ascii_alphabetic()) {
+                    true    => Component::Number(sign * keyword(unsigned, channels)?),
+                    false   => dimension(&word)?,
+                };
+                tokens.push(Token::Value(value));
+            },
+        }
+    }
+    Some(tokens)
+}
+
+//Evaluates a sum of products.
+fn sum(tokens: &[Token], position: &mut usize) -> Option<Component> {
+    let mut value = product(tokens, position)?;
+    while let Some(Token::Operator(operator @ ('+' | '-'))) = tokens.get(*position) {
+        *position += 1;
+        let operand = product(tokens, position)?;
+        let operand = match operator {
+            '-' => scale(operand, -1f32),
+            _   => operand,
+        };
+        value = match (value, operand) {
+            (Component::Number(a), Component::Number(b))            => Component::Number(a + b),
+            (Component::Percentage(a), Component::Percentage(b))    => Component::Percentage(a + b),
+            (Component::Angle(a), Component::Angle(b))              => Component::Angle(a + b),
+            _                                                       => return None,
+        };
+    }
+    Some(value)
+}
+
+//Evaluates a product of values or parenthesized sums.
+fn product(tokens: &[Token], position: &mut usize) -> Option<Component> {
+    let mut value = factor(tokens, position)?;
+    while let Some(Token::Operator(operator @ ('*' | '/'))) = tokens.get(*position) {
+        *position += 1;
+        let operand = factor(tokens, position)?;
+        value = match (operator, value, operand) {
+            ('*', Component::Number(a), x)                  => scale(x, a),
+            ('*', x, Component::Number(b))                  => scale(x, b),
+            ('/', x, Component::Number(b)) if b != 0f32     => scale(x, 1f32 / b),
+            _                                               => return None,
+        };
+    }
+    Some(value)
+}
+
+//Evaluates a value or a parenthesized sum.
+fn factor(tokens: &[Token], position: &mut usize) -> Option<Component> {
+    let token = *tokens.get(*position)?;
+    *position += 1;
+    match token {
+        Token::Value(value) => Some(value),
+        Token::Open         => {
+            let value = sum(tokens, position)?;
+            match tokens.get(*position) {
+                Some(Token::Close)  => {
+                    *position += 1;
+                    Some(value)
+                },
+                _                   => None,
+            }
+        },
+        _                   => None,
+    }
+}
+
+//Multiplies a value by a factor.
+fn scale(value: Component, factor: f32) -> Component {
+    match value {
+        Component::None             => Component::None,
+        Component::Number(x)        => Component::Number(x * factor),
+        Component::Percentage(x)    => Component::Percentage(x * factor),
+        Component::Angle(x)         => Component::Angle(x * factor),
+    }
+}
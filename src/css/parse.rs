@@ -0,0 +1,380 @@
+use crate::{
+    cielab,
+    error::ParseColorError,
+    illuminant::D50,
+    named,
+    oklab,
+    Alpha,
+    Cielab,
+    Cielch,
+    Oklab,
+    Oklch,
+    Srgb,
+    Srgba,
+};
+use super::{
+    calc::{ self, Component },
+    CssColor,
+    PredefinedSpace,
+};
+
+//Components and alpha of a color function, with whether they are written with the legacy
+//syntax, separated by commas. Relative colors have an origin color whose channels are bound to
+//channel keywords.
+struct Arguments<'a> {
+    components: Vec<&'a str>,
+    alpha: Option<&'a str>,
+    legacy: bool,
+    origin: Option<CssColor>,
+    channels: Vec<(&'static str, f32)>,
+}
+
+impl<'a> Arguments<'a> {
+    //Binds the channel keywords of a relative color, and `alpha`, to the channels of its origin
+    //color converted by the given function.
+    fn bind(&mut self, names: [&'static str; 3], convert: impl FnOnce(CssColor) -> [f32; 3]) {
+        if let Some(origin) = self.origin {
+            self.channels = names.into_iter().zip(convert(origin)).collect();
+            self.channels.push(("alpha", origin.alpha()));
+        }
+    }
+
+    //Checks that a color function has three components, and only uses the legacy syntax if it
+    //allows it.
+    fn components(&self, legacy: bool) -> Result<[&'a str; 3], ParseColorError> {
+        match (self.legacy && !legacy, &self.components[..]) {
+            (false, &[first, second, third])    => Ok([first, second, third]),
+            _                                   => Err(ParseColorError::InvalidSyntax),
+        }
+    }
+
+    //Parses a component of the color function. Its value must be finite.
+    fn component(&self, token: &str) -> Result<Component, ParseColorError> {
+        let lowercase = token.to_ascii_lowercase();
+        let component = match lowercase.strip_prefix("calc(").and_then(|x| x.strip_suffix(')')) {
+            _ if lowercase == "none"                                        => Some(Component::None),
+            Some(expression)                                                => calc::evaluate(expression, &self.channels),
+            None if token.starts_with(|x: char| x.is_ascii_alphabetic())    => calc::keyword(token, &self.channels).map(Component::Number),
+            None                                                            => calc::dimension(token),
+        };
+        component.and_then(calc::finite).ok_or_else(|| ParseColorError::InvalidComponent(token.to_string()))
+    }
+
+    //Parses a component which is a number or a percentage, 100% being the given reference.
+    //Percentages too large to be converted to numbers are rejected.
+    fn number_or_percentage(&self, token: &str, reference: f32) -> Result<f32, ParseColorError> {
+        let number = match self.component(token)? {
+            Component::None             => Some(0f32),
+            Component::Number(x)        => Some(x),
+            Component::Percentage(x)    => Some(x / 100f32 * reference).filter(|x| x.is_finite()),
+            Component::Angle(_)         => None,
+        };
+        number.ok_or_else(|| ParseColorError::InvalidComponent(token.to_string()))
+    }
+
+    //Parses a hue, a number of degrees or an angle, into degrees.
+    fn hue(&self, token: &str) -> Result<f32, ParseColorError> {
+        match self.component(token)? {
+            Component::None                             => Ok(0f32),
+            Component::Number(x) | Component::Angle(x)  => Ok(x),
+            Component::Percentage(_)                    => Err(ParseColorError::InvalidComponent(token.to_string())),
+        }
+    }
+
+    //Parses the alpha of the color. Without one, the color is fully opaque or, for relative
+    //colors, has the alpha of its origin color.
+    fn alpha(&self) -> Result<f32, ParseColorError> {
+        match (self.alpha, self.origin) {
+            (Some(token), _)        => Ok(self.number_or_percentage(token, 1f32)?.clamp(0f32, 1f32)),
+            (None, Some(origin))    => Ok(origin.alpha()),
+            (None, None)            => Ok(1f32),
+        }
+    }
+}
+
+//Parses a hexadecimal color, a named color, a color function, which may be a relative color, or
+//a `var()` function resolved with the given function.
+pub(super) fn parse(css: &str, variables: &dyn Fn(&str) -> Option<CssColor>) -> Result<CssColor, ParseColorError> {
+    let css = css.trim();
+    if css.starts_with('#') {
+        return css.parse::<Srgba>().map(CssColor::Rgb).map_err(ParseColorError::Hex);
+    }
+    let Some((name, arguments)) = css.split_once('(') else {
+        return keyword(css);
+    };
+    let arguments = arguments.strip_suffix(')').ok_or(ParseColorError::InvalidSyntax)?;
+    if name.eq_ignore_ascii_case("var") {
+        return variable(arguments, variables);
+    }
+    let mut arguments = split_arguments(arguments)?;
+    if !arguments.legacy && arguments.components.first().is_some_and(|x| x.eq_ignore_ascii_case("from")) {
+        let origin = arguments.components.get(1).ok_or(ParseColorError::InvalidSyntax)?;
+        arguments.origin = Some(parse(origin, variables)?);
+        arguments.components.drain(..2);
+    }
+    match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba"  => rgb(arguments),
+        "hsl" | "hsla"  => hsl(arguments),
+        "hwb"           => hwb(arguments),
+        "lab"           => lab(arguments),
+        "lch"           => lch(arguments),
+        "oklab"         => oklab(arguments),
+        "oklch"         => oklch(arguments),
+        "color"         => color(arguments),
+        _               => Err(ParseColorError::UnknownColor(name.to_string())),
+    }
+}
+
+//Parses a named color or `transparent`, transparent black.
+fn keyword(keyword: &str) -> Result<CssColor, ParseColorError> {
+    match keyword.eq_ignore_ascii_case("transparent") {
+        true    => Ok(CssColor::Rgb(Alpha::new(Srgb::new(0, 0, 0), 0f32).unwrap())),
+        false   => named::from_name(keyword).map(CssColor::from).ok_or_else(|| ParseColorError::UnknownColor(keyword.to_string())),
+    }
+}
+
+//Resolves the arguments of a `var()` function, a custom property name with an optional fallback
+//color.
+fn variable(arguments: &str, variables: &dyn Fn(&str) -> Option<CssColor>) -> Result<CssColor, ParseColorError> {
+    let (name, fallback) = match arguments.split_once(',') {
+        Some((name, fallback))  => (name.trim(), Some(fallback)),
+        None                    => (arguments.trim(), None),
+    };
+    if !name.starts_with("--") || name.contains(char::is_whitespace) {
+        return Err(ParseColorError::InvalidSyntax);
+    }
+    match (variables(name), fallback) {
+        (Some(color), _)        => Ok(color),
+        (None, Some(fallback))  => parse(fallback, variables),
+        (None, None)            => Err(ParseColorError::UnknownVariable(name.to_string())),
+    }
+}
+
+//Splits a string on the characters matching a separator outside of parentheses.
+fn split_top_level(string: &str, separator: impl Fn(char) -> bool) -> Vec<&str> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut split = Vec::new();
+    for (i, character) in string.char_indices() {
+        match character {
+            '('                                     => depth += 1,
+            ')'                                     => depth -= 1,
+            _ if depth == 0 && separator(character) => {
+                split.push(string[start..i].trim());
+                start = i + character.len_utf8();
+            },
+            _                                       => (),
+        }
+    }
+    split.push(string[start..].trim());
+    split
+}
+
+//Splits the arguments of a color function into its components and alpha. The legacy syntax
+//separates all of them with commas, the modern one separates components with spaces and the
+//alpha with a slash.
+fn split_arguments(arguments: &str) -> Result<Arguments<'_>, ParseColorError> {
+    let single = |x: &str| !x.is_empty() && split_top_level(x, char::is_whitespace).len() == 1;
+    let commas = split_top_level(arguments, |x| x == ',');
+    let (components, alpha, legacy) = match commas.len() {
+        1   => match split_top_level(arguments, |x| x == '/')[..] {
+            [components]            => (split_top_level(components, char::is_whitespace), None, false),
+            [components, alpha]     => (split_top_level(components, char::is_whitespace), Some(alpha), false),
+            _                       => return Err(ParseColorError::InvalidSyntax),
+        },
+        _   => {
+            if !commas.iter().all(|x| single(x)) || commas.iter().any(|x| x.eq_ignore_ascii_case("none")) {
+                return Err(ParseColorError::InvalidSyntax);
+            }
+            match commas[..] {
+                [r, g, b, alpha]    => (vec![r, g, b], Some(alpha), true),
+                _                   => (commas, None, true),
+            }
+        },
+    };
+    if alpha.is_some_and(|x| !single(x)) {
+        return Err(ParseColorError::InvalidSyntax);
+    }
+    let components = components.into_iter().filter(|x| !x.is_empty()).collect();
+    Ok(Arguments { components, alpha, legacy, origin: None, channels: Vec::new() })
+}
+
+//CIELAB components relative to D50 of an origin color. Origin colors too far from the gamut of
+//real colors may give components which aren't finite.
+fn cielab(origin: CssColor) -> [f32; 3] {
+    match origin {
+        CssColor::Lab(color)    => color.color().into(),
+        CssColor::Lch(color)    => Cielab::from(color.color()).into(),
+        _                       => cielab::from_ciexyz::<D50>(origin.to_ciexyz()).into(),
+    }
+}
+
+//Oklab components of an origin color. Origin colors too far from the gamut of real colors may
+//give components which aren't finite.
+fn oklab_color(origin: CssColor) -> [f32; 3] {
+    match origin {
+        CssColor::Oklab(color)  => color.color().into(),
+        CssColor::Oklch(color)  => Oklab::from(color.color()).into(),
+        _                       => oklab::from_ciexyz(origin.to_ciexyz()),
+    }
+}
+
+//Polar form of lightness and a and b components, with the hue in degrees between 0 and 360.
+fn polar(lab: [f32; 3]) -> [f32; 3] {
+    let [l, a, b] = lab;
+    [l, f32::sqrt(a * a + b * b), f32::atan2(b, a).to_degrees().rem_euclid(360f32)]
+}
+
+//Creates an sRGB color with an alpha channel from components between 0 and 1.
+fn srgba(components: [f32; 3], alpha: f32) -> CssColor {
+    let srgb = Srgb::from(components.map(|x| x.clamp(0f32, 1f32)));
+    CssColor::Rgb(Alpha::new(srgb, alpha).unwrap())
+}
+
+//sRGB components between 0 and 1 of an origin color.
+fn rgb_components(origin: CssColor) -> [f32; 3] {
+    Srgba::from(origin).color().into()
+}
+
+//Parses the arguments of `rgb()` and `rgba()`. The legacy syntax doesn't allow mixing numbers
+//and percentages.
+fn rgb(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    arguments.bind(["r", "g", "b"], |origin| rgb_components(origin).map(|x| x * 255f32));
+    let components = arguments.components(true)?;
+    let percentages = components.iter().filter(|x| x.ends_with('%')).count();
+    if arguments.legacy && percentages != 0 && percentages != 3 {
+        return Err(ParseColorError::InvalidSyntax);
+    }
+    let mut rgb = [0f32; 3];
+    for (channel, component) in rgb.iter_mut().zip(components) {
+        *channel = arguments.number_or_percentage(component, 255f32)? / 255f32;
+    }
+    Ok(srgba(rgb, arguments.alpha()?))
+}
+
+//Parses the arguments of `hsl()` and `hsla()`. The legacy syntax requires percentages for the
+//saturation and the lightness.
+fn hsl(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    arguments.bind(["h", "s", "l"], |origin| rgb_to_hsl(rgb_components(origin)));
+    let [h, s, l] = arguments.components(true)?;
+    if arguments.legacy && !(s.ends_with('%') && l.ends_with('%')) {
+        return Err(ParseColorError::InvalidSyntax);
+    }
+    let s = arguments.number_or_percentage(s, 100f32)?.clamp(0f32, 100f32) / 100f32;
+    let l = arguments.number_or_percentage(l, 100f32)?.clamp(0f32, 100f32) / 100f32;
+    Ok(srgba(hsl_to_rgb(arguments.hue(h)?, s, l), arguments.alpha()?))
+}
+
+//Parses the arguments of `hwb()`.
+fn hwb(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    arguments.bind(["h", "w", "b"], |origin| {
+        let rgb = rgb_components(origin);
+        let [hue, _, _] = rgb_to_hsl(rgb);
+        let whiteness = rgb.into_iter().fold(1f32, f32::min);
+        let blackness = 1f32 - rgb.into_iter().fold(0f32, f32::max);
+        [hue, whiteness * 100f32, blackness * 100f32]
+    });
+    let [h, w, b] = arguments.components(false)?;
+    let w = arguments.number_or_percentage(w, 100f32)?.clamp(0f32, 100f32) / 100f32;
+    let b = arguments.number_or_percentage(b, 100f32)?.clamp(0f32, 100f32) / 100f32;
+    //Whiteness and blackness adding up to more than 100% give a gray.
+    let rgb = match w + b >= 1f32 {
+        true    => [w / (w + b); 3],
+        false   => hsl_to_rgb(arguments.hue(h)?, 1f32, 0.5f32).map(|x| x * (1f32 - w - b) + w),
+    };
+    Ok(srgba(rgb, arguments.alpha()?))
+}
+
+//Parses the arguments of `lab()`. 100% is 100 for the lightness and 125 for a and b.
+fn lab(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    arguments.bind(["l", "a", "b"], cielab);
+    let [l, a, b] = arguments.components(false)?;
+    let l = arguments.number_or_percentage(l, 100f32)?.clamp(0f32, 100f32);
+    let [a, b] = [arguments.number_or_percentage(a, 125f32)?, arguments.number_or_percentage(b, 125f32)?];
+    let lab = Cielab::with_reference_white(l, a, b, D50).unwrap();
+    Ok(CssColor::Lab(Alpha::new(lab, arguments.alpha()?).unwrap()))
+}
+
+//Parses the arguments of `lch()`. 100% is 100 for the lightness and 150 for the chroma.
+fn lch(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    arguments.bind(["l", "c", "h"], |origin| polar(cielab(origin)));
+    let [l, c, h] = arguments.components(false)?;
+    let l = arguments.number_or_percentage(l, 100f32)?.clamp(0f32, 100f32);
+    let c = arguments.number_or_percentage(c, 150f32)?.max(0f32);
+    let lch = Cielch::with_reference_white(l, c, arguments.hue(h)?.to_radians(), D50).unwrap();
+    Ok(CssColor::Lch(Alpha::new(lch, arguments.alpha()?).unwrap()))
+}
+
+//Parses the arguments of `oklab()`. 100% is 1 for the lightness and 0.4 for a and b.
+fn oklab(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    arguments.bind(["l", "a", "b"], oklab_color);
+    let [l, a, b] = arguments.components(false)?;
+    let l = arguments.number_or_percentage(l, 1f32)?.clamp(0f32, 1f32);
+    let [a, b] = [arguments.number_or_percentage(a, 0.4f32)?, arguments.number_or_percentage(b, 0.4f32)?];
+    let oklab = Oklab::new(l, a, b).unwrap();
+    Ok(CssColor::Oklab(Alpha::new(oklab, arguments.alpha()?).unwrap()))
+}
+
+//Parses the arguments of `oklch()`. 100% is 1 for the lightness and 0.4 for the chroma.
+fn oklch(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    arguments.bind(["l", "c", "h"], |origin| polar(oklab_color(origin)));
+    let [l, c, h] = arguments.components(false)?;
+    let l = arguments.number_or_percentage(l, 1f32)?.clamp(0f32, 1f32);
+    let c = arguments.number_or_percentage(c, 0.4f32)?.max(0f32);
+    let oklch = Oklch::new(l, c, arguments.hue(h)?.to_radians()).unwrap();
+    Ok(CssColor::Oklch(Alpha::new(oklch, arguments.alpha()?).unwrap()))
+}
+
+//Parses the arguments of `color()`, the name of a predefined color space followed by its
+//components. 100% is 1 for all components.
+fn color(mut arguments: Arguments) -> Result<CssColor, ParseColorError> {
+    if arguments.legacy || arguments.components.is_empty() {
+        return Err(ParseColorError::InvalidSyntax);
+    }
+    let name = arguments.components.remove(0);
+    let space = match name.eq_ignore_ascii_case("xyz") {
+        true    => PredefinedSpace::XyzD65,
+        false   => PredefinedSpace::ALL.into_iter()
+            .find(|x| x.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| ParseColorError::UnknownColorSpace(name.to_string()))?,
+    };
+    let names = match space.is_rgb() {
+        true    => ["r", "g", "b"],
+        false   => ["x", "y", "z"],
+    };
+    arguments.bind(names, |origin| space.from_ciexyz(origin.to_ciexyz()));
+    let mut values = [0f32; 3];
+    for (value, component) in values.iter_mut().zip(arguments.components(false)?) {
+        *value = arguments.number_or_percentage(component, 1f32)?;
+    }
+    Ok(CssColor::Color(space, values, arguments.alpha()?))
+}
+
+//Conversion of a hue in degrees, a saturation and a lightness between 0 and 1 to sRGB components.
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [f32; 3] {
+    let amount = saturation * lightness.min(1f32 - lightness);
+    [0f32, 8f32, 4f32].map(|n| {
+        let k = (n + hue / 30f32).rem_euclid(12f32);
+        lightness - amount * (k - 3f32).min(9f32 - k).clamp(-1f32, 1f32)
+    })
+}
+
+//Conversion of sRGB components to a hue in degrees, a saturation and a lightness between 0 and
+//100. The hue of grays is 0.
+fn rgb_to_hsl(rgb: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2f32;
+    let delta = max - min;
+    if delta == 0f32 {
+        return [0f32, 0f32, lightness * 100f32];
+    }
+    let saturation = (max - lightness) / lightness.min(1f32 - lightness);
+    let hue = match max {
+        _ if max == r   => (g - b) / delta,
+        _ if max == g   => (b - r) / delta + 2f32,
+        _               => (r - g) / delta + 4f32,
+    };
+    [(hue * 60f32).rem_euclid(360f32), saturation * 100f32, lightness * 100f32]
+}
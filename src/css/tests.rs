@@ -0,0 +1,253 @@
+use super::*;
+
+fn parse(css: &str) -> CssColor {
+    css.parse().unwrap()
+}
+
+fn srgba(css: &str) -> [u8; 4] {
+    Srgba::from(parse(css)).into()
+}
+
+#[test]
+fn legacy_colors() {
+    for css in [
+        "#0a141e",
+        "rgb(10, 20, 30)",
+        "rgba(10, 20, 30, 1)",
+        "RGB(10 20 30)",
+        "rgb(3.9216% 7.8431% 11.7647%)",
+        "rgb(10 20 30 / 100%)",
+        "rgb(10 7.8431% 30)",
+    ] {
+        assert_eq!(srgba(css), [10, 20, 30, 255], "{css}");
+    }
+    assert_eq!(srgba("rgb(300 -20 none)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("rgba(10, 20, 30, 0.5)")[3], 128);
+    assert_eq!(srgba("#0a141e80")[3], 128);
+}
+
+#[test]
+fn keywords() {
+    assert_eq!(srgba("rebeccapurple"), [0x66, 0x33, 0x99, 255]);
+    assert_eq!(srgba(" LightGoldenRodYellow "), [250, 250, 210, 255]);
+    assert_eq!(srgba("transparent"), [0, 0, 0, 0]);
+    assert_eq!(parse("transparent").to_string(), "rgba(0, 0, 0, 0)");
+    assert_eq!(srgba("rgb(from teal r g 255)"), [0, 128, 255, 255]);
+}
+
+#[test]
+fn hsl_and_hwb() {
+    assert_eq!(srgba("hsl(120, 100%, 50%)"), [0, 255, 0, 255]);
+    assert_eq!(srgba("hsl(0.5turn 100 50)"), [0, 255, 255, 255]);
+    assert_eq!(srgba("hsla(240deg, 100%, 25%, 0.5)"), [0, 0, 128, 128]);
+    assert_eq!(srgba("hsl(none 0% 100%)"), [255, 255, 255, 255]);
+    assert_eq!(srgba("hsl(-120 100% 50%)"), [0, 0, 255, 255]);
+    assert_eq!(srgba("hwb(0 0% 0%)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("hwb(200grad 20% 20%)"), [51, 204, 204, 255]);
+    assert_eq!(srgba("hwb(30 60% 60%)"), [128, 128, 128, 255]);
+}
+
+#[test]
+fn lab_and_lch() {
+    let CssColor::Lab(lab) = parse("lab(50% 100% -50% / 0.25)") else { panic!() };
+    let [l, a, b]: [f32; 3] = lab.color().into();
+    assert_eq!([l, a, b, lab.alpha()], [50.0, 125.0, -62.5, 0.25]);
+    let CssColor::Lch(lch) = parse("lch(120 -10 1rad)") else { panic!() };
+    assert_eq!([lch.color().lightness(), lch.color().chroma(), lch.color().hue()], [100.0, 0.0, 1.0]);
+    assert_eq!(srgba("lab(54.29 80.82 69.88)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("lch(54.29 106.84 40.85)"), [255, 0, 0, 255]);
+}
+
+#[test]
+fn oklab_and_oklch() {
+    let CssColor::Oklab(oklab) = parse("oklab(50% -100% 0.1)") else { panic!() };
+    assert_eq!(<[f32; 3]>::from(oklab.color()), [0.5, -0.4, 0.1]);
+    let CssColor::Oklch(oklch) = parse("oklch(0.5 50% 90)") else { panic!() };
+    assert_eq!([oklch.color().lightness(), oklch.color().chroma()], [0.5, 0.2]);
+    assert_eq!(srgba("oklab(0.62796 0.22486 0.12585)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("oklch(0.45201 0.31321 264.05)"), [0, 0, 255, 255]);
+}
+
+#[test]
+fn predefined_spaces() {
+    for space in PredefinedSpace::ALL {
+        let css = format!("color({} 0.2 0.4 0.6)", space.name());
+        let CssColor::Color(parsed, _, _) = parse(&css) else { panic!() };
+        assert_eq!(parsed, space);
+        assert_eq!(parse(&css).to_string(), css);
+    }
+    assert_eq!(srgba("color(srgb 1 0.50196 0)"), [255, 128, 0, 255]);
+    assert_eq!(srgba("color(srgb-linear 1 0.21586 0)"), [255, 128, 0, 255]);
+    assert_eq!(srgba("color(xyz 0.41239 0.21264 0.01933)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("color(xyz-d50 0.43607 0.22249 0.01392)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("color(display-p3 0.91749 0.20029 0.13856)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("color(a98-rgb 0.85859 0 0)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("color(prophoto-rgb 0.70226 0.27562 0.10355)"), [255, 0, 0, 255]);
+    assert_eq!(srgba("color(rec2020 0.79198 0.23098 0.07376)"), [255, 0, 0, 255]);
+    //Colors outside of the sRGB gamut have their chroma reduced.
+    let [r, g, b, _] = srgba("color(display-p3 1 0 0)");
+    assert!(r > 240 && g < 30 && b < 30);
+    //Components outside of the gamut are kept.
+    assert_eq!(parse("color(srgb 1.5 -1 50%)").to_string(), "color(srgb 1.5 -1 0.5)");
+    assert_eq!(parse("color(xyz -0.1 0.5 2)").to_string(), "color(xyz-d65 -0.1 0.5 2)");
+    let [r, g, b, _] = srgba("color(srgb 1.2 0 0)");
+    assert!(r == 255 && g < 128 && b < 128);
+}
+
+#[test]
+fn gamut_mapping() {
+    //The chroma of colors far outside of the sRGB gamut is reduced at constant lightness, they
+    //don't become black or white.
+    for css in ["oklch(0.5 2 0)", "lab(50 1e30 1e30)", "lch(50 1e30 0)", "oklab(0.5 1e30 1e30)"] {
+        let color = Srgba::from(parse(css)).color();
+        let lightness = Cielab::<D50>::from(color).lightness();
+        assert!((35f32..=65f32).contains(&lightness), "{css}");
+    }
+    assert_eq!(srgba("oklch(1.5 0.4 30)"), [255, 255, 255, 255]);
+    assert_eq!(srgba("lab(0 100 100)"), [0, 0, 0, 255]);
+}
+
+#[test]
+fn serialization() {
+    assert_eq!(parse("#0A141E").to_string(), "rgb(10, 20, 30)");
+    assert_eq!(parse("hsl(0 100% 50% / 0.25)").to_string(), "rgba(255, 0, 0, 0.25)");
+    assert_eq!(parse("lab(50% -0 25 / 50%)").to_string(), "lab(50 0 25 / 0.5)");
+    assert_eq!(parse("lch(50 30 -90)").to_string(), "lch(50 30 270)");
+    assert_eq!(parse("oklab(0.5 0.1 -0.1)").to_string(), "oklab(0.5 0.1 -0.1)");
+    assert_eq!(parse("oklch(40% 0.1 450deg / none)").to_string(), "oklch(0.4 0.1 90 / 0)");
+    for css in ["rgba(1, 2, 3, 0.4)", "lab(12.5 -30 40.25)", "lch(80 20 359.5 / 0.1)", "oklch(0.9 0.05 10)", "color(display-p3 0 1 0.5)"] {
+        assert_eq!(parse(css).to_string(), css);
+        assert_eq!(parse(&parse(css).to_string()), parse(css));
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!("#12345".parse::<CssColor>(), Err(ParseColorError::Hex(crate::error::ParseHexError::InvalidLength(5))));
+    assert_eq!("reddish".parse::<CssColor>(), Err(ParseColorError::UnknownColor(String::from("reddish"))));
+    assert_eq!("hsv(1 2 3)".parse::<CssColor>(), Err(ParseColorError::UnknownColor(String::from("hsv"))));
+    assert_eq!("color(cmyk 1 2 3)".parse::<CssColor>(), Err(ParseColorError::UnknownColorSpace(String::from("cmyk"))));
+    assert_eq!("rgb(1 2 3deg)".parse::<CssColor>(), Err(ParseColorError::InvalidComponent(String::from("3deg"))));
+    assert_eq!("lch(50 30 10%)".parse::<CssColor>(), Err(ParseColorError::InvalidComponent(String::from("10%"))));
+    assert_eq!("rgb(1 2 x)".parse::<CssColor>(), Err(ParseColorError::InvalidComponent(String::from("x"))));
+    for css in [
+        "rgb(1 2 3",
+        "rgb(1 2)",
+        "rgb(1 2 3 4)",
+        "rgb(1, 2 3)",
+        "rgb(1, 2, 3,)",
+        "rgb(1, 2%, 3)",
+        "rgb(1, 2, none)",
+        "rgb(1 2 3 / 0.5 0.5)",
+        "hsl(1, 2, 3%)",
+        "hwb(1, 2%, 3%)",
+        "lab(1, 2, 3)",
+        "color(srgb, 1, 2, 3)",
+        "color()",
+    ] {
+        assert_eq!(css.parse::<CssColor>(), Err(ParseColorError::InvalidSyntax), "{css}");
+    }
+}
+
+#[test]
+fn overflows() {
+    for css in ["oklch(0.5 0.3 1e38turn)", "lch(50 40 1e38turn)", "lab(50 3e38% 0)", "hsl(4e38grad 100% 50%)"] {
+        assert!(matches!(css.parse::<CssColor>(), Err(ParseColorError::InvalidComponent(_))), "{css}");
+    }
+    assert!(matches!("lch(from lab(50 3e38 3e38) l c h)".parse::<CssColor>(), Err(ParseColorError::InvalidComponent(_))));
+    assert!(matches!("lab(from oklab(0.5 1e30 1e30) l a b)".parse::<CssColor>(), Err(ParseColorError::InvalidComponent(_))));
+    assert!("oklch(from lab(50 1e30 1e30) l c h)".parse::<CssColor>().is_ok());
+    let color = parse("lab(50 3e38 -3e38)");
+    assert!(!color.to_string().contains("inf"));
+    assert_eq!(Srgba::from(color).alpha(), 1.0);
+    assert_eq!(parse("lch(50 40 1e37deg)").to_string().parse::<CssColor>().map(|_| ()), Ok(()));
+    assert_eq!(Srgba::from(parse("oklch(0.5 0.3 1e38deg)")).alpha(), 1.0);
+}
+
+#[test]
+fn relative_colors() {
+    assert_eq!(srgba("rgb(from #0a141e r g b)"), [10, 20, 30, 255]);
+    assert_eq!(parse("lab(from white l a b)").to_string(), "lab(100 0 0)");
+    assert_eq!(parse("lch(from #fff l c h)").to_string(), "lch(100 0 0)");
+    assert_eq!(srgba("rgb(from #0a141e b g r / 50%)"), [30, 20, 10, 128]);
+    assert_eq!(srgba("rgb(from rgba(10, 20, 30, 0.5) r g b)")[3], 128);
+    assert_eq!(srgba("rgb(from #0a141e calc(r * 2) calc((g + 10) * 2) 255 / calc(alpha - 0.5))"), [20, 60, 255, 128]);
+    assert_eq!(srgba("hsl(from #f00 calc(h + 240) s l)"), [0, 0, 255, 255]);
+    assert_eq!(srgba("hwb(from #80ff80 h w b)"), [128, 255, 128, 255]);
+    assert_eq!(srgba("color(from #f80 srgb r g b)"), [255, 136, 0, 255]);
+    assert_eq!(srgba("color(from #f80 xyz x y z)"), [255, 136, 0, 255]);
+    assert_eq!(parse("lab(from lch(50 30 90) l a b)").to_string(), "lab(50 0 30)");
+    assert_eq!(parse("lch(from lab(50 0 30) calc(l + 10) c calc(h + 90))").to_string(), "lch(60 30 180)");
+    assert_eq!(parse("oklch(from oklab(0.5 0.1 0) l calc(c / 2) calc(h - 90))").to_string(), "oklch(0.5 0.05 270)");
+    assert_eq!(parse("oklab(from oklch(0.7 0.1 none) l a calc(b + 0.05) / none)").to_string(), "oklab(0.7 0.1 0.05 / 0)");
+    //Relative colors can be nested and mix color spaces.
+    assert_eq!(srgba("rgb(from oklch(from #336699 l c calc(h + 360)) r g b)"), [0x33, 0x66, 0x99, 255]);
+    assert_eq!(srgba("rgb(from lch(from #336699 l c h) r g b)"), [0x33, 0x66, 0x99, 255]);
+}
+
+#[test]
+fn calc() {
+    let cases = [
+        ("lab(calc(25 + 25) 0 0)", 50.0),
+        ("lab(calc(100 / 4 * 2) 0 0)", 50.0),
+        ("lab(calc(2 * (10 + 15)) 0 0)", 50.0),
+        ("lab(calc(10% * 5) 0 0)", 50.0),
+        ("lab(calc(70 - 2 * 10) 0 0)", 50.0),
+        ("lab(calc(-10 + 60) 0 0)", 50.0),
+        ("lab(CALC(calc(5e1)) 0 0)", 50.0),
+    ];
+    for (css, lightness) in cases {
+        let CssColor::Lab(lab) = parse(css) else { panic!() };
+        assert!((lab.color().lightness() - lightness).abs() < 1e-4, "{css}");
+    }
+    let CssColor::Lch(lch) = parse("lch(50 10 calc(0.25turn + 90deg))") else { panic!() };
+    assert!((lch.color().hue() - std::f32::consts::PI).abs() < 1e-6);
+    let CssColor::Lch(lch) = parse("lch(50 10 calc(pi * 1rad))") else { panic!() };
+    assert!((lch.color().hue() - std::f32::consts::PI).abs() < 1e-6);
+    for css in [
+        "lab(calc(10 + 10%) 0 0)",
+        "lab(calc(10 / 0) 0 0)",
+        "lab(calc(10 10) 0 0)",
+        "lab(calc((10 + 10) 0 0)",
+        "lab(calc() 0 0)",
+        "lab(calc(l) 0 0)",
+        "lab(l 0 0)",
+        "lch(50 10 calc(h + 10deg))",
+        "rgb(from #000 x g b)",
+    ] {
+        assert!(matches!(css.parse::<CssColor>(), Err(ParseColorError::InvalidComponent(_) | ParseColorError::InvalidSyntax)), "{css}");
+    }
+    //Expressions overflowing to infinity or giving NaN are invalid.
+    let nan = "calc(1e38*10 - 1e38*10)";
+    for css in [
+        format!("lab({nan} 0 0)"),
+        format!("lab(50 {nan} 0)"),
+        format!("oklab(0.5 0 {nan})"),
+        format!("lch(50 10 {nan})"),
+        format!("rgb(0 0 0 / {nan})"),
+        format!("color(srgb {nan} 0 0)"),
+    ] {
+        assert_eq!(css.parse::<CssColor>(), Err(ParseColorError::InvalidComponent(String::from(nan))), "{css}");
+    }
+    let infinity = "calc(1e38deg * 10)";
+    assert_eq!(format!("lch(50 10 {infinity})").parse::<CssColor>(), Err(ParseColorError::InvalidComponent(String::from(infinity))));
+}
+
+#[test]
+fn variables() {
+    let variables = |name: &str| match name {
+        "--red"     => Some(parse("#f00")),
+        "--blue"    => Some(parse("color(srgb 0 0 1 / 0.5)")),
+        _           => None,
+    };
+    let parse = |css| CssColor::parse_with_variables(css, variables).map(|x| <[u8; 4]>::from(Srgba::from(x)));
+    assert_eq!(parse("var(--red)"), Ok([255, 0, 0, 255]));
+    assert_eq!(parse("var( --red )"), Ok([255, 0, 0, 255]));
+    assert_eq!(parse("rgb(from var(--red) g r b)"), Ok([0, 255, 0, 255]));
+    assert_eq!(parse("rgb(from var(--blue) r g b)"), Ok([0, 0, 255, 128]));
+    assert_eq!(parse("var(--green, rgb(0 255 0))"), Ok([0, 255, 0, 255]));
+    assert_eq!(parse("var(--green, var(--red))"), Ok([255, 0, 0, 255]));
+    assert_eq!(parse("var(--green)"), Err(ParseColorError::UnknownVariable(String::from("--green"))));
+    assert_eq!(parse("var(green)"), Err(ParseColorError::InvalidSyntax));
+    assert_eq!("var(--red)".parse::<CssColor>(), Err(ParseColorError::UnknownVariable(String::from("--red"))));
+}
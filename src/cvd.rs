@@ -0,0 +1,524 @@
+//! Simulation of color vision deficiencies, previewing how colors are seen by people with
+//! protanopia, deuteranopia or tritanopia, or their anomalous trichromatic forms, and
+//! daltonization, correcting colors for them, and checking that palettes stay distinguishable
+//! with them.
+//!
+//! All the models work on linear sRGB values, so the simulated colors are always valid sRGB
+//! colors once clamped.
+
+mod palette;
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ciexyz::{
+        self,
+        FROM_SRGB_CONVERSION_MATRIX,
+    },
+    lms::{
+        ConeFundamentals,
+        HuntPointerEstevez,
+    },
+    matrix,
+    srgb::{
+        gamma_compress,
+        FROM_CIEXYZ_CONVERSION_MATRIX,
+    },
+    Srgb,
+};
+
+pub use palette::{
+    check_palette,
+    Conflict,
+    PaletteReport,
+    Vision,
+};
+
+//Identity matrix, in row-major order.
+const IDENTITY: [f32; 9] = [
+    1f32, 0f32, 0f32,
+    0f32, 1f32, 0f32,
+    0f32, 0f32, 1f32,
+];
+
+//Conversion matrix to convert from linear sRGB to Hunt-Pointer-Estévez LMS.
+const LMS_FROM_LINEAR_SRGB: [f32; 9] = matrix::multiply(HuntPointerEstevez::FROM_CIEXYZ, FROM_SRGB_CONVERSION_MATRIX);
+
+//Conversion matrix to convert from Hunt-Pointer-Estévez LMS to linear sRGB.
+const LINEAR_SRGB_FROM_LMS: [f32; 9] = matrix::multiply(FROM_CIEXYZ_CONVERSION_MATRIX, HuntPointerEstevez::TO_CIEXYZ);
+
+/// A color vision deficiency, given by the type of cone that is missing or anomalous.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Deficiency {
+    /// Missing or anomalous long wavelength cones (protanopia and protanomaly).
+    Protan,
+    /// Missing or anomalous medium wavelength cones (deuteranopia and deuteranomaly).
+    Deutan,
+    /// Missing or anomalous short wavelength cones (tritanopia and tritanomaly).
+    Tritan,
+}
+
+impl Deficiency {
+    //Index of the affected cone response in an LMS color.
+    fn cone(&self) -> usize {
+        match self {
+            Deficiency::Protan  => 0,
+            Deficiency::Deutan  => 1,
+            Deficiency::Tritan  => 2,
+        }
+    }
+}
+
+/// A simulator of a color vision deficiency.
+///
+/// The severity of the deficiency ranges from 0 (normal color vision) to 1 (dichromacy, the
+/// cones being missing). The dichromacy models of Brettel et al. and Viénot et al. simulate
+/// anomalous trichromacy by interpolating between the original and dichromat colors, while the
+/// model of Machado et al. is defined for all severities.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Simulator {
+    deficiency: Deficiency,
+    separation: [f32; 3],
+    matrices: [[f32; 9]; 2],
+}
+
+impl Simulator {
+    /// Creates a simulator using the model of Brettel, Viénot and Mollon (1997).
+    ///
+    /// Dichromat colors are projected onto two half-planes of the LMS color space, anchored on
+    /// the neutral axis and monochromatic stimuli seen the same way by normal and dichromat
+    /// observers (475 nm and 575 nm for protans and deutans, 485 nm and 660 nm for tritans).
+    /// This is the most accurate model for tritans.
+    ///
+    /// The severity must be between 0 and 1 included otherwise the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ Deficiency, Simulator }, Srgb };
+    /// let tritanopia = Simulator::brettel(Deficiency::Tritan, 1.0).unwrap();
+    /// let simulated = tritanopia.simulate(Srgb::new(0, 0, 255));
+    /// assert!(Simulator::brettel(Deficiency::Tritan, 1.5).is_none());
+    /// ```
+    pub fn brettel(deficiency: Deficiency, severity: f32) -> Option<Self> {
+        //CIE 1931 xy chromaticities of the anchoring monochromatic stimuli.
+        let anchors = match deficiency {
+            Deficiency::Protan | Deficiency::Deutan => [[0.1096f32, 0.0868f32], [0.4788f32, 0.5202f32]],
+            Deficiency::Tritan                      => [[0.0687f32, 0.2007f32], [0.7300f32, 0.2700f32]],
+        };
+        let [first, second] = anchors.map(|[x, y]| {
+            matrix::transform(HuntPointerEstevez::FROM_CIEXYZ, [x / y, 1f32, (1f32 - x - y) / y])
+        });
+        let white = matrix::transform(HuntPointerEstevez::FROM_CIEXYZ, ciexyz::D65.into());
+        let mut axis = [0f32; 3];
+        axis[deficiency.cone()] = 1f32;
+        let separation = cross(white, axis);
+        //The separation is oriented so that the colors on its positive side use the first plane.
+        let separation = match dot(separation, first) < 0f32 {
+            true    => separation.map(|x| -x),
+            false   => separation,
+        };
+        let matrices = [first, second].map(|anchor| {
+            blend(projection(deficiency, cross(white, anchor)), severity)
+        });
+        let separation = transform_transposed(LMS_FROM_LINEAR_SRGB, separation);
+        match (0f32..=1f32).contains(&severity) {
+            true    => Some(Self { deficiency, separation, matrices }),
+            false   => None,
+        }
+    }
+
+    /// Creates a simulator using the model of Viénot, Brettel and Mollon (1999).
+    ///
+    /// This is a simplification of the model of Brettel et al. for sRGB displays, using a single
+    /// plane of the LMS color space going through the neutral axis and the blue primary for
+    /// protans and deutans, and the red primary for tritans. It is less accurate for tritans.
+    ///
+    /// The severity must be between 0 and 1 included otherwise the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ Deficiency, Simulator }, Srgb };
+    /// let deuteranopia = Simulator::vienot(Deficiency::Deutan, 1.0).unwrap();
+    /// let red = deuteranopia.simulate(Srgb::new(255, 0, 0));
+    /// let green = deuteranopia.simulate(Srgb::new(0, 255, 0));
+    /// ```
+    pub fn vienot(deficiency: Deficiency, severity: f32) -> Option<Self> {
+        let primary = match deficiency {
+            Deficiency::Protan | Deficiency::Deutan => [0f32, 0f32, 1f32],
+            Deficiency::Tritan                      => [1f32, 0f32, 0f32],
+        };
+        let white = matrix::transform(LMS_FROM_LINEAR_SRGB, [1f32, 1f32, 1f32]);
+        let anchor = matrix::transform(LMS_FROM_LINEAR_SRGB, primary);
+        let matrix = blend(projection(deficiency, cross(white, anchor)), severity);
+        Self::from_matrix(deficiency, matrix, severity)
+    }
+
+    /// Creates a simulator using the model of Machado, Oliveira and Fernandes (2009).
+    ///
+    /// The simulation matrices of the model are given for severities by steps of 0.1,
+    /// those in between are linearly interpolated.
+    ///
+    /// The severity must be between 0 and 1 included otherwise the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ Deficiency, Simulator }, Srgb };
+    /// let protanomaly = Simulator::machado(Deficiency::Protan, 0.6).unwrap();
+    /// let simulated = protanomaly.simulate(Srgb::new(255, 0, 0));
+    /// ```
+    pub fn machado(deficiency: Deficiency, severity: f32) -> Option<Self> {
+        let table = match deficiency {
+            Deficiency::Protan  => &MACHADO_PROTAN,
+            Deficiency::Deutan  => &MACHADO_DEUTAN,
+            Deficiency::Tritan  => &MACHADO_TRITAN,
+        };
+        let step = (severity.clamp(0f32, 1f32) * 10f32).floor().min(9f32);
+        let (lower, upper) = (table[step as usize], table[step as usize + 1]);
+        let weight = severity * 10f32 - step;
+        let matrix = [0, 1, 2, 3, 4, 5, 6, 7, 8].map(|i| lower[i] + (upper[i] - lower[i]) * weight);
+        Self::from_matrix(deficiency, matrix, severity)
+    }
+
+    /// Color vision deficiency simulated by the simulator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::cvd::{ Deficiency, Simulator };
+    /// let simulator = Simulator::machado(Deficiency::Tritan, 0.5).unwrap();
+    /// assert_eq!(simulator.deficiency(), Deficiency::Tritan);
+    /// ```
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+
+    /// Simulates how an sRGB color is seen with the color vision deficiency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ Deficiency, Simulator }, Srgb };
+    /// let protanopia = Simulator::machado(Deficiency::Protan, 1.0).unwrap();
+    /// let gray = Srgb::new(128, 128, 128);
+    /// assert_eq!(protanopia.simulate(gray), gray);
+    /// ```
+    pub fn simulate(&self, srgb: Srgb) -> Srgb {
+        compress(self.simulate_linear(linear(srgb)))
+    }
+
+    //Simulation on linear sRGB values, which are clamped to the sRGB gamut.
+    fn simulate_linear(&self, linear: [f32; 3]) -> [f32; 3] {
+        let matrix = match dot(self.separation, linear) >= 0f32 {
+            true    => self.matrices[0],
+            false   => self.matrices[1],
+        };
+        matrix::transform(matrix, linear).map(|x| x.clamp(0f32, 1f32))
+    }
+
+    //Simulator using the same matrix for all colors.
+    fn from_matrix(deficiency: Deficiency, matrix: [f32; 9], severity: f32) -> Option<Self> {
+        match (0f32..=1f32).contains(&severity) {
+            true    => Some(Self { deficiency, separation: [0f32; 3], matrices: [matrix; 2] }),
+            false   => None,
+        }
+    }
+}
+
+/// A daltonizer, recoloring images so that people with a color vision deficiency can tell
+/// apart more of their colors.
+///
+/// The daltonization follows the error redistribution of Fidaner, Lin and Ozguven (2005): the
+/// difference between a color and its simulation, which is the information lost by the
+/// deficiency, is shifted onto the channels the deficiency preserves and added back to the
+/// color. Red-green information is moved to the green and blue channels for protans and deutans,
+/// and blue-yellow information is moved to the red and green channels for tritans.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Daltonizer {
+    simulator: Simulator,
+    shift: [f32; 9],
+}
+
+impl Daltonizer {
+    /// Creates a daltonizer for the color vision deficiency simulated by a simulator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::cvd::{ Daltonizer, Deficiency, Simulator };
+    /// let daltonizer = Daltonizer::new(Simulator::machado(Deficiency::Deutan, 1.0).unwrap());
+    /// ```
+    pub fn new(simulator: Simulator) -> Self {
+        let shift = match simulator.deficiency() {
+            Deficiency::Protan | Deficiency::Deutan => [
+                0f32, 0f32, 0f32,
+                0.7f32, 1f32, 0f32,
+                0.7f32, 0f32, 1f32,
+            ],
+            Deficiency::Tritan                      => [
+                1f32, 0f32, 0.7f32,
+                0f32, 1f32, 0.7f32,
+                0f32, 0f32, 0f32,
+            ],
+        };
+        Self { simulator, shift }
+    }
+
+    /// Corrects an sRGB color for the color vision deficiency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ Daltonizer, Deficiency, Simulator }, Srgb };
+    /// let daltonizer = Daltonizer::new(Simulator::machado(Deficiency::Protan, 1.0).unwrap());
+    /// let corrected = daltonizer.correct(Srgb::new(200, 60, 40));
+    /// let gray = Srgb::new(128, 128, 128);
+    /// assert_eq!(daltonizer.correct(gray), gray);
+    /// ```
+    pub fn correct(&self, srgb: Srgb) -> Srgb {
+        let original = linear(srgb);
+        let simulated = self.simulator.simulate_linear(original);
+        let error = matrix::transform(self.shift, [0, 1, 2].map(|i| original[i] - simulated[i]));
+        compress([0, 1, 2].map(|i| (original[i] + error[i]).clamp(0f32, 1f32)))
+    }
+
+    /// Corrects a buffer of pixels for the color vision deficiency, in place.
+    /// The pixels can be of any type converting to and from sRGB, such as `[u8; 3]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::cvd::{ Daltonizer, Deficiency, Simulator };
+    /// let daltonizer = Daltonizer::new(Simulator::brettel(Deficiency::Deutan, 0.8).unwrap());
+    /// let mut pixels = [[200u8, 60, 40], [90, 140, 40], [128, 128, 128]];
+    /// daltonizer.correct_pixels(&mut pixels);
+    /// assert_eq!(pixels[2], [128, 128, 128]);
+    /// ```
+    pub fn correct_pixels<P: Copy + Into<Srgb> + From<Srgb>>(&self, pixels: &mut [P]) {
+        for pixel in pixels.iter_mut() {
+            *pixel = self.correct((*pixel).into()).into();
+        }
+    }
+}
+
+//Linear values of an sRGB color.
+fn linear(srgb: Srgb) -> [f32; 3] {
+    [srgb.linear_red(), srgb.linear_green(), srgb.linear_blue()]
+}
+
+//sRGB color from linear values.
+fn compress(linear: [f32; 3]) -> Srgb {
+    linear.map(gamma_compress).into()
+}
+
+//Matrix projecting linear sRGB colors along the axis of the affected cone onto the LMS plane
+//of the given normal.
+fn projection(deficiency: Deficiency, normal: [f32; 3]) -> [f32; 9] {
+    let cone = deficiency.cone();
+    let mut lms = IDENTITY;
+    for i in 0..3 {
+        lms[cone * 3 + i] = match i == cone {
+            true    => 0f32,
+            false   => -normal[i] / normal[cone],
+        };
+    }
+    matrix::multiply(LINEAR_SRGB_FROM_LMS, matrix::multiply(lms, LMS_FROM_LINEAR_SRGB))
+}
+
+//Linear interpolation between the identity matrix and a simulation matrix.
+fn blend(matrix: [f32; 9], severity: f32) -> [f32; 9] {
+    [0, 1, 2, 3, 4, 5, 6, 7, 8].map(|i| IDENTITY[i] + (matrix[i] - IDENTITY[i]) * severity)
+}
+
+//Product of the transpose of a 3x3 matrix and a column vector.
+fn transform_transposed(matrix: [f32; 9], vector: [f32; 3]) -> [f32; 3] {
+    let [m11, m12, m13, m21, m22, m23, m31, m32, m33] = matrix;
+    matrix::transform([m11, m21, m31, m12, m22, m32, m13, m23, m33], vector)
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+//Machado et al. simulation matrices in linear RGB for protanomaly, by steps of 0.1 of severity.
+const MACHADO_PROTAN: [[f32; 9]; 11] = [
+    [
+        1f32, 0f32, 0f32,
+        0f32, 1f32, 0f32,
+        0f32, 0f32, 1f32,
+    ],
+    [
+        0.856167f32, 0.182038f32, -0.038205f32,
+        0.029342f32, 0.955115f32, 0.015544f32,
+        -0.00288f32, -0.001563f32, 1.004443f32,
+    ],
+    [
+        0.734766f32, 0.334872f32, -0.069637f32,
+        0.05184f32, 0.919198f32, 0.028963f32,
+        -0.004928f32, -0.004209f32, 1.009137f32,
+    ],
+    [
+        0.630323f32, 0.465641f32, -0.095964f32,
+        0.069181f32, 0.890046f32, 0.040773f32,
+        -0.006308f32, -0.007724f32, 1.014032f32,
+    ],
+    [
+        0.539009f32, 0.579343f32, -0.118352f32,
+        0.082546f32, 0.866121f32, 0.051332f32,
+        -0.007136f32, -0.011959f32, 1.019095f32,
+    ],
+    [
+        0.458064f32, 0.679578f32, -0.137642f32,
+        0.092785f32, 0.846313f32, 0.060902f32,
+        -0.007494f32, -0.016807f32, 1.024301f32,
+    ],
+    [
+        0.38545f32, 0.769005f32, -0.154455f32,
+        0.100526f32, 0.829802f32, 0.069673f32,
+        -0.007442f32, -0.02219f32, 1.029632f32,
+    ],
+    [
+        0.319627f32, 0.849633f32, -0.169261f32,
+        0.106241f32, 0.815969f32, 0.07779f32,
+        -0.007025f32, -0.028051f32, 1.035076f32,
+    ],
+    [
+        0.259411f32, 0.923008f32, -0.18242f32,
+        0.110296f32, 0.80434f32, 0.085364f32,
+        -0.006276f32, -0.034346f32, 1.040622f32,
+    ],
+    [
+        0.203876f32, 0.990338f32, -0.194214f32,
+        0.112975f32, 0.794542f32, 0.092483f32,
+        -0.005222f32, -0.041043f32, 1.046265f32,
+    ],
+    [
+        0.152286f32, 1.052583f32, -0.204868f32,
+        0.114503f32, 0.786281f32, 0.099216f32,
+        -0.003882f32, -0.048116f32, 1.051998f32,
+    ],
+];
+
+//Machado et al. simulation matrices in linear RGB for deuteranomaly, by steps of 0.1 of severity.
+const MACHADO_DEUTAN: [[f32; 9]; 11] = [
+    [
+        1f32, 0f32, 0f32,
+        0f32, 1f32, 0f32,
+        0f32, 0f32, 1f32,
+    ],
+    [
+        0.866435f32, 0.177704f32, -0.044139f32,
+        0.049567f32, 0.939063f32, 0.01137f32,
+        -0.003453f32, 0.007233f32, 0.99622f32,
+    ],
+    [
+        0.760729f32, 0.319078f32, -0.079807f32,
+        0.090568f32, 0.889315f32, 0.020117f32,
+        -0.006027f32, 0.013325f32, 0.992702f32,
+    ],
+    [
+        0.675425f32, 0.43385f32, -0.109275f32,
+        0.125303f32, 0.847755f32, 0.026942f32,
+        -0.00795f32, 0.018572f32, 0.989378f32,
+    ],
+    [
+        0.605511f32, 0.52856f32, -0.134071f32,
+        0.155318f32, 0.812366f32, 0.032316f32,
+        -0.009376f32, 0.023176f32, 0.9862f32,
+    ],
+    [
+        0.547494f32, 0.607765f32, -0.155259f32,
+        0.181692f32, 0.781742f32, 0.036566f32,
+        -0.01041f32, 0.027275f32, 0.983136f32,
+    ],
+    [
+        0.498864f32, 0.674741f32, -0.173604f32,
+        0.205199f32, 0.754872f32, 0.039929f32,
+        -0.011131f32, 0.030969f32, 0.980162f32,
+    ],
+    [
+        0.457771f32, 0.731899f32, -0.18967f32,
+        0.226409f32, 0.731012f32, 0.042579f32,
+        -0.011595f32, 0.034333f32, 0.977261f32,
+    ],
+    [
+        0.422823f32, 0.781057f32, -0.203881f32,
+        0.245752f32, 0.709602f32, 0.044646f32,
+        -0.011843f32, 0.037423f32, 0.974421f32,
+    ],
+    [
+        0.392952f32, 0.82361f32, -0.216562f32,
+        0.263559f32, 0.69021f32, 0.046232f32,
+        -0.01191f32, 0.040281f32, 0.97163f32,
+    ],
+    [
+        0.367322f32, 0.860646f32, -0.227968f32,
+        0.280085f32, 0.672501f32, 0.047413f32,
+        -0.01182f32, 0.04294f32, 0.968881f32,
+    ],
+];
+
+//Machado et al. simulation matrices in linear RGB for tritanomaly, by steps of 0.1 of severity.
+const MACHADO_TRITAN: [[f32; 9]; 11] = [
+    [
+        1f32, 0f32, 0f32,
+        0f32, 1f32, 0f32,
+        0f32, 0f32, 1f32,
+    ],
+    [
+        0.92667f32, 0.092514f32, -0.019184f32,
+        0.021191f32, 0.964503f32, 0.014306f32,
+        0.008437f32, 0.054813f32, 0.93675f32,
+    ],
+    [
+        0.89572f32, 0.13333f32, -0.02905f32,
+        0.029997f32, 0.9454f32, 0.024603f32,
+        0.013027f32, 0.104707f32, 0.882266f32,
+    ],
+    [
+        0.905871f32, 0.127791f32, -0.033662f32,
+        0.026856f32, 0.941251f32, 0.031893f32,
+        0.01341f32, 0.148296f32, 0.838294f32,
+    ],
+    [
+        0.948035f32, 0.08949f32, -0.037526f32,
+        0.014364f32, 0.946792f32, 0.038844f32,
+        0.010853f32, 0.193991f32, 0.795156f32,
+    ],
+    [
+        1.017277f32, 0.027029f32, -0.044306f32,
+        -0.006113f32, 0.958479f32, 0.047634f32,
+        0.006379f32, 0.248708f32, 0.744913f32,
+    ],
+    [
+        1.104996f32, -0.046633f32, -0.058363f32,
+        -0.032137f32, 0.971635f32, 0.060503f32,
+        0.001336f32, 0.317922f32, 0.680742f32,
+    ],
+    [
+        1.193214f32, -0.109812f32, -0.083402f32,
+        -0.058496f32, 0.97941f32, 0.079086f32,
+        -0.002346f32, 0.403492f32, 0.598854f32,
+    ],
+    [
+        1.257728f32, -0.139648f32, -0.118081f32,
+        -0.078003f32, 0.975409f32, 0.102594f32,
+        -0.003316f32, 0.501214f32, 0.502102f32,
+    ],
+    [
+        1.278864f32, -0.125333f32, -0.153531f32,
+        -0.084748f32, 0.957674f32, 0.127074f32,
+        -0.000989f32, 0.601151f32, 0.399838f32,
+    ],
+    [
+        1.255528f32, -0.076749f32, -0.178779f32,
+        -0.078411f32, 0.930809f32, 0.147602f32,
+        0.004733f32, 0.691367f32, 0.3039f32,
+    ],
+];
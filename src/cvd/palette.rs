@@ -0,0 +1,233 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    difference::{
+        Ciede2000,
+        ColorDifference,
+    },
+    Srgb,
+};
+use super::{
+    Deficiency,
+    Simulator,
+};
+
+/// A kind of color vision under which a palette is checked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Vision {
+    /// Normal color vision.
+    Normal,
+    /// Dichromacy of the given type.
+    Deficient(Deficiency),
+}
+
+impl Vision {
+    /// All the kinds of color vision checked by `check_palette`.
+    pub const ALL: [Vision; 4] = [
+        Vision::Normal,
+        Vision::Deficient(Deficiency::Protan),
+        Vision::Deficient(Deficiency::Deutan),
+        Vision::Deficient(Deficiency::Tritan),
+    ];
+
+    //Simulator of this kind of color vision, None for normal color vision.
+    fn simulator(&self) -> Option<Simulator> {
+        match self {
+            Vision::Normal                  => None,
+            Vision::Deficient(deficiency)   => Simulator::brettel(*deficiency, 1f32),
+        }
+    }
+}
+
+/// A pair of colors of a palette which are too close to each other under some color vision.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Conflict {
+    first: usize,
+    second: usize,
+    vision: Vision,
+    difference: f32,
+}
+
+impl Conflict {
+    /// Index in the palette of the first color of the pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::check_palette, Srgb };
+    /// let palette = [Srgb::new(255, 0, 0), Srgb::new(0, 0, 255), Srgb::new(254, 0, 0)];
+    /// assert_eq!(check_palette(&palette, 10.0).conflicts()[0].first(), 0);
+    /// ```
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// Index in the palette of the second color of the pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::check_palette, Srgb };
+    /// let palette = [Srgb::new(255, 0, 0), Srgb::new(0, 0, 255), Srgb::new(254, 0, 0)];
+    /// assert_eq!(check_palette(&palette, 10.0).conflicts()[0].second(), 2);
+    /// ```
+    pub fn second(&self) -> usize {
+        self.second
+    }
+
+    /// Color vision under which the colors are too close.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ check_palette, Vision }, Srgb };
+    /// let palette = [Srgb::new(255, 0, 0), Srgb::new(0, 0, 255), Srgb::new(254, 0, 0)];
+    /// assert_eq!(check_palette(&palette, 10.0).conflicts()[0].vision(), Vision::Normal);
+    /// ```
+    pub fn vision(&self) -> Vision {
+        self.vision
+    }
+
+    /// CIEDE2000 color difference between the colors as seen under the color vision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::check_palette, Srgb };
+    /// let palette = [Srgb::new(255, 0, 0), Srgb::new(0, 0, 255), Srgb::new(254, 0, 0)];
+    /// assert!(check_palette(&palette, 10.0).conflicts()[0].difference() < 1.0);
+    /// ```
+    pub fn difference(&self) -> f32 {
+        self.difference
+    }
+}
+
+/// The result of checking the distinguishability of the colors of a palette.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PaletteReport {
+    conflicts: Vec<Conflict>,
+    minimum_differences: [Option<f32>; 4],
+    pairs: usize,
+}
+
+impl PaletteReport {
+    /// All the conflicting pairs of colors, under all the kinds of color vision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ check_palette, Vision }, Srgb };
+    /// let palette = [Srgb::new(180, 80, 60), Srgb::new(110, 130, 60)];
+    /// let report = check_palette(&palette, 10.0);
+    /// assert!(!report.conflicts().is_empty());
+    /// assert!(report.conflicts().iter().all(|x| (x.first(), x.second()) == (0, 1) && x.vision() != Vision::Normal));
+    /// ```
+    pub fn conflicts(&self) -> &[Conflict] {
+        &self.conflicts
+    }
+
+    /// The conflicting pairs of colors under a given kind of color vision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ check_palette, Deficiency, Vision }, Srgb };
+    /// let palette = [Srgb::new(180, 80, 60), Srgb::new(110, 130, 60)];
+    /// let report = check_palette(&palette, 10.0);
+    /// assert_eq!(report.conflicts_under(Vision::Normal).count(), 0);
+    /// assert_eq!(report.conflicts_under(Vision::Deficient(Deficiency::Deutan)).count(), 1);
+    /// ```
+    pub fn conflicts_under(&self, vision: Vision) -> impl Iterator<Item = &Conflict> {
+        self.conflicts.iter().filter(move |conflict| conflict.vision == vision)
+    }
+
+    /// Smallest CIEDE2000 color difference between two colors of the palette under a given kind
+    /// of color vision. The return value is None if the palette has less than two colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::{ check_palette, Vision }, Srgb };
+    /// let palette = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let report = check_palette(&palette, 10.0);
+    /// assert!(report.minimum_difference(Vision::Normal).unwrap() > 99.0);
+    /// assert!(check_palette(&palette[..1], 10.0).minimum_difference(Vision::Normal).is_none());
+    /// ```
+    pub fn minimum_difference(&self, vision: Vision) -> Option<f32> {
+        let index = Vision::ALL.iter().position(|x| *x == vision).unwrap();
+        self.minimum_differences[index]
+    }
+
+    /// Whether no pair of colors conflicts under any kind of color vision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::check_palette, Srgb };
+    /// let palette = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// assert!(check_palette(&palette, 10.0).passes());
+    /// ```
+    pub fn passes(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Summary score of the palette, the fraction of its pairs of colors which are
+    /// distinguishable, counting each pair once per kind of color vision.
+    /// The score goes from 0 (no distinguishable pair) to 1 (the palette passes the check).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cvd::check_palette, Srgb };
+    /// let palette = [Srgb::new(180, 80, 60), Srgb::new(110, 130, 60)];
+    /// let score = check_palette(&palette, 10.0).score();
+    /// assert!(score > 0.0 && score < 1.0);
+    /// ```
+    pub fn score(&self) -> f32 {
+        match self.pairs {
+            0   => 1f32,
+            _   => 1f32 - self.conflicts.len() as f32 / (self.pairs * Vision::ALL.len()) as f32,
+        }
+    }
+}
+
+/// Checks that the colors of a palette can be told apart under normal color vision and with
+/// each type of dichromacy.
+///
+/// Every pair of colors is compared under each kind of color vision with the CIEDE2000 color
+/// difference, dichromacies being simulated with the model of Brettel et al. Pairs whose
+/// difference is below the threshold are reported as conflicts.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ cvd::check_palette, Srgb };
+/// let palette = [
+///     Srgb::new(0x00, 0x72, 0xb2),
+///     Srgb::new(0xe6, 0x9f, 0x00),
+///     Srgb::new(0x00, 0x00, 0x00),
+/// ];
+/// assert!(check_palette(&palette, 10.0).passes());
+/// ```
+pub fn check_palette(colors: &[Srgb], threshold: f32) -> PaletteReport {
+    let mut conflicts = Vec::new();
+    let mut minimum_differences = [None; 4];
+    for (vision, minimum) in Vision::ALL.into_iter().zip(minimum_differences.iter_mut()) {
+        let simulator = vision.simulator();
+        let seen: Vec<Srgb> = colors.iter()
+            .map(|x| simulator.as_ref().map_or(*x, |simulator| simulator.simulate(*x)))
+            .collect();
+        for first in 0..seen.len() {
+            for second in first + 1..seen.len() {
+                let difference = seen[first].difference(&seen[second], Ciede2000::default());
+                *minimum = Some(minimum.map_or(difference, |x: f32| x.min(difference)));
+                if difference < threshold {
+                    conflicts.push(Conflict { first, second, vision, difference });
+                }
+            }
+        }
+    }
+    let pairs = colors.len() * colors.len().saturating_sub(1) / 2;
+    PaletteReport { conflicts, minimum_differences, pairs }
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+fn okabe_ito_palette() -> [Srgb; 8] {
+    [
+        [0x00, 0x00, 0x00],
+        [0xe6, 0x9f, 0x00],
+        [0x56, 0xb4, 0xe9],
+        [0x00, 0x9e, 0x73],
+        [0xf0, 0xe4, 0x42],
+        [0x00, 0x72, 0xb2],
+        [0xd5, 0x5e, 0x00],
+        [0xcc, 0x79, 0xa7],
+    ].map(Srgb::from)
+}
+
+#[test]
+fn empty() {
+    let report = check_palette(&[], 10.0);
+    assert!(report.passes());
+    assert_eq!(report.score(), 1.0);
+    assert!(Vision::ALL.iter().all(|x| report.minimum_difference(*x).is_none()));
+}
+
+#[test]
+fn red_green() {
+    let report = check_palette(&[Srgb::new(180, 80, 60), Srgb::new(110, 130, 60)], 10.0);
+    assert_eq!(report.conflicts_under(Vision::Normal).count(), 0);
+    assert_eq!(report.conflicts_under(Vision::Deficient(Deficiency::Protan)).count(), 1);
+    assert_eq!(report.conflicts_under(Vision::Deficient(Deficiency::Deutan)).count(), 1);
+    assert_eq!(report.conflicts_under(Vision::Deficient(Deficiency::Tritan)).count(), 0);
+    assert_eq!(report.score(), 0.5);
+    for conflict in report.conflicts() {
+        assert_eq!((conflict.first(), conflict.second()), (0, 1));
+        assert_eq!(Some(conflict.difference()), report.minimum_difference(conflict.vision()));
+    }
+}
+
+#[test]
+fn duplicate_color() {
+    let blue = Srgb::new(0, 0x72, 0xb2);
+    let report = check_palette(&[blue, Srgb::new(0, 0, 0), blue], 10.0);
+    assert_eq!(report.conflicts().len(), 4);
+    assert!(report.conflicts().iter().all(|x| (x.first(), x.second()) == (0, 2) && x.difference() == 0.0));
+    assert_eq!(report.score(), 1.0 - 4.0 / 12.0);
+}
+
+#[test]
+fn okabe_ito() {
+    let report = check_palette(&okabe_ito_palette(), 5.0);
+    assert_eq!(report.conflicts_under(Vision::Normal).count(), 0);
+    assert!(report.score() > 0.9);
+}
@@ -0,0 +1,150 @@
+use super::*;
+use crate::{
+    illuminant::D65,
+    Cielab,
+};
+
+const DEFICIENCIES: [Deficiency; 3] = [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan];
+
+fn simulators(deficiency: Deficiency, severity: f32) -> [Simulator; 3] {
+    [
+        Simulator::brettel(deficiency, severity).unwrap(),
+        Simulator::vienot(deficiency, severity).unwrap(),
+        Simulator::machado(deficiency, severity).unwrap(),
+    ]
+}
+
+#[test]
+fn invalid_severity() {
+    assert!(Simulator::brettel(Deficiency::Protan, -0.1).is_none());
+    assert!(Simulator::vienot(Deficiency::Deutan, 1.1).is_none());
+    assert!(Simulator::machado(Deficiency::Tritan, f32::NAN).is_none());
+}
+
+#[test]
+fn machado_rows_sum_to_one() {
+    for table in [MACHADO_PROTAN, MACHADO_DEUTAN, MACHADO_TRITAN] {
+        for matrix in table {
+            for row in matrix.chunks(3) {
+                assert!((row.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+            }
+        }
+    }
+}
+
+#[test]
+fn machado_interpolation() {
+    let lower = Simulator::machado(Deficiency::Deutan, 0.3).unwrap().matrices[0];
+    let upper = Simulator::machado(Deficiency::Deutan, 0.4).unwrap().matrices[0];
+    let middle = Simulator::machado(Deficiency::Deutan, 0.35).unwrap().matrices[0];
+    assert!((0..9).all(|i| (middle[i] - (lower[i] + upper[i]) / 2.0).abs() < 1e-5));
+    assert_eq!(Simulator::machado(Deficiency::Protan, 1.0).unwrap().matrices[0], MACHADO_PROTAN[10]);
+}
+
+#[test]
+fn no_deficiency() {
+    for deficiency in DEFICIENCIES {
+        for simulator in simulators(deficiency, 0.0) {
+            for srgb in [Srgb::new(255, 0, 0), Srgb::new(10, 200, 30), Srgb::new(40, 50, 240)] {
+                assert_eq!(simulator.simulate(srgb), srgb);
+            }
+        }
+    }
+}
+
+#[test]
+fn neutral_colors_unchanged() {
+    for deficiency in DEFICIENCIES {
+        for simulator in simulators(deficiency, 1.0) {
+            for gray in [0, 64, 128, 255] {
+                let srgb = Srgb::new(gray, gray, gray);
+                let simulated: [u8; 3] = simulator.simulate(srgb).into();
+                assert!(simulated.iter().all(|x| x.abs_diff(gray) <= 1));
+            }
+        }
+    }
+}
+
+#[test]
+fn dichromat_confusion() {
+    //Red and green become hard to tell apart for protans and deutans, not for tritans.
+    let difference = |simulator: Simulator| {
+        let red = Cielab::<D65>::from(simulator.simulate(Srgb::new(200, 60, 40)));
+        let green = Cielab::<D65>::from(simulator.simulate(Srgb::new(90, 140, 40)));
+        red.delta_e_2000(&green, 1.0, 1.0, 1.0)
+    };
+    let normal = difference(Simulator::machado(Deficiency::Protan, 0.0).unwrap());
+    for deficiency in [Deficiency::Protan, Deficiency::Deutan] {
+        for simulator in simulators(deficiency, 1.0) {
+            assert!(difference(simulator) < normal / 2.0);
+        }
+    }
+    assert!(difference(Simulator::brettel(Deficiency::Tritan, 1.0).unwrap()) > normal / 2.0);
+}
+
+#[test]
+fn brettel_half_planes() {
+    //Simulated colors stay on the same side of the separation plane.
+    let simulator = Simulator::brettel(Deficiency::Protan, 1.0).unwrap();
+    for srgb in [Srgb::new(0, 0, 255), Srgb::new(255, 255, 0), Srgb::new(255, 0, 0)] {
+        let simulated = simulator.simulate(srgb);
+        let before = [srgb.linear_red(), srgb.linear_green(), srgb.linear_blue()];
+        let after = [simulated.linear_red(), simulated.linear_green(), simulated.linear_blue()];
+        let (before, after) = (dot(simulator.separation, before), dot(simulator.separation, after));
+        assert!(before * after >= 0.0 || after.abs() < 1e-3);
+    }
+}
+
+#[test]
+fn severity_in_between() {
+    let srgb = Srgb::new(200, 60, 40);
+    let full: Cielab = Simulator::brettel(Deficiency::Deutan, 1.0).unwrap().simulate(srgb).into();
+    let half: Cielab = Simulator::brettel(Deficiency::Deutan, 0.5).unwrap().simulate(srgb).into();
+    let original: Cielab = srgb.into();
+    let to_full = original.delta_e_76(&full);
+    assert!(original.delta_e_76(&half) < to_full);
+    assert!(half.delta_e_76(&full) < to_full);
+}
+
+#[test]
+fn daltonizer_without_deficiency() {
+    for deficiency in DEFICIENCIES {
+        let daltonizer = Daltonizer::new(Simulator::machado(deficiency, 0.0).unwrap());
+        for srgb in [Srgb::new(255, 0, 0), Srgb::new(10, 200, 30), Srgb::new(40, 50, 240)] {
+            assert_eq!(daltonizer.correct(srgb), srgb);
+        }
+    }
+}
+
+#[test]
+fn daltonizer_restores_contrast() {
+    let pairs = [
+        (Deficiency::Protan, Srgb::new(200, 60, 40), Srgb::new(90, 140, 40)),
+        (Deficiency::Deutan, Srgb::new(200, 60, 40), Srgb::new(90, 140, 40)),
+        (Deficiency::Tritan, Srgb::new(60, 60, 220), Srgb::new(40, 140, 120)),
+    ];
+    for (deficiency, a, b) in pairs {
+        let simulator = Simulator::brettel(deficiency, 1.0).unwrap();
+        let daltonizer = Daltonizer::new(simulator);
+        let difference = |a: Srgb, b: Srgb| {
+            let a = Cielab::<D65>::from(simulator.simulate(a));
+            let b = Cielab::<D65>::from(simulator.simulate(b));
+            a.delta_e_2000(&b, 1.0, 1.0, 1.0)
+        };
+        assert!(difference(daltonizer.correct(a), daltonizer.correct(b)) > difference(a, b));
+    }
+}
+
+#[test]
+fn correct_pixels() {
+    let daltonizer = Daltonizer::new(Simulator::vienot(Deficiency::Protan, 1.0).unwrap());
+    let colors = [Srgb::new(200, 60, 40), Srgb::new(90, 140, 40), Srgb::new(0, 0, 0)];
+    let mut pixels = colors.map(<[u8; 3]>::from);
+    daltonizer.correct_pixels(&mut pixels);
+    for (pixel, color) in pixels.into_iter().zip(colors) {
+        assert_eq!(Srgb::from(pixel), daltonizer.correct(color));
+    }
+    let mut srgb_pixels = colors;
+    daltonizer.correct_pixels(&mut srgb_pixels);
+    assert_eq!(srgb_pixels, colors.map(|x| daltonizer.correct(x)));
+}
@@ -0,0 +1,273 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    cielab::{
+        self,
+        Cie94Application,
+    },
+    illuminant::{
+        D65,
+        Illuminant,
+    },
+    lms::ConeFundamentals,
+    matrix,
+    oklab,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Lms,
+    Oklab,
+    Oklch,
+    Srgb,
+    Ycocg,
+    YcocgR,
+};
+
+/// A color difference metric able to compare colors of type `C`.
+///
+/// The colors are converted to the color space in which the metric is defined before being
+/// compared.
+pub trait Metric<C> {
+    /// Difference between a reference color and a sample color.
+    fn delta_e(&self, reference: &C, sample: &C) -> f32;
+}
+
+/// A color that can be compared to another color of the same type under a color difference
+/// metric.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ difference::{ Ciede2000, ColorDifference }, Srgb };
+/// fn closest<C: ColorDifference + Copy>(target: C, candidates: &[C]) -> Option<C>
+/// where
+///     Ciede2000: cowor::difference::Metric<C>,
+/// {
+///     candidates.iter().copied().min_by(|a, b| {
+///         let a = target.difference(a, Ciede2000::default());
+///         let b = target.difference(b, Ciede2000::default());
+///         a.total_cmp(&b)
+///     })
+/// }
+/// let candidates = [Srgb::new(255, 0, 0), Srgb::new(0, 255, 0), Srgb::new(0, 0, 255)];
+/// assert_eq!(closest(Srgb::new(200, 50, 40), &candidates), Some(Srgb::new(255, 0, 0)));
+/// ```
+pub trait ColorDifference: Sized {
+    /// Difference between a reference color (`self`) and a sample color under a given metric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ difference::{ Cie76, ColorDifference }, Srgb };
+    /// let a = Srgb::new(10, 20, 30);
+    /// let b = Srgb::new(10, 20, 30);
+    /// assert_eq!(a.difference(&b, Cie76), 0.0);
+    /// ```
+    fn difference<M: Metric<Self>>(&self, sample: &Self, metric: M) -> f32 {
+        metric.delta_e(self, sample)
+    }
+}
+
+impl<C: ToCielab> ColorDifference for C {}
+
+/// A color that can be converted to CIELAB, to be compared with CIELAB based color difference
+/// metrics.
+///
+/// Colors already relative to a reference white keep it, other colors are converted relatively
+/// to D65. Colors outside of the gamut of real colors are converted as they are, without being
+/// clamped, while YCoCg colors outside of the sRGB gamut are converted as the gray of the same
+/// luma.
+pub trait ToCielab {
+    /// Reference white of the CIELAB color.
+    type White: Illuminant;
+
+    /// Conversion of the color to CIELAB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Cielab, difference::ToCielab, Srgb };
+    /// let srgb = Srgb::new(10, 20, 30);
+    /// assert_eq!(srgb.to_cielab(), Cielab::from(srgb));
+    /// ```
+    fn to_cielab(&self) -> Cielab<Self::White>;
+}
+
+impl<W: Illuminant> ToCielab for Cielab<W> {
+    type White = W;
+
+    fn to_cielab(&self) -> Cielab<W> {
+        *self
+    }
+}
+
+impl<W: Illuminant> ToCielab for Cielch<W> {
+    type White = W;
+
+    fn to_cielab(&self) -> Cielab<W> {
+        (*self).into()
+    }
+}
+
+impl ToCielab for Ciexyz {
+    type White = D65;
+
+    fn to_cielab(&self) -> Cielab {
+        (*self).into()
+    }
+}
+
+impl ToCielab for Srgb {
+    type White = D65;
+
+    fn to_cielab(&self) -> Cielab {
+        (*self).into()
+    }
+}
+
+impl<M: ConeFundamentals> ToCielab for Lms<M> {
+    type White = D65;
+
+    fn to_cielab(&self) -> Cielab {
+        cielab::from_ciexyz(matrix::transform(M::TO_CIEXYZ, (*self).into()))
+    }
+}
+
+impl ToCielab for Oklab {
+    type White = D65;
+
+    fn to_cielab(&self) -> Cielab {
+        cielab::from_ciexyz(oklab::to_ciexyz(*self))
+    }
+}
+
+impl ToCielab for Oklch {
+    type White = D65;
+
+    fn to_cielab(&self) -> Cielab {
+        Oklab::from(*self).to_cielab()
+    }
+}
+
+impl ToCielab for Ycocg {
+    type White = D65;
+
+    fn to_cielab(&self) -> Cielab {
+        Srgb::try_from(*self).unwrap_or_else(|_| Srgb::from([self.y(); 3])).into()
+    }
+}
+
+impl ToCielab for YcocgR {
+    type White = D65;
+
+    fn to_cielab(&self) -> Cielab {
+        Srgb::try_from(*self).unwrap_or_else(|_| Srgb::new(self.y(), self.y(), self.y())).into()
+    }
+}
+
+/// CIE76 color difference (ΔE*ab), defined in CIELAB.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cie76;
+
+/// CIE94 color difference (ΔE94), defined in CIELAB.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cie94 {
+    application: Cie94Application,
+}
+
+/// CIEDE2000 color difference (ΔE00), defined in CIELAB.
+///
+/// The default metric uses the reference conditions (kL = kC = kH = 1).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ciede2000 {
+    kl: f32,
+    kc: f32,
+    kh: f32,
+}
+
+/// CMC l:c color difference (ΔE CMC), defined in CIELAB.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cmc {
+    lightness: f32,
+    chroma: f32,
+}
+
+impl Cie94 {
+    /// Creates a CIE94 metric using the weights of a given application.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ cielab::Cie94Application, difference::Cie94 };
+    /// let metric = Cie94::new(Cie94Application::Textiles);
+    /// ```
+    pub fn new(application: Cie94Application) -> Self {
+        Self { application }
+    }
+}
+
+impl Ciede2000 {
+    /// Creates a CIEDE2000 metric from the lightness, chroma and hue parametric weighting
+    /// factors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::difference::Ciede2000;
+    /// let textiles = Ciede2000::new(2.0, 1.0, 1.0);
+    /// ```
+    pub fn new(kl: f32, kc: f32, kh: f32) -> Self {
+        Self { kl, kc, kh }
+    }
+}
+
+impl Default for Ciede2000 {
+    fn default() -> Self {
+        Self::new(1f32, 1f32, 1f32)
+    }
+}
+
+impl Cmc {
+    /// CMC 2:1, used for acceptability.
+    pub const ACCEPTABILITY: Self = Self { lightness: 2f32, chroma: 1f32 };
+
+    /// CMC 1:1, used for perceptibility.
+    pub const PERCEPTIBILITY: Self = Self { lightness: 1f32, chroma: 1f32 };
+
+    /// Creates a CMC l:c metric from the lightness (l) and chroma (c) weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::difference::Cmc;
+    /// assert_eq!(Cmc::new(2.0, 1.0), Cmc::ACCEPTABILITY);
+    /// ```
+    pub fn new(lightness: f32, chroma: f32) -> Self {
+        Self { lightness, chroma }
+    }
+}
+
+impl<C: ToCielab> Metric<C> for Cie76 {
+    fn delta_e(&self, reference: &C, sample: &C) -> f32 {
+        reference.to_cielab().delta_e_76(&sample.to_cielab())
+    }
+}
+
+impl<C: ToCielab> Metric<C> for Cie94 {
+    fn delta_e(&self, reference: &C, sample: &C) -> f32 {
+        reference.to_cielab().delta_e_94(&sample.to_cielab(), self.application)
+    }
+}
+
+impl<C: ToCielab> Metric<C> for Ciede2000 {
+    fn delta_e(&self, reference: &C, sample: &C) -> f32 {
+        reference.to_cielab().delta_e_2000(&sample.to_cielab(), self.kl, self.kc, self.kh)
+    }
+}
+
+impl<C: ToCielab> Metric<C> for Cmc {
+    fn delta_e(&self, reference: &C, sample: &C) -> f32 {
+        reference.to_cielab().delta_e_cmc(&sample.to_cielab(), self.lightness, self.chroma)
+    }
+}
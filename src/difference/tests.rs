@@ -0,0 +1,54 @@
+use super::*;
+use crate::illuminant::D50;
+
+#[test]
+fn same_as_cielab() {
+    let a = Srgb::new(200, 30, 40);
+    let b = Srgb::new(180, 60, 20);
+    let [lab_a, lab_b] = [a, b].map(Cielab::<D65>::from);
+    assert_eq!(a.difference(&b, Cie76), lab_a.delta_e_76(&lab_b));
+    assert_eq!(a.difference(&b, Cie94::new(Cie94Application::Textiles)), lab_a.delta_e_94(&lab_b, Cie94Application::Textiles));
+    assert_eq!(a.difference(&b, Ciede2000::default()), lab_a.delta_e_2000(&lab_b, 1.0, 1.0, 1.0));
+    assert_eq!(a.difference(&b, Cmc::ACCEPTABILITY), lab_a.delta_e_cmc(&lab_b, 2.0, 1.0));
+}
+
+#[test]
+fn across_color_types() {
+    let a = Srgb::new(200, 30, 40);
+    let b = Srgb::new(180, 60, 20);
+    let expected = a.difference(&b, Ciede2000::default());
+    let [xyz_a, xyz_b] = [a, b].map(Ciexyz::from);
+    assert!((xyz_a.difference(&xyz_b, Ciede2000::default()) - expected).abs() < 1e-4);
+    let [lch_a, lch_b] = [a, b].map(Cielch::<D65>::from);
+    assert!((lch_a.difference(&lch_b, Ciede2000::default()) - expected).abs() < 1e-4);
+    let [lms_a, lms_b] = [a, b].map(Lms::<crate::lms::Bradford>::from);
+    assert!((lms_a.difference(&lms_b, Ciede2000::default()) - expected).abs() < 1e-3);
+    let [oklab_a, oklab_b] = [a, b].map(Oklab::from);
+    assert!((oklab_a.difference(&oklab_b, Ciede2000::default()) - expected).abs() < 1e-3);
+    let [oklch_a, oklch_b] = [a, b].map(Oklch::from);
+    assert!((oklch_a.difference(&oklch_b, Ciede2000::default()) - expected).abs() < 1e-3);
+    let [ycocg_a, ycocg_b] = [a, b].map(Ycocg::from);
+    assert!((ycocg_a.difference(&ycocg_b, Ciede2000::default()) - expected).abs() < 1e-3);
+    let [ycocg_r_a, ycocg_r_b] = [a, b].map(YcocgR::from);
+    assert_eq!(ycocg_r_a.difference(&ycocg_r_b, Ciede2000::default()), expected);
+}
+
+#[test]
+fn outside_of_gamut() {
+    //Those YCoCg colors don't land in the sRGB gamut, they are compared through the grays of
+    //the same luma.
+    let a = Ycocg::new(0.0, 0.5, 0.5).unwrap();
+    let b = Ycocg::new(0.1, 0.5, 0.5).unwrap();
+    let gray = Ycocg::new(0.1, 0.0, 0.0).unwrap();
+    assert!(a.difference(&b, Cie76) > 0.0);
+    assert_eq!(b.difference(&gray, Cie76), 0.0);
+    let c = YcocgR::new(0, 255, 255).unwrap();
+    assert_eq!(c.difference(&YcocgR::new(0, 0, 0).unwrap(), Cie76), 0.0);
+}
+
+#[test]
+fn reference_white() {
+    let a = Cielab::with_reference_white(50.0, 10.0, 10.0, D50).unwrap();
+    let b = Cielab::with_reference_white(55.0, 10.0, 10.0, D50).unwrap();
+    assert_eq!(a.difference(&b, Cie76), 5.0);
+}
@@ -24,3 +24,99 @@ impl fmt::Display for OutOfSpecification {
 }
 
 impl error::Error for OutOfSpecification {}
+
+/// An error for when a string can not be parsed as a CSS gradient.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseGradientError;
+
+impl fmt::Display for ParseGradientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid or unsupported CSS gradient")
+    }
+}
+
+impl error::Error for ParseGradientError {}
+
+/// An error for when a string can not be parsed as a hexadecimal color.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseHexError {
+    /// The color doesn't have 3, 4, 6 or 8 digits. Holds the number of digits found.
+    InvalidLength(usize),
+    /// The color has a character which isn't a hexadecimal digit.
+    InvalidDigit(char),
+    /// The color isn't fully opaque and is parsed as a color without alpha channel.
+    Translucent,
+}
+
+impl fmt::Display for ParseHexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseHexError::InvalidLength(length)    => write!(f, "hexadecimal color must have 3, 4, 6 or 8 digits, found {}", length),
+            ParseHexError::InvalidDigit(digit)      => write!(f, "invalid hexadecimal digit '{}'", digit),
+            ParseHexError::Translucent              => write!(f, "translucent hexadecimal color can't be parsed without alpha channel"),
+        }
+    }
+}
+
+impl error::Error for ParseHexError {}
+
+/// An error for when a string can not be parsed as a CSS color.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseColorError {
+    /// The color is an invalid hexadecimal color.
+    Hex(ParseHexError),
+    /// The color isn't a known color function or keyword. Holds the function name or keyword.
+    UnknownColor(String),
+    /// The `color()` function uses an unknown color space. Holds the name of the color space.
+    UnknownColorSpace(String),
+    /// The arguments of the color function don't follow its syntax.
+    InvalidSyntax,
+    /// A component of the color function isn't valid. Holds the component.
+    InvalidComponent(String),
+    /// A `var()` function refers to an undefined custom property and has no fallback. Holds the
+    /// name of the custom property.
+    UnknownVariable(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::Hex(error)                  => write!(f, "{}", error),
+            ParseColorError::UnknownColor(name)          => write!(f, "unknown color '{}'", name),
+            ParseColorError::UnknownColorSpace(name)     => write!(f, "unknown color space '{}'", name),
+            ParseColorError::InvalidSyntax               => write!(f, "invalid color function syntax"),
+            ParseColorError::InvalidComponent(component) => write!(f, "invalid color component '{}'", component),
+            ParseColorError::UnknownVariable(name)       => write!(f, "undefined custom property '{}'", name),
+        }
+    }
+}
+
+impl error::Error for ParseColorError {}
+
+/// An error for when a string can not be parsed as an X11 color specification.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseXColorError {
+    /// The specification isn't made of a prefix followed by three components separated by
+    /// slashes.
+    InvalidSyntax,
+    /// The prefix of the specification isn't a known format. Holds the prefix in lowercase.
+    UnknownFormat(String),
+    /// A component isn't a valid number, or a valid hexadecimal channel of 1 to 4 digits for the
+    /// `rgb:` format. Holds the component.
+    InvalidComponent(String),
+    /// The components don't represent a valid color of their format.
+    OutOfSpecification,
+}
+
+impl fmt::Display for ParseXColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseXColorError::InvalidSyntax                 => write!(f, "invalid X11 color specification syntax"),
+            ParseXColorError::UnknownFormat(prefix)         => write!(f, "unknown X11 color specification format '{}'", prefix),
+            ParseXColorError::InvalidComponent(component)   => write!(f, "invalid color component '{}'", component),
+            ParseXColorError::OutOfSpecification            => write!(f, "invalid color according to the specifications of its color space"),
+        }
+    }
+}
+
+impl error::Error for ParseXColorError {}
@@ -0,0 +1,406 @@
+//! Multi-stop color gradients, interpolated in a chosen color space, and their serialization
+//! to and from CSS gradients.
+
+mod css;
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    illuminant::D50,
+    interpolation::{
+        HueInterpolation,
+        Mix,
+    },
+    oklab,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Oklab,
+    Oklch,
+    Srgb,
+};
+
+pub use css::GradientKind;
+
+/// A color space in which the colors of a gradient are interpolated.
+///
+/// The color spaces are the same as the CSS `<color-space>` of the same names: CIELAB and CIELCh
+/// are relative to D50, colors being adapted from and to the D65 white of sRGB with the Bradford
+/// chromatic adaptation transform, Oklab and Oklch are relative to D65.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum InterpolationSpace {
+    /// sRGB, on gamma compressed components (`srgb`).
+    Srgb,
+    /// sRGB, on linear components (`srgb-linear`).
+    SrgbLinear,
+    /// CIEXYZ relative to D65 (`xyz-d65`).
+    Ciexyz,
+    /// CIELAB relative to D50 (`lab`).
+    #[default]
+    Cielab,
+    /// CIELCh relative to D50, with a hue interpolation method (`lch`).
+    Cielch(HueInterpolation),
+    /// Oklab (`oklab`).
+    Oklab,
+    /// Oklch, with a hue interpolation method (`oklch`).
+    Oklch(HueInterpolation),
+}
+
+impl InterpolationSpace {
+    /// Interpolates between two sRGB colors in the color space.
+    ///
+    /// Colors outside of the sRGB gamut resulting from the interpolation have their chroma
+    /// reduced until they fit in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::InterpolationSpace, Srgb };
+    /// let red = Srgb::new(255, 0, 0);
+    /// let blue = Srgb::new(0, 0, 255);
+    /// assert_eq!(InterpolationSpace::Srgb.mix(red, blue, 0.5), Srgb::new(128, 0, 128));
+    /// assert_eq!(InterpolationSpace::SrgbLinear.mix(red, blue, 0.5), Srgb::new(188, 0, 188));
+    /// ```
+    pub fn mix(&self, first: Srgb, second: Srgb, amount: f32) -> Srgb {
+        match self {
+            InterpolationSpace::Srgb                        => first.mix(&second, amount),
+            InterpolationSpace::SrgbLinear                  => first.mix_linear(&second, amount),
+            InterpolationSpace::Ciexyz                      => {
+                let mixed = Ciexyz::from(first).mix(&Ciexyz::from(second), amount);
+                Srgb::from_ciexyz_reducing_chroma(mixed.into())
+            },
+            InterpolationSpace::Cielab                      => {
+                let mixed = Cielab::<D50>::from(first).mix(&Cielab::from(second), amount);
+                Srgb::from_ciexyz_reducing_chroma(Ciexyz::from(mixed).into())
+            },
+            InterpolationSpace::Cielch(hue_interpolation)   => {
+                let mixed = Cielch::<D50>::from(first).mix_hue(&Cielch::from(second), amount, *hue_interpolation);
+                Srgb::from_ciexyz_reducing_chroma(Ciexyz::from(mixed).into())
+            },
+            InterpolationSpace::Oklab                       => {
+                let mixed = Oklab::from(first).mix(&Oklab::from(second), amount);
+                Srgb::from_ciexyz_reducing_chroma(oklab::to_ciexyz(mixed))
+            },
+            InterpolationSpace::Oklch(hue_interpolation)    => {
+                let mixed = Oklch::from(first).mix_hue(&Oklch::from(second), amount, *hue_interpolation);
+                Srgb::from_ciexyz_reducing_chroma(oklab::to_ciexyz(mixed.into()))
+            },
+        }
+    }
+}
+
+/// An easing function, shaping the interpolation between two color stops.
+///
+/// Those are the easing functions of CSS, given by cubic Bézier curves.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// CSS `ease`, equivalent to `CubicBezier(0.25, 0.1, 0.25, 1.0)`.
+    Ease,
+    /// CSS `ease-in`, equivalent to `CubicBezier(0.42, 0.0, 1.0, 1.0)`.
+    EaseIn,
+    /// CSS `ease-out`, equivalent to `CubicBezier(0.0, 0.0, 0.58, 1.0)`.
+    EaseOut,
+    /// CSS `ease-in-out`, equivalent to `CubicBezier(0.42, 0.0, 0.58, 1.0)`.
+    EaseInOut,
+    /// Cubic Bézier curve from (0, 0) to (1, 1) with the control points (x1, y1) and (x2, y2).
+    /// The x coordinates are clamped between 0 and 1.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Applies the easing function to a progress between 0 and 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::gradient::Easing;
+    /// assert_eq!(Easing::Linear.apply(0.3), 0.3);
+    /// assert!(Easing::EaseIn.apply(0.3) < 0.3);
+    /// assert_eq!(Easing::EaseInOut.apply(1.0), 1.0);
+    /// ```
+    pub fn apply(&self, progress: f32) -> f32 {
+        let (x1, y1, x2, y2) = match *self {
+            Easing::Linear                          => return progress,
+            Easing::Ease                            => (0.25f32, 0.1f32, 0.25f32, 1f32),
+            Easing::EaseIn                          => (0.42f32, 0f32, 1f32, 1f32),
+            Easing::EaseOut                         => (0f32, 0f32, 0.58f32, 1f32),
+            Easing::EaseInOut                       => (0.42f32, 0f32, 0.58f32, 1f32),
+            Easing::CubicBezier(x1, y1, x2, y2)     => (x1.clamp(0f32, 1f32), y1, x2.clamp(0f32, 1f32), y2),
+        };
+        let bezier = |a: f32, b: f32, s: f32| 3f32 * (1f32 - s) * (1f32 - s) * s * a + 3f32 * (1f32 - s) * s * s * b + s * s * s;
+        //The x coordinate of the curve is increasing, its parameter is found by bisection.
+        let (mut low, mut high) = (0f32, 1f32);
+        for _ in 0..32 {
+            let middle = (low + high) / 2f32;
+            match bezier(x1, x2, middle) < progress {
+                true    => low = middle,
+                false   => high = middle,
+            }
+        }
+        bezier(y1, y2, (low + high) / 2f32)
+    }
+}
+
+/// A color stop of a gradient, a color at a position along the gradient.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorStop {
+    color: Srgb,
+    position: f32,
+}
+
+impl ColorStop {
+    /// Creates a color stop from a color and its position along the gradient,
+    /// 0 being the start of the gradient and 1 its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::ColorStop, Srgb };
+    /// let stop = ColorStop::new(Srgb::new(255, 0, 0), 0.25);
+    /// ```
+    pub fn new(color: Srgb, position: f32) -> Self {
+        Self { color, position }
+    }
+
+    /// Color of a color stop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::ColorStop, Srgb };
+    /// let stop = ColorStop::new(Srgb::new(255, 0, 0), 0.25);
+    /// assert_eq!(stop.color(), Srgb::new(255, 0, 0));
+    /// ```
+    pub fn color(&self) -> Srgb {
+        self.color
+    }
+
+    /// Position of a color stop along the gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::ColorStop, Srgb };
+    /// let stop = ColorStop::new(Srgb::new(255, 0, 0), 0.25);
+    /// assert_eq!(stop.position(), 0.25);
+    /// ```
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+}
+
+/// A gradient made of color stops, interpolated in a color space.
+///
+/// Positions along the gradient go from 0 at its start to 1 at its end, although color stops
+/// may be placed before its start or after its end. Before the first color stop and after the
+/// last one the gradient has the color of that stop.
+///
+/// The interpolation between two consecutive color stops can be shaped by an easing function
+/// and by a hint, the position at which the gradient is halfway between their colors, as the
+/// color hints of CSS gradients.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gradient {
+    stops: Vec<ColorStop>,
+    hints: Vec<Option<f32>>,
+    space: InterpolationSpace,
+    easing: Easing,
+}
+
+impl Gradient {
+    /// Creates a gradient from its color stops and the color space its colors are interpolated in.
+    ///
+    /// There must be at least one color stop and their positions must be finite and in
+    /// increasing order otherwise the return value is None. Consecutive stops at the same
+    /// position make a sharp transition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ ColorStop, Gradient, InterpolationSpace }, Srgb };
+    /// let valid = Gradient::new(&[
+    ///     ColorStop::new(Srgb::new(255, 0, 0), 0.0),
+    ///     ColorStop::new(Srgb::new(0, 0, 255), 1.0),
+    /// ], InterpolationSpace::Cielab); // Ok.
+    /// let invalid = Gradient::new(&[
+    ///     ColorStop::new(Srgb::new(255, 0, 0), 1.0),
+    ///     ColorStop::new(Srgb::new(0, 0, 255), 0.0),
+    /// ], InterpolationSpace::Cielab); // Not ok. The positions are in decreasing order.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn new(stops: &[ColorStop], space: InterpolationSpace) -> Option<Self> {
+        let finite = stops.iter().all(|x| x.position.is_finite());
+        let increasing = stops.windows(2).all(|x| x[0].position <= x[1].position);
+        match !stops.is_empty() && finite && increasing {
+            true    => Some(Self { stops: stops.to_vec(), hints: vec![None; stops.len() - 1], space, easing: Easing::Linear }),
+            false   => None,
+        }
+    }
+
+    /// Creates a gradient from colors spread evenly from its start to its end.
+    /// There must be at least one color otherwise the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(255, 0, 0), Srgb::new(0, 255, 0), Srgb::new(0, 0, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// assert_eq!(gradient.stops()[1].position(), 0.5);
+    /// ```
+    pub fn evenly_spaced(colors: &[Srgb], space: InterpolationSpace) -> Option<Self> {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        let stops: Vec<ColorStop> = colors.iter().enumerate()
+            .map(|(i, color)| ColorStop::new(*color, i as f32 / last))
+            .collect();
+        Self::new(&stops, space)
+    }
+
+    /// Sets the hints of the gradient, one for each pair of consecutive color stops.
+    ///
+    /// A hint is the position at which the gradient is halfway between the colors of the stops,
+    /// it must be between their positions. None leaves the halfway point in the middle.
+    /// The return value is None if there isn't one hint per pair of stops or if a hint isn't
+    /// between its stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// let gradient = gradient.with_hints(&[Some(0.2)]).unwrap();
+    /// assert_eq!(gradient.sample(0.2), Srgb::new(128, 128, 128));
+    /// ```
+    pub fn with_hints(self, hints: &[Option<f32>]) -> Option<Self> {
+        let valid = hints.len() == self.hints.len() && hints.iter().zip(self.stops.windows(2)).all(|(hint, stops)| {
+            hint.is_none_or(|x| (stops[0].position..=stops[1].position).contains(&x))
+        });
+        match valid {
+            true    => Some(Self { hints: hints.to_vec(), ..self }),
+            false   => None,
+        }
+    }
+
+    /// Sets the easing function applied between each pair of consecutive color stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Easing, Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// let gradient = gradient.with_easing(Easing::EaseInOut);
+    /// assert_eq!(gradient.sample(0.5), Srgb::new(128, 128, 128));
+    /// ```
+    pub fn with_easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// Color stops of a gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// assert_eq!(gradient.stops().len(), 2);
+    /// ```
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Hints of a gradient, one for each pair of consecutive color stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// assert_eq!(gradient.hints(), &[None]);
+    /// ```
+    pub fn hints(&self) -> &[Option<f32>] {
+        &self.hints
+    }
+
+    /// Color space in which the colors of a gradient are interpolated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Ciexyz).unwrap();
+    /// assert_eq!(gradient.space(), InterpolationSpace::Ciexyz);
+    /// ```
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    /// Easing function applied between each pair of consecutive color stops of a gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Easing, Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// assert_eq!(gradient.easing(), Easing::Linear);
+    /// ```
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Color of a gradient at a given position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(255, 0, 0), Srgb::new(0, 0, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// assert_eq!(gradient.sample(0.5), Srgb::new(128, 0, 128));
+    /// assert_eq!(gradient.sample(-1.0), Srgb::new(255, 0, 0));
+    /// ```
+    pub fn sample(&self, position: f32) -> Srgb {
+        let next = self.stops.iter().position(|x| x.position > position);
+        let index = match next {
+            Some(0)     => return self.stops[0].color,
+            Some(x)     => x - 1,
+            None        => return self.stops[self.stops.len() - 1].color,
+        };
+        let (first, second) = (self.stops[index], self.stops[index + 1]);
+        let length = second.position - first.position;
+        let progress = (position - first.position) / length;
+        let progress = match self.hints[index] {
+            Some(hint)  => match (hint - first.position) / length {
+                x if x <= 0f32  => 1f32,
+                x if x >= 1f32  => 0f32,
+                x               => progress.powf(0.5f32.ln() / x.ln()),
+            },
+            None        => progress,
+        };
+        self.space.mix(first.color, second.color, self.easing.apply(progress))
+    }
+
+    /// Colors of a gradient at a number of positions spread evenly from its start to its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(0, 0, 0), Srgb::new(255, 255, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Srgb).unwrap();
+    /// let samples = gradient.colors(3);
+    /// assert_eq!(samples, vec![Srgb::new(0, 0, 0), Srgb::new(128, 128, 128), Srgb::new(255, 255, 255)]);
+    /// ```
+    pub fn colors(&self, count: usize) -> Vec<Srgb> {
+        let last = count.saturating_sub(1).max(1) as f32;
+        (0..count).map(|i| self.sample(i as f32 / last)).collect()
+    }
+}
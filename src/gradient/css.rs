@@ -0,0 +1,408 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt::Write;
+use crate::{
+    css::CssColor,
+    error::ParseGradientError,
+    interpolation::HueInterpolation,
+    Srgb,
+    Srgba,
+};
+use super::{
+    ColorStop,
+    Easing,
+    Gradient,
+    InterpolationSpace,
+};
+
+//Number of color stops approximating each eased segment of a gradient in CSS.
+const EASING_STOPS: usize = 16;
+
+/// The kind of a CSS gradient, given by the function used to write it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GradientKind {
+    /// `linear-gradient()`.
+    Linear,
+    /// `radial-gradient()`.
+    Radial,
+    /// `conic-gradient()`.
+    Conic,
+}
+
+impl GradientKind {
+    //Name of the CSS function of the gradient kind.
+    fn function(&self) -> &'static str {
+        match self {
+            GradientKind::Linear    => "linear-gradient",
+            GradientKind::Radial    => "radial-gradient",
+            GradientKind::Conic     => "conic-gradient",
+        }
+    }
+}
+
+impl InterpolationSpace {
+    //CSS color interpolation method of the interpolation space.
+    fn css(&self) -> String {
+        match self {
+            InterpolationSpace::Srgb                        => String::from("srgb"),
+            InterpolationSpace::SrgbLinear                  => String::from("srgb-linear"),
+            InterpolationSpace::Ciexyz                      => String::from("xyz-d65"),
+            InterpolationSpace::Cielab                      => String::from("lab"),
+            InterpolationSpace::Cielch(hue_interpolation)   => format!("lch{}", hue_method(*hue_interpolation)),
+            InterpolationSpace::Oklab                       => String::from("oklab"),
+            InterpolationSpace::Oklch(hue_interpolation)    => format!("oklch{}", hue_method(*hue_interpolation)),
+        }
+    }
+}
+
+//CSS hue interpolation method following a polar color space, omitted for the default one.
+fn hue_method(hue_interpolation: HueInterpolation) -> &'static str {
+    match hue_interpolation {
+        HueInterpolation::Shorter       => "",
+        HueInterpolation::Longer        => " longer hue",
+        HueInterpolation::Increasing    => " increasing hue",
+        HueInterpolation::Decreasing    => " decreasing hue",
+    }
+}
+
+impl Gradient {
+    /// Serializes a gradient to a CSS gradient of the given kind.
+    ///
+    /// Colors are written as hexadecimal colors and positions as percentages. The interpolation
+    /// space is given with an `in` color interpolation method unless it is sRGB, the default of
+    /// gradients made of such colors. Easing functions have no CSS equivalent, eased transitions
+    /// are approximated with intermediate color stops.
+    ///
+    /// The geometry of the gradient (direction, shape, center) is left to its default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, GradientKind, InterpolationSpace }, Srgb };
+    /// let colors = [Srgb::new(255, 0, 0), Srgb::new(0, 0, 255)];
+    /// let gradient = Gradient::evenly_spaced(&colors, InterpolationSpace::Cielab).unwrap();
+    /// assert_eq!(gradient.to_css(GradientKind::Linear), "linear-gradient(in lab, #ff0000 0%, #0000ff 100%)");
+    /// ```
+    pub fn to_css(&self, kind: GradientKind) -> String {
+        let mut css = format!("{}(", kind.function());
+        if self.space != InterpolationSpace::Srgb {
+            write!(css, "in {}, ", self.space.css()).unwrap();
+        }
+        let stop = |color: Srgb, position: f32| format!("{:#x} {}", color, percentage(position));
+        let mut arguments = vec![stop(self.stops[0].color, self.stops[0].position)];
+        for (stops, hint) in self.stops.windows(2).zip(&self.hints) {
+            let (first, second) = (stops[0], stops[1]);
+            match (self.easing, hint) {
+                (Easing::Linear, Some(hint))    => arguments.push(percentage(*hint)),
+                (Easing::Linear, None)          => (),
+                _                               => {
+                    let length = second.position - first.position;
+                    for i in 1..EASING_STOPS {
+                        let position = first.position + length * i as f32 / EASING_STOPS as f32;
+                        arguments.push(stop(self.sample(position), position));
+                    }
+                },
+            }
+            arguments.push(stop(second.color, second.position));
+        }
+        css.push_str(&arguments.join(", "));
+        css.push(')');
+        css
+    }
+
+    /// Parses a CSS gradient into its kind and a gradient.
+    ///
+    /// Colors can be any fully opaque CSS color, and are converted to sRGB. Positions are
+    /// percentages, or angles for conic gradients. Missing positions are filled in the same way
+    /// as browsers do. Without a color interpolation method the colors are interpolated in sRGB
+    /// if they are all written with legacy syntaxes (hexadecimal colors, named colors, `rgb()`,
+    /// `hsl()`, `hwb()`), in Oklab otherwise.
+    ///
+    /// The geometry of the gradient (direction, shape, center) is checked then skipped. The
+    /// return value is a ParseGradientError if the string isn't a CSS gradient or uses
+    /// unsupported features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ gradient::{ Gradient, GradientKind, InterpolationSpace }, Srgb };
+    /// let css = "linear-gradient(to right in lab, #f00, 30%, rgb(0 0 255) 80%)";
+    /// let (kind, gradient) = Gradient::from_css(css).unwrap();
+    /// assert_eq!(kind, GradientKind::Linear);
+    /// assert_eq!(gradient.space(), InterpolationSpace::Cielab);
+    /// assert_eq!(gradient.stops()[1].color(), Srgb::new(0, 0, 255));
+    /// assert_eq!(gradient.stops()[1].position(), 0.8);
+    /// assert_eq!(gradient.hints(), &[Some(0.3)]);
+    /// assert!(Gradient::from_css("linear-gradient(#f00)").is_err());
+    /// let (_, gradient) = Gradient::from_css("linear-gradient(#f00, oklch(0.5 0.1 120))").unwrap();
+    /// assert_eq!(gradient.space(), InterpolationSpace::Oklab);
+    /// ```
+    pub fn from_css(css: &str) -> Result<(GradientKind, Self), ParseGradientError> {
+        let css = css.trim();
+        let (kind, arguments) = [GradientKind::Linear, GradientKind::Radial, GradientKind::Conic].into_iter()
+            .find_map(|kind| {
+                let arguments = css.strip_prefix(kind.function())?.trim_start().strip_prefix('(')?.strip_suffix(')')?;
+                Some((kind, arguments))
+            })
+            .ok_or(ParseGradientError)?;
+        let mut arguments = split_arguments(arguments);
+        //The first argument is either a color stop or the geometry and color interpolation method.
+        let space = match arguments.first().map(|x| (parse_stop(x, kind), parse_position(x, kind))) {
+            Some((Err(_), Err(_)))  => parse_prelude(arguments.remove(0), kind)?,
+            _                       => None,
+        };
+        //Color stops with their positions if given, and the hints before each of them.
+        let mut stops: Vec<(CssColor, Option<f32>)> = Vec::new();
+        let mut hints: Vec<Option<f32>> = Vec::new();
+        let mut hint = None;
+        for argument in arguments {
+            match (parse_position(argument, kind), hint) {
+                (Ok(_), _) if stops.is_empty()  => return Err(ParseGradientError),
+                (Ok(_), Some(_))                => return Err(ParseGradientError),
+                (Ok(position), None)            => hint = Some(position),
+                (Err(_), _)                     => {
+                    for stop in parse_stop(argument, kind)? {
+                        if !stops.is_empty() {
+                            hints.push(hint.take());
+                        }
+                        stops.push(stop);
+                    }
+                },
+            }
+        }
+        if hint.is_some() || stops.len() < 2 {
+            return Err(ParseGradientError);
+        }
+        //Like CSS, colors are interpolated in Oklab by default unless they are all legacy colors.
+        let space = space.unwrap_or(match stops.iter().all(|(color, _)| color.is_legacy()) {
+            true    => InterpolationSpace::Srgb,
+            false   => InterpolationSpace::Oklab,
+        });
+        let positions = fix_positions(&stops.iter().map(|x| x.1).collect::<Vec<_>>());
+        let stops: Vec<ColorStop> = stops.iter().zip(&positions)
+            .map(|((color, _), position)| ColorStop::new(Srgba::from(*color).color(), *position))
+            .collect();
+        //Hints outside of their color stops are clamped between them.
+        let hints: Vec<Option<f32>> = hints.into_iter().zip(positions.windows(2))
+            .map(|(hint, positions)| hint.map(|x| x.clamp(positions[0], positions[1])))
+            .collect();
+        let gradient = Self::new(&stops, space).and_then(|x| x.with_hints(&hints)).ok_or(ParseGradientError)?;
+        Ok((kind, gradient))
+    }
+}
+
+//Percentage of a position, rounded to 4 decimals.
+fn percentage(position: f32) -> String {
+    format!("{}%", (position * 1e6f32).round() / 1e4f32)
+}
+
+//Splits the arguments of a CSS function on the commas which aren't in nested functions.
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut split = Vec::new();
+    for (i, character) in arguments.char_indices() {
+        match character {
+            '('                 => depth += 1,
+            ')'                 => depth -= 1,
+            ',' if depth == 0   => {
+                split.push(arguments[start..i].trim());
+                start = i + 1;
+            },
+            _                   => (),
+        }
+    }
+    split.push(arguments[start..].trim());
+    split
+}
+
+//Parses the first argument of a gradient which isn't a color stop, returning its color
+//interpolation method if it has one. The geometry of the gradient, written before or after the
+//color interpolation method, is checked then skipped.
+fn parse_prelude(prelude: &str, kind: GradientKind) -> Result<Option<InterpolationSpace>, ParseGradientError> {
+    let words: Vec<String> = prelude.split_whitespace().map(|x| x.to_ascii_lowercase()).collect();
+    let Some(start) = words.iter().position(|x| x == "in") else {
+        check_geometry(&words, kind)?;
+        return Ok(None);
+    };
+    let space = words.get(start + 1).ok_or(ParseGradientError)?;
+    //The hue interpolation method is followed by the `hue` keyword.
+    let (hue_interpolation, end) = match (words.get(start + 2), words.get(start + 3)) {
+        (Some(method), Some(hue)) if hue == "hue"   => match method.as_str() {
+            "shorter"       => (Some(HueInterpolation::Shorter), start + 4),
+            "longer"        => (Some(HueInterpolation::Longer), start + 4),
+            "increasing"    => (Some(HueInterpolation::Increasing), start + 4),
+            "decreasing"    => (Some(HueInterpolation::Decreasing), start + 4),
+            _               => return Err(ParseGradientError),
+        },
+        _                                           => (None, start + 2),
+    };
+    let space = match (space.as_str(), hue_interpolation) {
+        ("srgb", None)                  => InterpolationSpace::Srgb,
+        ("srgb-linear", None)           => InterpolationSpace::SrgbLinear,
+        ("xyz" | "xyz-d65", None)       => InterpolationSpace::Ciexyz,
+        ("lab", None)                   => InterpolationSpace::Cielab,
+        ("lch", hue_interpolation)      => InterpolationSpace::Cielch(hue_interpolation.unwrap_or_default()),
+        ("oklab", None)                 => InterpolationSpace::Oklab,
+        ("oklch", hue_interpolation)    => InterpolationSpace::Oklch(hue_interpolation.unwrap_or_default()),
+        _                               => return Err(ParseGradientError),
+    };
+    //The geometry is either entirely before or entirely after the color interpolation method.
+    match (&words[..start], &words[end..]) {
+        ([], geometry) | (geometry, [])     => check_geometry(geometry, kind)?,
+        _                                   => return Err(ParseGradientError),
+    }
+    Ok(Some(space))
+}
+
+//Checks the lowercase words of the geometry of a gradient: `[<angle> | to <side-or-corner>]` for
+//linear gradients, `[<shape> || <size>] [at <position>]` for radial gradients and
+//`[from <angle>] [at <position>]` for conic gradients.
+fn check_geometry(words: &[String], kind: GradientKind) -> Result<(), ParseGradientError> {
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let (words, position) = match words.iter().position(|x| *x == "at") {
+        Some(at) if kind != GradientKind::Linear    => (&words[..at], Some(&words[at + 1..])),
+        _                                           => (&words[..], None),
+    };
+    let valid = match kind {
+        GradientKind::Linear    => match words {
+            []                  => true,
+            [angle]             => is_angle(angle),
+            ["to", sides @ ..]  => {
+                let horizontal = sides.iter().filter(|x| ["left", "right"].contains(x)).count();
+                let vertical = sides.iter().filter(|x| ["top", "bottom"].contains(x)).count();
+                !sides.is_empty() && horizontal <= 1 && vertical <= 1 && horizontal + vertical == sides.len()
+            },
+            _                   => false,
+        },
+        GradientKind::Radial    => {
+            let extents = ["closest-side", "closest-corner", "farthest-side", "farthest-corner"];
+            let shapes = words.iter().filter(|x| ["circle", "ellipse"].contains(x)).count();
+            let sizes = words.iter().filter(|x| extents.contains(x)).count();
+            let lengths = words.iter().filter(|x| is_length_percentage(x)).count();
+            shapes <= 1 && sizes + lengths.min(1) <= 1 && lengths <= 2 && shapes + sizes + lengths == words.len()
+        },
+        GradientKind::Conic     => match words {
+            []                  => true,
+            ["from", angle]     => is_angle(angle),
+            _                   => false,
+        },
+    };
+    //A position is made of up to 4 keywords or lengths.
+    let valid_position = position.is_none_or(|words| {
+        let keywords = ["left", "center", "right", "top", "bottom"];
+        (1..=4).contains(&words.len()) && words.iter().all(|x| keywords.contains(x) || is_length_percentage(x))
+    });
+    match valid && valid_position {
+        true    => Ok(()),
+        false   => Err(ParseGradientError),
+    }
+}
+
+//Whether a word is a CSS angle.
+fn is_angle(word: &str) -> bool {
+    !word.ends_with('%') && parse_position(word, GradientKind::Conic).is_ok()
+}
+
+//Whether a word is a CSS length or percentage.
+fn is_length_percentage(word: &str) -> bool {
+    let units = ["%", "px", "em", "rem", "ex", "ch", "vw", "vh", "vmin", "vmax", "cm", "mm", "q", "in", "pt", "pc"];
+    let unit = word.trim_start_matches(|x: char| x.is_ascii_digit() || "+-.".contains(x));
+    let number = word[..word.len() - unit.len()].parse::<f32>();
+    match number {
+        Ok(x) if x.is_finite()  => units.contains(&unit) || (unit.is_empty() && x == 0f32),
+        _                       => false,
+    }
+}
+
+//Parses a color stop, made of a color followed by up to two positions. A color with two
+//positions is the same as two color stops of that color.
+fn parse_stop(stop: &str, kind: GradientKind) -> Result<Vec<(CssColor, Option<f32>)>, ParseGradientError> {
+    //The color is either a function, which may contain spaces and nested parentheses, or a
+    //single word.
+    let end = match stop.find('(') {
+        Some(x) if !stop[..x].contains(char::is_whitespace) => closing_parenthesis(stop, x).ok_or(ParseGradientError)? + 1,
+        _                                                   => stop.find(char::is_whitespace).unwrap_or(stop.len()),
+    };
+    let color: CssColor = stop[..end].parse().map_err(|_| ParseGradientError)?;
+    if color.alpha() != 1f32 {
+        return Err(ParseGradientError);
+    }
+    let positions = stop[end..].split_whitespace()
+        .map(|x| parse_position(x, kind))
+        .collect::<Result<Vec<f32>, _>>()?;
+    match positions[..] {
+        []                  => Ok(vec![(color, None)]),
+        [position]          => Ok(vec![(color, Some(position))]),
+        [first, second]     => Ok(vec![(color, Some(first)), (color, Some(second))]),
+        _                   => Err(ParseGradientError),
+    }
+}
+
+//Parses the position of a color stop or of a hint as a fraction of the gradient.
+fn parse_position(position: &str, kind: GradientKind) -> Result<f32, ParseGradientError> {
+    let (number, divisor) = match position.strip_suffix('%') {
+        Some(number)                            => (number, 100f32),
+        None if kind == GradientKind::Conic     => {
+            let unit = position.trim_start_matches(|x: char| x.is_ascii_digit() || "+-.eE".contains(x));
+            let divisor = match unit.to_ascii_lowercase().as_str() {
+                "deg"   => 360f32,
+                "grad"  => 400f32,
+                "rad"   => std::f32::consts::TAU,
+                "turn"  => 1f32,
+                _       => return Err(ParseGradientError),
+            };
+            (&position[..position.len() - unit.len()], divisor)
+        },
+        None                                    => return Err(ParseGradientError),
+    };
+    match number.parse::<f32>() {
+        Ok(x) if x.is_finite()  => Ok(x / divisor),
+        _                       => Err(ParseGradientError),
+    }
+}
+
+//Index of the parenthesis closing the one at the given index.
+fn closing_parenthesis(string: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, character) in string.char_indices().skip_while(|(i, _)| *i < open) {
+        match character {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _   => (),
+        }
+        if depth == 0 {
+            return Some(i);
+        }
+    }
+    None
+}
+
+//Fills in missing positions of color stops and makes them increasing, as CSS does.
+fn fix_positions(positions: &[Option<f32>]) -> Vec<f32> {
+    let mut positions = positions.to_vec();
+    let last = positions.len() - 1;
+    positions[0] = positions[0].or(Some(0f32));
+    positions[last] = positions[last].or(Some(1f32));
+    //A position can't be smaller than the position of a stop before it.
+    let mut largest = f32::NEG_INFINITY;
+    for position in positions.iter_mut().flatten() {
+        largest = largest.max(*position);
+        *position = largest;
+    }
+    //Missing positions are spread evenly between the positions around them.
+    let mut fixed = vec![0f32; positions.len()];
+    let mut previous = 0;
+    for i in 0..positions.len() {
+        if let Some(position) = positions[i] {
+            let start = fixed[previous];
+            for (j, missing) in fixed.iter_mut().enumerate().take(i).skip(previous + 1) {
+                *missing = start + (position - start) * (j - previous) as f32 / (i - previous) as f32;
+            }
+            fixed[i] = position;
+            previous = i;
+        }
+    }
+    fixed
+}
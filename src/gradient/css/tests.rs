@@ -0,0 +1,137 @@
+use super::*;
+
+fn red_to_blue(space: InterpolationSpace) -> Gradient {
+    Gradient::evenly_spaced(&[Srgb::new(255, 0, 0), Srgb::new(0, 0, 255)], space).unwrap()
+}
+
+#[test]
+fn to_css() {
+    assert_eq!(red_to_blue(InterpolationSpace::Srgb).to_css(GradientKind::Radial), "radial-gradient(#ff0000 0%, #0000ff 100%)");
+    assert_eq!(red_to_blue(InterpolationSpace::SrgbLinear).to_css(GradientKind::Conic), "conic-gradient(in srgb-linear, #ff0000 0%, #0000ff 100%)");
+    let lch = red_to_blue(InterpolationSpace::Cielch(HueInterpolation::Longer));
+    assert_eq!(lch.to_css(GradientKind::Linear), "linear-gradient(in lch longer hue, #ff0000 0%, #0000ff 100%)");
+    let hinted = red_to_blue(InterpolationSpace::Ciexyz).with_hints(&[Some(0.25)]).unwrap();
+    assert_eq!(hinted.to_css(GradientKind::Linear), "linear-gradient(in xyz-d65, #ff0000 0%, 25%, #0000ff 100%)");
+    let thirds = Gradient::evenly_spaced(&[Srgb::new(0, 0, 0); 4], InterpolationSpace::Srgb).unwrap();
+    assert_eq!(thirds.to_css(GradientKind::Linear), "linear-gradient(#000000 0%, #000000 33.3333%, #000000 66.6667%, #000000 100%)");
+}
+
+#[test]
+fn eased_to_css() {
+    let gradient = red_to_blue(InterpolationSpace::Srgb).with_easing(Easing::EaseIn);
+    let css = gradient.to_css(GradientKind::Linear);
+    let (_, parsed) = Gradient::from_css(&css).unwrap();
+    assert_eq!(parsed.stops().len(), EASING_STOPS + 1);
+    for position in [0.1, 0.3, 0.5, 0.7, 0.9] {
+        let [expected, actual]: [[u8; 3]; 2] = [gradient.sample(position), parsed.sample(position)].map(Into::into);
+        assert!((0..3).all(|i| expected[i].abs_diff(actual[i]) <= 2));
+    }
+}
+
+#[test]
+fn round_trip() {
+    let gradient = Gradient::new(&[
+        ColorStop::new(Srgb::new(10, 20, 30), -0.25),
+        ColorStop::new(Srgb::new(200, 100, 0), 0.5),
+        ColorStop::new(Srgb::new(0, 255, 128), 0.5),
+        ColorStop::new(Srgb::new(255, 255, 255), 1.25),
+    ], InterpolationSpace::Cielch(HueInterpolation::Decreasing)).unwrap();
+    let gradient = gradient.with_hints(&[Some(0.0), None, Some(1.0)]).unwrap();
+    for kind in [GradientKind::Linear, GradientKind::Radial, GradientKind::Conic] {
+        assert_eq!(Gradient::from_css(&gradient.to_css(kind)), Ok((kind, gradient.clone())));
+    }
+}
+
+#[test]
+fn missing_positions() {
+    let (_, gradient) = Gradient::from_css("linear-gradient(#000, #111, #222 50%, #333, #444, #555)").unwrap();
+    let expected = [0.0, 0.25, 0.5, 2.0 / 3.0, 5.0 / 6.0, 1.0];
+    assert!(gradient.stops().iter().zip(expected).all(|(stop, x)| (stop.position() - x).abs() < 1e-6));
+    let (_, gradient) = Gradient::from_css("linear-gradient(#000 40%, #111 20%, #222)").unwrap();
+    let positions: Vec<f32> = gradient.stops().iter().map(|x| x.position()).collect();
+    assert_eq!(positions, vec![0.4, 0.4, 1.0]);
+}
+
+#[test]
+fn two_positions() {
+    let (_, gradient) = Gradient::from_css("linear-gradient(#f00 0% 50%, #00f 50% 100%)").unwrap();
+    assert_eq!(gradient.stops().len(), 4);
+    assert_eq!(gradient.sample(0.25), Srgb::new(255, 0, 0));
+    assert_eq!(gradient.sample(0.75), Srgb::new(0, 0, 255));
+}
+
+#[test]
+fn prelude() {
+    let cases = [
+        ("linear-gradient(45deg, #f00, #00f)", InterpolationSpace::Srgb),
+        ("linear-gradient(to top left in srgb-linear, #f00, #00f)", InterpolationSpace::SrgbLinear),
+        ("radial-gradient(circle at 10% 20% in xyz, #f00, #00f)", InterpolationSpace::Ciexyz),
+        ("radial-gradient(in LAB, #f00, #00f)", InterpolationSpace::Cielab),
+        ("conic-gradient(from 90deg in lch increasing hue, #f00, #00f)", InterpolationSpace::Cielch(HueInterpolation::Increasing)),
+        ("conic-gradient(in lch, #f00, #00f)", InterpolationSpace::Cielch(HueInterpolation::Shorter)),
+        ("linear-gradient(in oklch longer hue, #f00, #00f)", InterpolationSpace::Oklch(HueInterpolation::Longer)),
+        ("linear-gradient(to right, hsl(0 100% 50%), hwb(240 0% 0%))", InterpolationSpace::Srgb),
+        ("linear-gradient(to right, #f00, lab(50 20 30))", InterpolationSpace::Oklab),
+        ("linear-gradient(red 10%, blue)", InterpolationSpace::Srgb),
+        ("radial-gradient(ellipse farthest-corner at left 10px top, #f00, #00f)", InterpolationSpace::Srgb),
+        ("radial-gradient(in oklab 50px 20% at center, #f00, #00f)", InterpolationSpace::Oklab),
+        ("conic-gradient(from 0.25turn at 50% 50% in srgb, #f00, #00f)", InterpolationSpace::Srgb),
+    ];
+    for (css, space) in cases {
+        assert_eq!(Gradient::from_css(css).unwrap().1.space(), space);
+    }
+}
+
+#[test]
+fn colors() {
+    let (_, gradient) = Gradient::from_css("linear-gradient(rgb(255, 0, 0), rgb(0 50% 100%) 60%, #ABCDEF)").unwrap();
+    let colors: Vec<Srgb> = gradient.stops().iter().map(|x| x.color()).collect();
+    assert_eq!(colors, vec![Srgb::new(255, 0, 0), Srgb::new(0, 128, 255), Srgb::new(0xab, 0xcd, 0xef)]);
+    let (_, gradient) = Gradient::from_css("linear-gradient(hsl(120deg 100% 50%) 10%, color(srgb 0 0 1 / 1) 90%)").unwrap();
+    let colors: Vec<Srgb> = gradient.stops().iter().map(|x| x.color()).collect();
+    assert_eq!(colors, vec![Srgb::new(0, 255, 0), Srgb::new(0, 0, 255)]);
+}
+
+#[test]
+fn conic_angles() {
+    let (_, gradient) = Gradient::from_css("conic-gradient(#f00 90deg, #0f0 0.5turn, #00f 400grad)").unwrap();
+    let positions: Vec<f32> = gradient.stops().iter().map(|x| x.position()).collect();
+    assert_eq!(positions, vec![0.25, 0.5, 1.0]);
+    assert!(Gradient::from_css("linear-gradient(#f00 90deg, #00f)").is_err());
+}
+
+#[test]
+fn invalid() {
+    for css in [
+        "",
+        "linear-gradient",
+        "linear-gradient(#f00, #00f",
+        "repeating-linear-gradient(#f00, #00f)",
+        "linear-gradient(#f00)",
+        "linear-gradient(#f00, 50%)",
+        "linear-gradient(#f00, 20%, 50%, #00f)",
+        "linear-gradient(20%, #f00, #00f)",
+        "linear-gradient(in hsl, #f00, #00f)",
+        "linear-gradient(in lab longer hue, #f00, #00f)",
+        "linear-gradient(#f00 10px, #00f)",
+        "linear-gradient(#f00 0% 10% 20%, #00f)",
+        "linear-gradient(#ff, #00f)",
+        "linear-gradient(rgb(1 2), #00f)",
+        "linear-gradient(rgb(1 2 3 / 0.5), #00f)",
+        "linear-gradient(#f008, #00f)",
+        "linear-gradient(transparent, #00f)",
+        "linear-gradient(garbage, #f00, #00f)",
+        "linear-gradient(to nowhere 12 zz, #f00, #00f)",
+        "linear-gradient(to left right, #f00, #00f)",
+        "linear-gradient(45px, #f00, #00f)",
+        "linear-gradient(to right in lab to left, #f00, #00f)",
+        "radial-gradient(@@@ in lab, #f00, #00f)",
+        "radial-gradient(circle circle, #f00, #00f)",
+        "radial-gradient(closest-side 10px, #f00, #00f)",
+        "radial-gradient(at, #f00, #00f)",
+        "conic-gradient(from 50%, #f00, #00f)",
+        "conic-gradient(at nowhere, #f00, #00f)",
+    ] {
+        assert_eq!(Gradient::from_css(css), Err(ParseGradientError), "{css}");
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+fn two_stops(first: Srgb, second: Srgb, space: InterpolationSpace) -> Gradient {
+    Gradient::evenly_spaced(&[first, second], space).unwrap()
+}
+
+#[test]
+fn invalid() {
+    let red = Srgb::new(255, 0, 0);
+    assert!(Gradient::new(&[], InterpolationSpace::Srgb).is_none());
+    assert!(Gradient::evenly_spaced(&[], InterpolationSpace::Srgb).is_none());
+    assert!(Gradient::new(&[ColorStop::new(red, f32::NAN)], InterpolationSpace::Srgb).is_none());
+    let gradient = two_stops(red, red, InterpolationSpace::Srgb);
+    assert!(gradient.clone().with_hints(&[]).is_none());
+    assert!(gradient.clone().with_hints(&[Some(1.5)]).is_none());
+    assert!(gradient.with_hints(&[Some(0.5)]).is_some());
+}
+
+#[test]
+fn single_stop() {
+    let red = Srgb::new(255, 0, 0);
+    let gradient = Gradient::evenly_spaced(&[red], InterpolationSpace::Cielab).unwrap();
+    assert_eq!(gradient.colors(4), vec![red; 4]);
+}
+
+#[test]
+fn endpoints() {
+    let red = Srgb::new(255, 0, 0);
+    let blue = Srgb::new(0, 0, 255);
+    for space in [
+        InterpolationSpace::Srgb,
+        InterpolationSpace::SrgbLinear,
+        InterpolationSpace::Ciexyz,
+        InterpolationSpace::Cielab,
+        InterpolationSpace::Cielch(HueInterpolation::Longer),
+    ] {
+        let colors = two_stops(red, blue, space).colors(5);
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[0], red);
+        assert_eq!(colors[4], blue);
+    }
+}
+
+#[test]
+fn cielab_midpoint() {
+    //color-mix(in lab, white, black) is lab(50 0 0).
+    let gradient = two_stops(Srgb::new(255, 255, 255), Srgb::new(0, 0, 0), InterpolationSpace::Cielab);
+    assert_eq!(gradient.sample(0.5), Srgb::new(119, 119, 119));
+}
+
+#[test]
+fn oklab_midpoint() {
+    //color-mix(in oklab, white, black) is oklab(0.5 0 0).
+    let gradient = two_stops(Srgb::new(255, 255, 255), Srgb::new(0, 0, 0), InterpolationSpace::Oklab);
+    assert_eq!(gradient.sample(0.5), Srgb::new(99, 99, 99));
+}
+
+#[test]
+fn out_of_gamut() {
+    //Going around the hue circle from red to blue the long way passes through colors with
+    //too much chroma for sRGB.
+    let gradient = two_stops(Srgb::new(255, 0, 0), Srgb::new(0, 0, 255), InterpolationSpace::Cielch(HueInterpolation::Longer));
+    for color in gradient.colors(20) {
+        let [r, g, b]: [f32; 3] = color.into();
+        assert!([r, g, b].iter().all(|x| (0.0..=1.0).contains(x)));
+    }
+    let middle: Cielch = gradient.sample(0.5).into();
+    assert!(middle.hue().to_degrees() > 90.0 && middle.hue().to_degrees() < 240.0);
+}
+
+#[test]
+fn sharp_transition() {
+    let red = Srgb::new(255, 0, 0);
+    let blue = Srgb::new(0, 0, 255);
+    let gradient = Gradient::new(&[
+        ColorStop::new(red, 0.0),
+        ColorStop::new(red, 0.5),
+        ColorStop::new(blue, 0.5),
+        ColorStop::new(blue, 1.0),
+    ], InterpolationSpace::Srgb).unwrap();
+    assert_eq!(gradient.sample(0.49), red);
+    assert_eq!(gradient.sample(0.5), blue);
+}
+
+#[test]
+fn hints() {
+    let black = Srgb::new(0, 0, 0);
+    let white = Srgb::new(255, 255, 255);
+    let gradient = Gradient::new(&[ColorStop::new(black, 0.2), ColorStop::new(white, 0.6)], InterpolationSpace::Srgb).unwrap();
+    let hinted = gradient.clone().with_hints(&[Some(0.3)]).unwrap();
+    assert_eq!(hinted.sample(0.3), Srgb::new(128, 128, 128));
+    assert_eq!(hinted.sample(0.2), black);
+    assert_eq!(hinted.sample(0.6), white);
+    assert_eq!(gradient.clone().with_hints(&[None]).unwrap(), gradient);
+    let at_start = gradient.with_hints(&[Some(0.2)]).unwrap();
+    assert_eq!(at_start.sample(0.21), white);
+}
+
+#[test]
+fn easing() {
+    assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    for easing in [Easing::Ease, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut, Easing::CubicBezier(0.1, 0.7, 0.9, 0.2)] {
+        assert!(easing.apply(0.0).abs() < 1e-5);
+        assert!((easing.apply(1.0) - 1.0).abs() < 1e-5);
+    }
+    assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-5);
+    assert!((Easing::EaseInOut.apply(0.25) + Easing::EaseInOut.apply(0.75) - 1.0).abs() < 1e-5);
+    assert!(Easing::EaseIn.apply(0.25) < 0.25);
+    assert!(Easing::EaseOut.apply(0.25) > 0.25);
+    //Reference value of cubic-bezier(0.25, 0.1, 0.25, 1) at 0.5.
+    assert!((Easing::Ease.apply(0.5) - 0.8024).abs() < 1e-3);
+    let black = Srgb::new(0, 0, 0);
+    let white = Srgb::new(255, 255, 255);
+    let gradient = two_stops(black, white, InterpolationSpace::Srgb).with_easing(Easing::EaseIn);
+    assert!(gradient.sample(0.25).red() < 0.25);
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+use crate::{
+    ciexyz,
+    Ciexyz,
+};
+
+/// A standard illuminant, used as the reference white of color spaces relative to a white
+/// point such as CIELAB and CIELCh.
+///
+/// All white points are given for the CIE 1931 2° standard observer.
+pub trait Illuminant: fmt::Debug + PartialEq + Copy {
+    /// White point of the illuminant.
+    const WHITE_POINT: Ciexyz;
+}
+
+/// A (incandescent / tungsten) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct A;
+
+/// C (average / north sky daylight) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct C;
+
+/// D50 (horizon light) standard illuminant, the reference white of ICC profiles.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct D50;
+
+/// D55 (mid-morning / mid-afternoon daylight) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct D55;
+
+/// D65 (noon daylight) standard illuminant, the reference white of sRGB.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct D65;
+
+/// D75 (north sky daylight) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct D75;
+
+/// E (equal energy) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct E;
+
+/// F2 (cool white fluorescent) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct F2;
+
+/// F7 (broadband daylight fluorescent) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct F7;
+
+/// F11 (narrow tri-band fluorescent) standard illuminant.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct F11;
+
+impl Illuminant for A {
+    const WHITE_POINT: Ciexyz = ciexyz::A;
+}
+
+impl Illuminant for C {
+    const WHITE_POINT: Ciexyz = ciexyz::C;
+}
+
+impl Illuminant for D50 {
+    const WHITE_POINT: Ciexyz = ciexyz::D50;
+}
+
+impl Illuminant for D55 {
+    const WHITE_POINT: Ciexyz = ciexyz::D55;
+}
+
+impl Illuminant for D65 {
+    const WHITE_POINT: Ciexyz = ciexyz::D65;
+}
+
+impl Illuminant for D75 {
+    const WHITE_POINT: Ciexyz = ciexyz::D75;
+}
+
+impl Illuminant for E {
+    const WHITE_POINT: Ciexyz = ciexyz::E;
+}
+
+impl Illuminant for F2 {
+    const WHITE_POINT: Ciexyz = ciexyz::F2;
+}
+
+impl Illuminant for F7 {
+    const WHITE_POINT: Ciexyz = ciexyz::F7;
+}
+
+impl Illuminant for F11 {
+    const WHITE_POINT: Ciexyz = ciexyz::F11;
+}
@@ -0,0 +1,283 @@
+//! Interpolation between colors, following the `color-mix()` function of CSS Color 4 in each
+//! color space.
+
+#[cfg(test)]
+mod tests;
+
+use std::f32::consts::{
+    PI,
+    TAU,
+};
+use crate::{
+    illuminant::Illuminant,
+    lms::ConeFundamentals,
+    srgb::gamma_compress,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Lms,
+    Oklab,
+    Oklch,
+    Srgb,
+    Ycocg,
+    YcocgR,
+};
+
+//Chroma under which the hue of a polar color is considered powerless. This is not 0 so that
+//achromatic colors converted from other color spaces with rounding errors are treated as such.
+const POWERLESS_CHROMA: f32 = 1e-4;
+
+/// A color that can be interpolated with another color of the same type.
+///
+/// The interpolation is linear on the components of the color space of the type. Colors are
+/// interpolated the same way as by the CSS `color-mix()` function in that color space, the
+/// hues of polar color spaces taking the shorter path around the hue circle.
+pub trait Mix: Sized {
+    /// Interpolates between two colors. An amount of 0 gives the first color (`self`) and an
+    /// amount of 1 gives the second. The amount is clamped between 0 and 1, a NaN amount gives
+    /// the first color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ interpolation::Mix, Srgb };
+    /// let red = Srgb::new(255, 0, 0);
+    /// let blue = Srgb::new(0, 0, 255);
+    /// assert_eq!(red.mix(&blue, 0.5), Srgb::new(128, 0, 128));
+    /// assert_eq!(red.mix(&blue, 0.0), red);
+    /// ```
+    fn mix(&self, other: &Self, amount: f32) -> Self;
+}
+
+/// The way hues are interpolated around the hue circle, as the hue interpolation methods of
+/// CSS Color 4.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum HueInterpolation {
+    /// Takes the shorter arc between the hues.
+    #[default]
+    Shorter,
+    /// Takes the longer arc between the hues.
+    Longer,
+    /// Takes the arc going in the direction of increasing hues.
+    Increasing,
+    /// Takes the arc going in the direction of decreasing hues.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Interpolates between two hues in radians. The resulting hue is between 0 included and
+    /// 2π excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::interpolation::HueInterpolation;
+    /// let (a, b) = (30f32.to_radians(), 350f32.to_radians());
+    /// let shorter = HueInterpolation::Shorter.interpolate(a, b, 0.5);
+    /// let longer = HueInterpolation::Longer.interpolate(a, b, 0.5);
+    /// assert!((shorter.to_degrees() - 10.0).abs() < 1e-3);
+    /// assert!((longer.to_degrees() - 190.0).abs() < 1e-3);
+    /// ```
+    pub fn interpolate(&self, first: f32, second: f32, amount: f32) -> f32 {
+        let (mut first, mut second) = (first.rem_euclid(TAU), second.rem_euclid(TAU));
+        let difference = second - first;
+        match self {
+            HueInterpolation::Shorter       => match difference {
+                x if x > PI     => first += TAU,
+                x if x < -PI    => second += TAU,
+                _               => (),
+            },
+            HueInterpolation::Longer        => match difference {
+                x if x > 0f32 && x < PI     => first += TAU,
+                x if x > -PI && x <= 0f32   => second += TAU,
+                _                           => (),
+            },
+            HueInterpolation::Increasing    => if second < first {
+                second += TAU;
+            },
+            HueInterpolation::Decreasing    => if first < second {
+                first += TAU;
+            },
+        }
+        let hue = (first + (second - first) * clamp_amount(amount)).rem_euclid(TAU);
+        //rem_euclid can round up to TAU itself.
+        match hue < TAU {
+            true    => hue,
+            false   => 0f32,
+        }
+    }
+}
+
+//Linear interpolation between two values, which never leaves the range between them.
+pub(crate) fn lerp(first: f32, second: f32, amount: f32) -> f32 {
+    let value = first + (second - first) * clamp_amount(amount);
+    value.clamp(first.min(second), first.max(second))
+}
+
+//Amount of an interpolation clamped between 0 and 1, a NaN amount being the same as 0.
+fn clamp_amount(amount: f32) -> f32 {
+    match amount.is_nan() {
+        true    => 0f32,
+        false   => amount.clamp(0f32, 1f32),
+    }
+}
+
+//Linear interpolation between two sets of components.
+fn lerp_components(first: [f32; 3], second: [f32; 3], amount: f32) -> [f32; 3] {
+    [0, 1, 2].map(|i| lerp(first[i], second[i], amount))
+}
+
+/// Interpolation between two sRGB colors, on their gamma compressed components.
+/// This is `color-mix(in srgb, ...)`.
+impl Mix for Srgb {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        lerp_components((*self).into(), (*other).into(), amount).into()
+    }
+}
+
+impl Srgb {
+    /// Interpolates between two sRGB colors on their linear components.
+    /// This is `color-mix(in srgb-linear, ...)`.
+    ///
+    /// An amount of 0 gives the first color (`self`) and an amount of 1 gives the second.
+    /// The amount is clamped between 0 and 1, a NaN amount gives the first color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Srgb;
+    /// let red = Srgb::new(255, 0, 0);
+    /// let blue = Srgb::new(0, 0, 255);
+    /// assert_eq!(red.mix_linear(&blue, 0.5), Srgb::new(188, 0, 188));
+    /// ```
+    pub fn mix_linear(&self, other: &Self, amount: f32) -> Self {
+        let first = [self.linear_red(), self.linear_green(), self.linear_blue()];
+        let second = [other.linear_red(), other.linear_green(), other.linear_blue()];
+        lerp_components(first, second, amount).map(gamma_compress).into()
+    }
+}
+
+/// Interpolation between two CIEXYZ colors.
+/// This is `color-mix(in xyz-d65, ...)`.
+impl Mix for Ciexyz {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        Self::try_from(lerp_components((*self).into(), (*other).into(), amount)).unwrap()
+    }
+}
+
+/// Interpolation between two CIELAB colors.
+/// This is `color-mix(in lab, ...)` when the reference white is D50.
+impl<W: Illuminant> Mix for Cielab<W> {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        Self::try_from(lerp_components((*self).into(), (*other).into(), amount)).unwrap()
+    }
+}
+
+/// Interpolation between two CIELCh colors, taking the shorter path around the hue circle.
+/// This is `color-mix(in lch, ...)` when the reference white is D50.
+impl<W: Illuminant> Mix for Cielch<W> {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        self.mix_hue(other, amount, HueInterpolation::Shorter)
+    }
+}
+
+impl<W: Illuminant> Cielch<W> {
+    /// Interpolates between two CIELCh colors with a given hue interpolation method.
+    /// This is `color-mix(in lch <hue-interpolation-method> hue, ...)` when the reference white
+    /// is D50.
+    ///
+    /// An amount of 0 gives the first color (`self`) and an amount of 1 gives the second.
+    /// The amount is clamped between 0 and 1, a NaN amount gives the first color. The hue of a
+    /// color without chroma is powerless, the hue of the other color is then used for both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Cielch, interpolation::HueInterpolation };
+    /// let a = Cielch::new(50.0, 40.0, 30f32.to_radians()).unwrap();
+    /// let b = Cielch::new(50.0, 40.0, 90f32.to_radians()).unwrap();
+    /// let increasing = a.mix_hue(&b, 0.5, HueInterpolation::Increasing);
+    /// let decreasing = a.mix_hue(&b, 0.5, HueInterpolation::Decreasing);
+    /// assert!((increasing.hue().to_degrees() - 60.0).abs() < 1e-3);
+    /// assert!((decreasing.hue().to_degrees() - 240.0).abs() < 1e-3);
+    /// ```
+    pub fn mix_hue(&self, other: &Self, amount: f32, hue_interpolation: HueInterpolation) -> Self {
+        Self::try_from(lerp_polar((*self).into(), (*other).into(), amount, hue_interpolation)).unwrap()
+    }
+}
+
+//Linear interpolation between the lightness, chroma and hue components of two polar colors.
+//The hue of a color without chroma is powerless, the hue of the other color is used for both.
+fn lerp_polar(first: [f32; 3], second: [f32; 3], amount: f32, hue_interpolation: HueInterpolation) -> [f32; 3] {
+    let [l1, c1, h1] = first;
+    let [l2, c2, h2] = second;
+    let (h1, h2) = match (c1 < POWERLESS_CHROMA, c2 < POWERLESS_CHROMA) {
+        (true, false)   => (h2, h2),
+        (false, true)   => (h1, h1),
+        _               => (h1, h2),
+    };
+    let hue = hue_interpolation.interpolate(h1, h2, amount);
+    [lerp(l1, l2, amount), lerp(c1, c2, amount), hue]
+}
+
+/// Interpolation between two Oklab colors.
+/// This is `color-mix(in oklab, ...)`.
+impl Mix for Oklab {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        Self::try_from(lerp_components((*self).into(), (*other).into(), amount)).unwrap()
+    }
+}
+
+/// Interpolation between two Oklch colors, taking the shorter path around the hue circle.
+/// This is `color-mix(in oklch, ...)`.
+impl Mix for Oklch {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        self.mix_hue(other, amount, HueInterpolation::Shorter)
+    }
+}
+
+impl Oklch {
+    /// Interpolates between two Oklch colors with a given hue interpolation method.
+    /// This is `color-mix(in oklch <hue-interpolation-method> hue, ...)`.
+    ///
+    /// An amount of 0 gives the first color (`self`) and an amount of 1 gives the second.
+    /// The amount is clamped between 0 and 1, a NaN amount gives the first color. The hue of a
+    /// color without chroma is powerless, the hue of the other color is then used for both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Oklch, interpolation::HueInterpolation };
+    /// let a = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap();
+    /// let b = Oklch::new(0.5, 0.1, 90f32.to_radians()).unwrap();
+    /// let longer = a.mix_hue(&b, 0.5, HueInterpolation::Longer);
+    /// assert!((longer.hue().to_degrees() - 240.0).abs() < 1e-3);
+    /// ```
+    pub fn mix_hue(&self, other: &Self, amount: f32, hue_interpolation: HueInterpolation) -> Self {
+        Self::try_from(lerp_polar((*self).into(), (*other).into(), amount, hue_interpolation)).unwrap()
+    }
+}
+
+/// Interpolation between two LMS colors.
+impl<M: ConeFundamentals> Mix for Lms<M> {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        lerp_components((*self).into(), (*other).into(), amount).into()
+    }
+}
+
+/// Interpolation between two YCoCg colors.
+impl Mix for Ycocg {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        Self::try_from(lerp_components((*self).into(), (*other).into(), amount)).unwrap()
+    }
+}
+
+/// Interpolation between two YCoCg-R colors.
+/// The interpolated components are rounded to the nearest integers.
+impl Mix for YcocgR {
+    fn mix(&self, other: &Self, amount: f32) -> Self {
+        let first = <[i16; 3]>::from(*self).map(f32::from);
+        let second = <[i16; 3]>::from(*other).map(f32::from);
+        Self::try_from(lerp_components(first, second, amount).map(|x| x.round() as i16)).unwrap()
+    }
+}
@@ -0,0 +1,138 @@
+use super::*;
+use crate::{
+    illuminant::D50,
+    lms::Bradford,
+    Alpha,
+};
+
+fn hue_between(hue_interpolation: HueInterpolation, first: f32, second: f32) -> f32 {
+    hue_interpolation.interpolate(first.to_radians(), second.to_radians(), 0.5).to_degrees()
+}
+
+#[test]
+fn hue_interpolation() {
+    let cases = [
+        (HueInterpolation::Shorter, 30.0, 350.0, 10.0),
+        (HueInterpolation::Shorter, 350.0, 30.0, 10.0),
+        (HueInterpolation::Shorter, 30.0, 90.0, 60.0),
+        (HueInterpolation::Longer, 30.0, 350.0, 190.0),
+        (HueInterpolation::Longer, 30.0, 90.0, 240.0),
+        (HueInterpolation::Longer, 90.0, 30.0, 240.0),
+        (HueInterpolation::Increasing, 350.0, 30.0, 10.0),
+        (HueInterpolation::Increasing, 30.0, 350.0, 190.0),
+        (HueInterpolation::Decreasing, 30.0, 350.0, 10.0),
+        (HueInterpolation::Decreasing, 350.0, 30.0, 190.0),
+        (HueInterpolation::Shorter, -10.0, 370.0, 0.0),
+    ];
+    for (hue_interpolation, first, second, expected) in cases {
+        let hue = hue_between(hue_interpolation, first, second);
+        assert!((hue - expected).abs() < 1e-3, "{:?} {} {} {}", hue_interpolation, first, second, hue);
+    }
+}
+
+#[test]
+fn hue_range() {
+    for method in [HueInterpolation::Shorter, HueInterpolation::Longer, HueInterpolation::Increasing, HueInterpolation::Decreasing] {
+        for amount in [0.0, 0.25, 0.5, 1.0] {
+            let hue = method.interpolate(0.0, TAU - 1e-7, amount);
+            assert!((0.0..TAU).contains(&hue));
+        }
+    }
+}
+
+#[test]
+fn amount_is_clamped() {
+    let red = Srgb::new(255, 0, 0);
+    let blue = Srgb::new(0, 0, 255);
+    assert_eq!(red.mix(&blue, -1.0), red);
+    assert_eq!(red.mix(&blue, 2.0), blue);
+    assert_eq!(red.mix_linear(&blue, 2.0), blue);
+}
+
+#[test]
+fn srgb() {
+    let red = Srgb::new(255, 0, 0);
+    let blue = Srgb::new(0, 0, 255);
+    assert_eq!(red.mix(&blue, 0.5), Srgb::new(128, 0, 128));
+    assert_eq!(red.mix(&blue, 0.25), Srgb::new(191, 0, 64));
+    assert_eq!(red.mix_linear(&blue, 0.5), Srgb::new(188, 0, 188));
+}
+
+#[test]
+fn cielab() {
+    let a = Cielab::with_reference_white(20.0, -30.0, 40.0, D50).unwrap();
+    let b = Cielab::with_reference_white(80.0, 10.0, -20.0, D50).unwrap();
+    assert_eq!(a.mix(&b, 0.5), Cielab::with_reference_white(50.0, -10.0, 10.0, D50).unwrap());
+    assert_eq!(a.mix(&b, 1.0), b);
+}
+
+#[test]
+fn cielch_powerless_hue() {
+    let gray = Cielch::new(50.0, 0.0, 0.0).unwrap();
+    let orange = Cielch::new(70.0, 60.0, 60f32.to_radians()).unwrap();
+    let mixed = gray.mix(&orange, 0.5);
+    assert!((mixed.hue() - 60f32.to_radians()).abs() < 1e-5);
+    assert!((mixed.chroma() - 30.0).abs() < 1e-5);
+    assert!((mixed.lightness() - 60.0).abs() < 1e-5);
+    let mixed = orange.mix(&gray, 0.5);
+    assert!((mixed.hue() - 60f32.to_radians()).abs() < 1e-5);
+    let white = Cielch::from(Srgb::new(255, 255, 255));
+    assert!((white.mix(&orange, 0.1).hue() - 60f32.to_radians()).abs() < 1e-5);
+}
+
+#[test]
+fn cielch_hue_interpolation() {
+    let a = Cielch::new(50.0, 40.0, 30f32.to_radians()).unwrap();
+    let b = Cielch::new(50.0, 40.0, 350f32.to_radians()).unwrap();
+    assert!((a.mix(&b, 0.5).hue().to_degrees() - 10.0).abs() < 1e-3);
+    assert!((a.mix_hue(&b, 0.5, HueInterpolation::Longer).hue().to_degrees() - 190.0).abs() < 1e-3);
+}
+
+#[test]
+fn ciexyz() {
+    let a = Ciexyz::new(0.2, 0.4, 0.6).unwrap();
+    let b = Ciexyz::new(0.4, 0.2, 1.0).unwrap();
+    let [x, y, z]: [f32; 3] = a.mix(&b, 0.5).into();
+    assert!((x - 0.3).abs() < 1e-6 && (y - 0.3).abs() < 1e-6 && (z - 0.8).abs() < 1e-6);
+}
+
+#[test]
+fn ycocg() {
+    let a = Ycocg::new(0.0, -0.5, 0.5).unwrap();
+    let b = Ycocg::new(1.0, 0.5, -0.5).unwrap();
+    assert_eq!(a.mix(&b, 0.5), Ycocg::new(0.5, 0.0, 0.0).unwrap());
+    let a = YcocgR::new(0, -255, 255).unwrap();
+    let b = YcocgR::new(255, 255, -255).unwrap();
+    assert_eq!(a.mix(&b, 0.5), YcocgR::new(128, 0, 0).unwrap());
+}
+
+#[test]
+fn lms() {
+    let a = Lms::<Bradford>::new(0.1, 0.2, 0.3);
+    let b = Lms::new(0.3, 0.2, 0.1);
+    let [l, m, s]: [f32; 3] = a.mix(&b, 0.5).into();
+    assert!((l - 0.2).abs() < 1e-6 && (m - 0.2).abs() < 1e-6 && (s - 0.2).abs() < 1e-6);
+}
+
+#[test]
+fn extremes() {
+    let black = Ciexyz::new(0.0, 0.0, 0.0).unwrap();
+    assert_eq!(black.mix(&black, 0.5), black);
+    let white = Cielab::new(100.0, 0.0, 0.0).unwrap();
+    assert_eq!(white.mix(&white, 0.5), white);
+    let white = Cielch::new(100.0, 0.0, 0.0).unwrap();
+    assert_eq!(white.mix(&white, 0.5), white);
+}
+
+#[test]
+fn nan_amount() {
+    let a = Cielab::new(50.0, 10.0, 20.0).unwrap();
+    let b = Cielab::new(70.0, -10.0, 0.0).unwrap();
+    assert_eq!(a.mix(&b, f32::NAN), a);
+    let a = Cielch::new(50.0, 10.0, 1.0).unwrap();
+    let b = Cielch::new(70.0, 20.0, 3.0).unwrap();
+    assert_eq!(a.mix_hue(&b, f32::NAN, HueInterpolation::Longer), a);
+    let a = Alpha::new(Srgb::new(200, 30, 40), 0.2).unwrap();
+    let b = Alpha::new(Srgb::new(10, 60, 20), 0.8).unwrap();
+    assert_eq!(a.mix(&b, f32::NAN).alpha(), 0.2);
+}
@@ -1,9 +1,31 @@
+pub mod adaptation;
+pub mod alpha;
 pub mod cielab;
 pub mod cielch;
 pub mod ciexyz;
+pub mod compositing;
+pub mod contrast;
+pub mod css;
+pub mod cvd;
+pub mod difference;
 pub mod error;
+pub mod gradient;
+pub mod illuminant;
+pub mod interpolation;
+pub mod lms;
+pub mod matrix;
+pub mod named;
+pub mod oklab;
+pub mod oklch;
 pub mod srgb;
+pub mod x11;
+pub mod ycocg;
+pub use alpha::{ Alpha, Premultiplied, Srgba };
 pub use cielab::Cielab;
 pub use cielch::Cielch;
 pub use ciexyz::Ciexyz;
+pub use lms::Lms;
+pub use oklab::Oklab;
+pub use oklch::Oklch;
 pub use srgb::Srgb;
+pub use ycocg::{ Ycocg, YcocgR };
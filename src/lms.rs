@@ -0,0 +1,150 @@
+mod conversions;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fmt,
+    marker::PhantomData,
+};
+use crate::matrix;
+
+/// A struct representing a color in an LMS color space, the response of the long, medium and
+/// short wavelength cones of the human eye.
+///
+/// There is no single LMS color space, the cone responses depend on the cone fundamentals
+/// used to derive them from CIEXYZ. Those are selected by the `M` type parameter.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Lms<M>(f32, f32, f32, PhantomData<M>); // long, medium, short
+
+/// A set of cone fundamentals, defining an LMS color space through its conversion matrix
+/// from CIEXYZ.
+pub trait ConeFundamentals: fmt::Debug + PartialEq + Copy {
+    /// Conversion matrix to convert from CIEXYZ to LMS, in row-major order.
+    const FROM_CIEXYZ: [f32; 9];
+    /// Conversion matrix to convert from LMS to CIEXYZ, in row-major order.
+    const TO_CIEXYZ: [f32; 9] = matrix::inverse_unchecked(Self::FROM_CIEXYZ);
+}
+
+/// Hunt-Pointer-Estévez cone fundamentals, normalized to the equal energy illuminant.
+/// Those are the cone responses used by the von Kries chromatic adaptation transform.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HuntPointerEstevez;
+
+/// Cone responses used by the von Kries chromatic adaptation transform,
+/// which are the Hunt-Pointer-Estévez cone fundamentals.
+pub type VonKries = HuntPointerEstevez;
+
+/// Bradford "sharpened" cone responses, from the Bradford chromatic adaptation transform.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Bradford;
+
+/// CAT02 "sharpened" cone responses, from the CIECAM02 color appearance model.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cat02;
+
+/// CAT16 "sharpened" cone responses, from the CAM16 color appearance model.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cat16;
+
+/// Stockman-Sharpe 2° cone fundamentals.
+/// The matrix is defined for the CIE 2006 physiological observer, using it with CIE 1931
+/// tristimulus values is an approximation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct StockmanSharpe;
+
+impl ConeFundamentals for HuntPointerEstevez {
+    const FROM_CIEXYZ: [f32; 9] = [
+        0.38971f32, 0.68898f32, -0.07868f32,
+        -0.22981f32, 1.18340f32, 0.04641f32,
+        0f32, 0f32, 1f32,
+    ];
+}
+
+impl ConeFundamentals for Bradford {
+    const FROM_CIEXYZ: [f32; 9] = [
+        0.8951f32, 0.2664f32, -0.1614f32,
+        -0.7502f32, 1.7135f32, 0.0367f32,
+        0.0389f32, -0.0685f32, 1.0296f32,
+    ];
+}
+
+impl ConeFundamentals for Cat02 {
+    const FROM_CIEXYZ: [f32; 9] = [
+        0.7328f32, 0.4296f32, -0.1624f32,
+        -0.7036f32, 1.6975f32, 0.0061f32,
+        0.0030f32, 0.0136f32, 0.9834f32,
+    ];
+}
+
+impl ConeFundamentals for Cat16 {
+    const FROM_CIEXYZ: [f32; 9] = [
+        0.401288f32, 0.650173f32, -0.051461f32,
+        -0.250268f32, 1.204414f32, 0.045854f32,
+        -0.002079f32, 0.048952f32, 0.953127f32,
+    ];
+}
+
+impl ConeFundamentals for StockmanSharpe {
+    const FROM_CIEXYZ: [f32; 9] = [
+        0.210576f32, 0.855098f32, -0.0396983f32,
+        -0.417076f32, 1.17726f32, 0.0786283f32,
+        0f32, 0f32, 0.516835f32,
+    ];
+}
+
+impl<M: ConeFundamentals> Lms<M> {
+    /// Creates a Lms instance from 3 float
+    /// representing the long, medium and short cone responses.
+    ///
+    /// Negative values are allowed as "sharpened" cone responses such as Bradford's
+    /// can be negative for real colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Lms, lms::Bradford };
+    /// let lms = Lms::<Bradford>::new(0.1, 0.2, 0.3);
+    /// ```
+    pub fn new(long: f32, medium: f32, short: f32) -> Self {
+        Self(long, medium, short, PhantomData)
+    }
+
+    /// Long wavelength cone response of an LMS color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Lms, lms::Bradford };
+    /// let lms = Lms::<Bradford>::new(0.1, 0.2, 0.3);
+    /// assert_eq!(lms.long(), 0.1);
+    /// ```
+    pub fn long(&self) -> f32 {
+        self.0
+    }
+
+    /// Medium wavelength cone response of an LMS color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Lms, lms::Bradford };
+    /// let lms = Lms::<Bradford>::new(0.1, 0.2, 0.3);
+    /// assert_eq!(lms.medium(), 0.2);
+    /// ```
+    pub fn medium(&self) -> f32 {
+        self.1
+    }
+
+    /// Short wavelength cone response of an LMS color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ Lms, lms::Bradford };
+    /// let lms = Lms::<Bradford>::new(0.1, 0.2, 0.3);
+    /// assert_eq!(lms.short(), 0.3);
+    /// ```
+    pub fn short(&self) -> f32 {
+        self.2
+    }
+}
@@ -0,0 +1,134 @@
+use std::marker::PhantomData;
+use crate::{
+    illuminant::Illuminant,
+    matrix,
+    oklab,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Oklab,
+    Oklch,
+    Srgb,
+};
+use super::{
+    ConeFundamentals,
+    Lms,
+};
+
+/// Conversion from CIEXYZ to LMS.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Ciexyz, Lms, lms::Bradford };
+/// let lms: Lms<Bradford> = Ciexyz::new(0.1, 0.2, 0.3).unwrap().into();
+/// ```
+impl<M: ConeFundamentals> From<Ciexyz> for Lms<M> {
+    fn from(ciexyz: Ciexyz) -> Self {
+        let [l, m, s] = matrix::transform(M::FROM_CIEXYZ, ciexyz.into());
+        Self(l, m, s, PhantomData)
+    }
+}
+
+/// Conversion from sRGB to LMS.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Lms, lms::Bradford, Srgb };
+/// let lms: Lms<Bradford> = Srgb::new(10, 20, 30).into();
+/// ```
+impl<M: ConeFundamentals> From<Srgb> for Lms<M> {
+    fn from(srgb: Srgb) -> Self {
+        let ciexyz: Ciexyz = srgb.into();
+        Self::from(ciexyz)
+    }
+}
+
+/// Conversion from CIELAB to LMS.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielab, Lms, lms::Bradford };
+/// let lms: Lms<Bradford> = Cielab::new(10.0, 20.0, 30.0).unwrap().into();
+/// ```
+impl<M: ConeFundamentals, W: Illuminant> From<Cielab<W>> for Lms<M> {
+    fn from(cielab: Cielab<W>) -> Self {
+        let ciexyz: Ciexyz = cielab.into();
+        Self::from(ciexyz)
+    }
+}
+
+/// Conversion from CIELCh to LMS.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielch, Lms, lms::Bradford };
+/// let lms: Lms<Bradford> = Cielch::new(10.0, 20.0, 30f32.to_radians()).unwrap().into();
+/// ```
+impl<M: ConeFundamentals, W: Illuminant> From<Cielch<W>> for Lms<M> {
+    fn from(cielch: Cielch<W>) -> Self {
+        let ciexyz: Ciexyz = cielch.into();
+        Self::from(ciexyz)
+    }
+}
+
+/// Conversion from Oklab to LMS.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Lms, lms::Bradford, Oklab };
+/// let lms: Lms<Bradford> = Oklab::new(0.5, 0.1, -0.1).unwrap().into();
+/// ```
+impl<M: ConeFundamentals> From<Oklab> for Lms<M> {
+    fn from(oklab: Oklab) -> Self {
+        matrix::transform(M::FROM_CIEXYZ, oklab::to_ciexyz(oklab)).into()
+    }
+}
+
+/// Conversion from Oklch to LMS.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Lms, lms::Bradford, Oklch };
+/// let lms: Lms<Bradford> = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap().into();
+/// ```
+impl<M: ConeFundamentals> From<Oklch> for Lms<M> {
+    fn from(oklch: Oklch) -> Self {
+        Self::from(Oklab::from(oklch))
+    }
+}
+
+/// New LMS color from an array of 3 floats.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Lms, lms::Bradford };
+/// let lms: Lms<Bradford> = [0.1, 0.2, 0.3].into();
+/// ```
+impl<M: ConeFundamentals> From<[f32; 3]> for Lms<M> {
+    fn from(array: [f32; 3]) -> Self {
+        let [l, m, s] = array;
+        Self(l, m, s, PhantomData)
+    }
+}
+
+/// Convert an LMS color into an array of 3 floats.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Lms, lms::Bradford };
+/// let [l, m, s]: [f32; 3] = Lms::<Bradford>::new(0.1, 0.2, 0.3).into();
+/// ```
+impl<M> From<Lms<M>> for [f32; 3] {
+    fn from(lms: Lms<M>) -> Self {
+        let Lms(l, m, s, _) = lms;
+        [l, m, s]
+    }
+}
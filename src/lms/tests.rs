@@ -0,0 +1,69 @@
+use super::*;
+use crate::{
+    ciexyz::D65,
+    Ciexyz,
+    Srgb,
+};
+
+fn round_trip<M: ConeFundamentals>() {
+    for [x, y, z] in [[0.0, 0.0, 0.0], [0.1, 0.2, 0.3], [0.9, 0.5, 0.2], D65.into()] {
+        let ciexyz = Ciexyz::new(x, y, z).unwrap();
+        let lms: Lms<M> = ciexyz.into();
+        let [rx, ry, rz]: [f32; 3] = Ciexyz::try_from(lms).unwrap().into();
+        assert!((rx - x).abs() < 1e-5);
+        assert!((ry - y).abs() < 1e-5);
+        assert!((rz - z).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn new() {
+    let a = Lms::<Bradford>(0.1, 0.2, 0.3, PhantomData);
+    let b = Lms::<Bradford>::new(0.1, 0.2, 0.3);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn long() {
+    let a = Lms::<Cat02>::new(0.1, 0.2, 0.3);
+    assert_eq!(a.long(), 0.1);
+}
+
+#[test]
+fn medium() {
+    let a = Lms::<Cat02>::new(0.1, 0.2, 0.3);
+    assert_eq!(a.medium(), 0.2);
+}
+
+#[test]
+fn short() {
+    let a = Lms::<Cat02>::new(0.1, 0.2, 0.3);
+    assert_eq!(a.short(), 0.3);
+}
+
+#[test]
+fn from_ciexyz() {
+    let lms: Lms<Bradford> = Ciexyz::new(1.0, 0.0, 0.0).unwrap().into();
+    assert_eq!(lms, Lms::new(0.8951, -0.7502, 0.0389));
+    let lms: Lms<HuntPointerEstevez> = Ciexyz::new(1.0, 1.0, 1.0).unwrap().into();
+    let [l, m, s]: [f32; 3] = lms.into();
+    assert!((l - 1.0).abs() < 1e-4);
+    assert!((m - 1.0).abs() < 1e-4);
+    assert!((s - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn ciexyz_round_trip() {
+    round_trip::<HuntPointerEstevez>();
+    round_trip::<Bradford>();
+    round_trip::<Cat02>();
+    round_trip::<Cat16>();
+    round_trip::<StockmanSharpe>();
+}
+
+#[test]
+fn srgb_round_trip() {
+    let srgb = Srgb::new(10, 200, 30);
+    let lms: Lms<Cat16> = srgb.into();
+    assert_eq!(srgb, lms.try_into().unwrap());
+}
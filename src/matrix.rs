@@ -0,0 +1,148 @@
+//! Helpers for 3x3 matrices, stored the same way as the conversion matrices of the crate:
+//! an array of 9 floats in row-major order.
+
+#[cfg(test)]
+mod tests;
+
+/// Product of a 3x3 matrix and a column vector.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::matrix::transform;
+/// let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+/// assert_eq!(transform(identity, [0.1, 0.2, 0.3]), [0.1, 0.2, 0.3]);
+/// ```
+pub const fn transform(matrix: [f32; 9], vector: [f32; 3]) -> [f32; 3] {
+    let [m11, m12, m13, m21, m22, m23, m31, m32, m33] = matrix;
+    let [x, y, z] = vector;
+    [
+        m11 * x + m12 * y + m13 * z,
+        m21 * x + m22 * y + m23 * z,
+        m31 * x + m32 * y + m33 * z,
+    ]
+}
+
+/// Product of two 3x3 matrices.
+/// Transforming a vector by the product is the same as transforming it by `b` then by `a`.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::matrix::multiply;
+/// let scaling = [2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0];
+/// let swap = [0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+/// assert_eq!(multiply(scaling, swap), [0.0, 2.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0]);
+/// ```
+pub const fn multiply(a: [f32; 9], b: [f32; 9]) -> [f32; 9] {
+    let [a11, a12, a13, a21, a22, a23, a31, a32, a33] = a;
+    let [b11, b12, b13, b21, b22, b23, b31, b32, b33] = b;
+    [
+        a11 * b11 + a12 * b21 + a13 * b31,
+        a11 * b12 + a12 * b22 + a13 * b32,
+        a11 * b13 + a12 * b23 + a13 * b33,
+        a21 * b11 + a22 * b21 + a23 * b31,
+        a21 * b12 + a22 * b22 + a23 * b32,
+        a21 * b13 + a22 * b23 + a23 * b33,
+        a31 * b11 + a32 * b21 + a33 * b31,
+        a31 * b12 + a32 * b22 + a33 * b32,
+        a31 * b13 + a32 * b23 + a33 * b33,
+    ]
+}
+
+/// Inverse of a 3x3 matrix.
+/// The return value is None if the matrix isn't invertible.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::matrix::inverse;
+/// let scaling = [2.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.5];
+/// assert_eq!(inverse(scaling), Some([0.5, 0.0, 0.0, 0.0, 0.25, 0.0, 0.0, 0.0, 2.0]));
+/// assert_eq!(inverse([0.0; 9]), None);
+/// ```
+pub fn inverse(matrix: [f32; 9]) -> Option<[f32; 9]> {
+    let inverse = inverse_unchecked(matrix);
+    match inverse.iter().all(|x| x.is_finite()) {
+        true    => Some(inverse),
+        false   => None,
+    }
+}
+
+//Inverse of a 3x3 matrix usable in constant expressions.
+//The matrix must be invertible otherwise the result is made of non finite values.
+pub(crate) const fn inverse_unchecked(matrix: [f32; 9]) -> [f32; 9] {
+    let [m11, m12, m13, m21, m22, m23, m31, m32, m33] = matrix;
+    let c11 = m22 * m33 - m23 * m32;
+    let c12 = m23 * m31 - m21 * m33;
+    let c13 = m21 * m32 - m22 * m31;
+    let determinant = m11 * c11 + m12 * c12 + m13 * c13;
+    [
+        c11 / determinant,
+        (m13 * m32 - m12 * m33) / determinant,
+        (m12 * m23 - m13 * m22) / determinant,
+        c12 / determinant,
+        (m11 * m33 - m13 * m31) / determinant,
+        (m13 * m21 - m11 * m23) / determinant,
+        c13 / determinant,
+        (m12 * m31 - m11 * m32) / determinant,
+        (m11 * m22 - m12 * m21) / determinant,
+    ]
+}
+
+/// Computes the matrix converting linear RGB values to CIEXYZ for an RGB color space defined
+/// by the xy chromaticities of its red, green and blue primaries and of its white point.
+///
+/// The white point is normalized so that it has a Y of 1.
+/// The return value is None if the chromaticities don't define a valid RGB color space.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::matrix::{ rgb_to_ciexyz, transform };
+/// //sRGB primaries and D65 white point.
+/// let matrix = rgb_to_ciexyz([0.64, 0.33], [0.30, 0.60], [0.15, 0.06], [0.3127, 0.3290]).unwrap();
+/// let [x, y, z] = transform(matrix, [1.0, 1.0, 1.0]);
+/// assert!((y - 1.0).abs() < 1e-6);
+/// ```
+pub fn rgb_to_ciexyz(red: [f32; 2], green: [f32; 2], blue: [f32; 2], white: [f32; 2]) -> Option<[f32; 9]> {
+    let [xr, yr, zr] = chromaticity_to_ciexyz(red)?;
+    let [xg, yg, zg] = chromaticity_to_ciexyz(green)?;
+    let [xb, yb, zb] = chromaticity_to_ciexyz(blue)?;
+    let white = chromaticity_to_ciexyz(white)?;
+    let primaries = [xr, xg, xb, yr, yg, yb, zr, zg, zb];
+    let [sr, sg, sb] = transform(inverse(primaries)?, white);
+    Some([
+        xr * sr, xg * sg, xb * sb,
+        yr * sr, yg * sg, yb * sb,
+        zr * sr, zg * sg, zb * sb,
+    ])
+}
+
+/// Computes the matrix converting CIEXYZ values to linear RGB for an RGB color space defined
+/// by the xy chromaticities of its red, green and blue primaries and of its white point.
+///
+/// This is the inverse of the matrix given by `rgb_to_ciexyz`.
+/// The return value is None if the chromaticities don't define a valid RGB color space.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::matrix::{ ciexyz_to_rgb, transform };
+/// //sRGB primaries and D65 white point.
+/// let matrix = ciexyz_to_rgb([0.64, 0.33], [0.30, 0.60], [0.15, 0.06], [0.3127, 0.3290]).unwrap();
+/// let [r, g, b] = transform(matrix, [0.9505, 1.0, 1.089]);
+/// assert!((r - 1.0).abs() < 1e-3);
+/// ```
+pub fn ciexyz_to_rgb(red: [f32; 2], green: [f32; 2], blue: [f32; 2], white: [f32; 2]) -> Option<[f32; 9]> {
+    inverse(rgb_to_ciexyz(red, green, blue, white)?)
+}
+
+//CIEXYZ values with a Y of 1 from xy chromaticities.
+fn chromaticity_to_ciexyz(chromaticity: [f32; 2]) -> Option<[f32; 3]> {
+    let [x, y] = chromaticity;
+    match y > 0f32 && x >= 0f32 && x + y <= 1f32 {
+        true    => Some([x / y, 1f32, (1f32 - x - y) / y]),
+        false   => None,
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+const SRGB_PRIMARIES: [[f32; 2]; 3] = [[0.64, 0.33], [0.30, 0.60], [0.15, 0.06]];
+const D65_CHROMATICITY: [f32; 2] = [0.3127, 0.3290];
+
+#[test]
+fn inverse_of_inverse() {
+    let matrix = [1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0];
+    let twice = inverse(inverse(matrix).unwrap()).unwrap();
+    assert!((0..9).all(|i| (twice[i] - matrix[i]).abs() < 1e-4));
+}
+
+#[test]
+fn multiply_by_inverse() {
+    let matrix = [1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0];
+    let identity = multiply(matrix, inverse(matrix).unwrap());
+    assert!((0..9).all(|i| (identity[i] - [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0][i]).abs() < 1e-5));
+}
+
+#[test]
+fn multiply_then_transform() {
+    let a = [1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0];
+    let b = [0.5, 0.0, 1.0, 2.0, 1.0, 0.0, 0.0, 3.0, 1.0];
+    let vector = [0.1, 0.2, 0.3];
+    assert_eq!(transform(multiply(a, b), vector), transform(a, transform(b, vector)));
+}
+
+#[test]
+fn singular() {
+    assert!(inverse([1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 0.0]).is_none());
+}
+
+#[test]
+fn white_point() {
+    let [red, green, blue] = SRGB_PRIMARIES;
+    let matrix = rgb_to_ciexyz(red, green, blue, D65_CHROMATICITY).unwrap();
+    let [x, y, z] = transform(matrix, [1.0, 1.0, 1.0]);
+    assert!((x / (x + y + z) - D65_CHROMATICITY[0]).abs() < 1e-6);
+    assert!((y / (x + y + z) - D65_CHROMATICITY[1]).abs() < 1e-6);
+    assert!((y - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn round_trip() {
+    let [red, green, blue] = SRGB_PRIMARIES;
+    let to_ciexyz = rgb_to_ciexyz(red, green, blue, D65_CHROMATICITY).unwrap();
+    let to_rgb = ciexyz_to_rgb(red, green, blue, D65_CHROMATICITY).unwrap();
+    let rgb = transform(to_rgb, transform(to_ciexyz, [0.2, 0.5, 0.7]));
+    assert!((0..3).all(|i| (rgb[i] - [0.2, 0.5, 0.7][i]).abs() < 1e-5));
+}
+
+#[test]
+fn invalid_chromaticities() {
+    let [red, green, _] = SRGB_PRIMARIES;
+    assert!(rgb_to_ciexyz(red, green, [0.15, 0.0], D65_CHROMATICITY).is_none());
+    assert!(rgb_to_ciexyz(red, green, [0.6, 0.6], D65_CHROMATICITY).is_none());
+    assert!(rgb_to_ciexyz(red, green, green, D65_CHROMATICITY).is_none());
+}
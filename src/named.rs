@@ -0,0 +1,534 @@
+//! Named colors of CSS and SVG, with lookups by name and of the perceptually nearest name of any
+//! color.
+//!
+//! # Examples
+//!
+//! ```
+//! use cowor::{ named, Srgb };
+//! assert_eq!(named::from_name("RebeccaPurple"), Some(named::REBECCAPURPLE));
+//! assert_eq!(named::to_name(Srgb::new(255, 165, 0)), Some("orange"));
+//! assert_eq!(named::nearest(Srgb::new(250, 130, 110)).0, "salmon");
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    difference::{
+        Ciede2000,
+        ColorDifference,
+        Metric,
+    },
+    Srgb,
+};
+
+/// `aliceblue` (#f0f8ff).
+pub const ALICEBLUE: Srgb = Srgb::new(240, 248, 255);
+/// `antiquewhite` (#faebd7).
+pub const ANTIQUEWHITE: Srgb = Srgb::new(250, 235, 215);
+/// `aqua` (#00ffff).
+pub const AQUA: Srgb = Srgb::new(0, 255, 255);
+/// `aquamarine` (#7fffd4).
+pub const AQUAMARINE: Srgb = Srgb::new(127, 255, 212);
+/// `azure` (#f0ffff).
+pub const AZURE: Srgb = Srgb::new(240, 255, 255);
+/// `beige` (#f5f5dc).
+pub const BEIGE: Srgb = Srgb::new(245, 245, 220);
+/// `bisque` (#ffe4c4).
+pub const BISQUE: Srgb = Srgb::new(255, 228, 196);
+/// `black` (#000000).
+pub const BLACK: Srgb = Srgb::new(0, 0, 0);
+/// `blanchedalmond` (#ffebcd).
+pub const BLANCHEDALMOND: Srgb = Srgb::new(255, 235, 205);
+/// `blue` (#0000ff).
+pub const BLUE: Srgb = Srgb::new(0, 0, 255);
+/// `blueviolet` (#8a2be2).
+pub const BLUEVIOLET: Srgb = Srgb::new(138, 43, 226);
+/// `brown` (#a52a2a).
+pub const BROWN: Srgb = Srgb::new(165, 42, 42);
+/// `burlywood` (#deb887).
+pub const BURLYWOOD: Srgb = Srgb::new(222, 184, 135);
+/// `cadetblue` (#5f9ea0).
+pub const CADETBLUE: Srgb = Srgb::new(95, 158, 160);
+/// `chartreuse` (#7fff00).
+pub const CHARTREUSE: Srgb = Srgb::new(127, 255, 0);
+/// `chocolate` (#d2691e).
+pub const CHOCOLATE: Srgb = Srgb::new(210, 105, 30);
+/// `coral` (#ff7f50).
+pub const CORAL: Srgb = Srgb::new(255, 127, 80);
+/// `cornflowerblue` (#6495ed).
+pub const CORNFLOWERBLUE: Srgb = Srgb::new(100, 149, 237);
+/// `cornsilk` (#fff8dc).
+pub const CORNSILK: Srgb = Srgb::new(255, 248, 220);
+/// `crimson` (#dc143c).
+pub const CRIMSON: Srgb = Srgb::new(220, 20, 60);
+/// `cyan` (#00ffff).
+pub const CYAN: Srgb = Srgb::new(0, 255, 255);
+/// `darkblue` (#00008b).
+pub const DARKBLUE: Srgb = Srgb::new(0, 0, 139);
+/// `darkcyan` (#008b8b).
+pub const DARKCYAN: Srgb = Srgb::new(0, 139, 139);
+/// `darkgoldenrod` (#b8860b).
+pub const DARKGOLDENROD: Srgb = Srgb::new(184, 134, 11);
+/// `darkgray` (#a9a9a9).
+pub const DARKGRAY: Srgb = Srgb::new(169, 169, 169);
+/// `darkgreen` (#006400).
+pub const DARKGREEN: Srgb = Srgb::new(0, 100, 0);
+/// `darkgrey` (#a9a9a9).
+pub const DARKGREY: Srgb = Srgb::new(169, 169, 169);
+/// `darkkhaki` (#bdb76b).
+pub const DARKKHAKI: Srgb = Srgb::new(189, 183, 107);
+/// `darkmagenta` (#8b008b).
+pub const DARKMAGENTA: Srgb = Srgb::new(139, 0, 139);
+/// `darkolivegreen` (#556b2f).
+pub const DARKOLIVEGREEN: Srgb = Srgb::new(85, 107, 47);
+/// `darkorange` (#ff8c00).
+pub const DARKORANGE: Srgb = Srgb::new(255, 140, 0);
+/// `darkorchid` (#9932cc).
+pub const DARKORCHID: Srgb = Srgb::new(153, 50, 204);
+/// `darkred` (#8b0000).
+pub const DARKRED: Srgb = Srgb::new(139, 0, 0);
+/// `darksalmon` (#e9967a).
+pub const DARKSALMON: Srgb = Srgb::new(233, 150, 122);
+/// `darkseagreen` (#8fbc8f).
+pub const DARKSEAGREEN: Srgb = Srgb::new(143, 188, 143);
+/// `darkslateblue` (#483d8b).
+pub const DARKSLATEBLUE: Srgb = Srgb::new(72, 61, 139);
+/// `darkslategray` (#2f4f4f).
+pub const DARKSLATEGRAY: Srgb = Srgb::new(47, 79, 79);
+/// `darkslategrey` (#2f4f4f).
+pub const DARKSLATEGREY: Srgb = Srgb::new(47, 79, 79);
+/// `darkturquoise` (#00ced1).
+pub const DARKTURQUOISE: Srgb = Srgb::new(0, 206, 209);
+/// `darkviolet` (#9400d3).
+pub const DARKVIOLET: Srgb = Srgb::new(148, 0, 211);
+/// `deeppink` (#ff1493).
+pub const DEEPPINK: Srgb = Srgb::new(255, 20, 147);
+/// `deepskyblue` (#00bfff).
+pub const DEEPSKYBLUE: Srgb = Srgb::new(0, 191, 255);
+/// `dimgray` (#696969).
+pub const DIMGRAY: Srgb = Srgb::new(105, 105, 105);
+/// `dimgrey` (#696969).
+pub const DIMGREY: Srgb = Srgb::new(105, 105, 105);
+/// `dodgerblue` (#1e90ff).
+pub const DODGERBLUE: Srgb = Srgb::new(30, 144, 255);
+/// `firebrick` (#b22222).
+pub const FIREBRICK: Srgb = Srgb::new(178, 34, 34);
+/// `floralwhite` (#fffaf0).
+pub const FLORALWHITE: Srgb = Srgb::new(255, 250, 240);
+/// `forestgreen` (#228b22).
+pub const FORESTGREEN: Srgb = Srgb::new(34, 139, 34);
+/// `fuchsia` (#ff00ff).
+pub const FUCHSIA: Srgb = Srgb::new(255, 0, 255);
+/// `gainsboro` (#dcdcdc).
+pub const GAINSBORO: Srgb = Srgb::new(220, 220, 220);
+/// `ghostwhite` (#f8f8ff).
+pub const GHOSTWHITE: Srgb = Srgb::new(248, 248, 255);
+/// `gold` (#ffd700).
+pub const GOLD: Srgb = Srgb::new(255, 215, 0);
+/// `goldenrod` (#daa520).
+pub const GOLDENROD: Srgb = Srgb::new(218, 165, 32);
+/// `gray` (#808080).
+pub const GRAY: Srgb = Srgb::new(128, 128, 128);
+/// `green` (#008000).
+pub const GREEN: Srgb = Srgb::new(0, 128, 0);
+/// `greenyellow` (#adff2f).
+pub const GREENYELLOW: Srgb = Srgb::new(173, 255, 47);
+/// `grey` (#808080).
+pub const GREY: Srgb = Srgb::new(128, 128, 128);
+/// `honeydew` (#f0fff0).
+pub const HONEYDEW: Srgb = Srgb::new(240, 255, 240);
+/// `hotpink` (#ff69b4).
+pub const HOTPINK: Srgb = Srgb::new(255, 105, 180);
+/// `indianred` (#cd5c5c).
+pub const INDIANRED: Srgb = Srgb::new(205, 92, 92);
+/// `indigo` (#4b0082).
+pub const INDIGO: Srgb = Srgb::new(75, 0, 130);
+/// `ivory` (#fffff0).
+pub const IVORY: Srgb = Srgb::new(255, 255, 240);
+/// `khaki` (#f0e68c).
+pub const KHAKI: Srgb = Srgb::new(240, 230, 140);
+/// `lavender` (#e6e6fa).
+pub const LAVENDER: Srgb = Srgb::new(230, 230, 250);
+/// `lavenderblush` (#fff0f5).
+pub const LAVENDERBLUSH: Srgb = Srgb::new(255, 240, 245);
+/// `lawngreen` (#7cfc00).
+pub const LAWNGREEN: Srgb = Srgb::new(124, 252, 0);
+/// `lemonchiffon` (#fffacd).
+pub const LEMONCHIFFON: Srgb = Srgb::new(255, 250, 205);
+/// `lightblue` (#add8e6).
+pub const LIGHTBLUE: Srgb = Srgb::new(173, 216, 230);
+/// `lightcoral` (#f08080).
+pub const LIGHTCORAL: Srgb = Srgb::new(240, 128, 128);
+/// `lightcyan` (#e0ffff).
+pub const LIGHTCYAN: Srgb = Srgb::new(224, 255, 255);
+/// `lightgoldenrodyellow` (#fafad2).
+pub const LIGHTGOLDENRODYELLOW: Srgb = Srgb::new(250, 250, 210);
+/// `lightgray` (#d3d3d3).
+pub const LIGHTGRAY: Srgb = Srgb::new(211, 211, 211);
+/// `lightgreen` (#90ee90).
+pub const LIGHTGREEN: Srgb = Srgb::new(144, 238, 144);
+/// `lightgrey` (#d3d3d3).
+pub const LIGHTGREY: Srgb = Srgb::new(211, 211, 211);
+/// `lightpink` (#ffb6c1).
+pub const LIGHTPINK: Srgb = Srgb::new(255, 182, 193);
+/// `lightsalmon` (#ffa07a).
+pub const LIGHTSALMON: Srgb = Srgb::new(255, 160, 122);
+/// `lightseagreen` (#20b2aa).
+pub const LIGHTSEAGREEN: Srgb = Srgb::new(32, 178, 170);
+/// `lightskyblue` (#87cefa).
+pub const LIGHTSKYBLUE: Srgb = Srgb::new(135, 206, 250);
+/// `lightslategray` (#778899).
+pub const LIGHTSLATEGRAY: Srgb = Srgb::new(119, 136, 153);
+/// `lightslategrey` (#778899).
+pub const LIGHTSLATEGREY: Srgb = Srgb::new(119, 136, 153);
+/// `lightsteelblue` (#b0c4de).
+pub const LIGHTSTEELBLUE: Srgb = Srgb::new(176, 196, 222);
+/// `lightyellow` (#ffffe0).
+pub const LIGHTYELLOW: Srgb = Srgb::new(255, 255, 224);
+/// `lime` (#00ff00).
+pub const LIME: Srgb = Srgb::new(0, 255, 0);
+/// `limegreen` (#32cd32).
+pub const LIMEGREEN: Srgb = Srgb::new(50, 205, 50);
+/// `linen` (#faf0e6).
+pub const LINEN: Srgb = Srgb::new(250, 240, 230);
+/// `magenta` (#ff00ff).
+pub const MAGENTA: Srgb = Srgb::new(255, 0, 255);
+/// `maroon` (#800000).
+pub const MAROON: Srgb = Srgb::new(128, 0, 0);
+/// `mediumaquamarine` (#66cdaa).
+pub const MEDIUMAQUAMARINE: Srgb = Srgb::new(102, 205, 170);
+/// `mediumblue` (#0000cd).
+pub const MEDIUMBLUE: Srgb = Srgb::new(0, 0, 205);
+/// `mediumorchid` (#ba55d3).
+pub const MEDIUMORCHID: Srgb = Srgb::new(186, 85, 211);
+/// `mediumpurple` (#9370db).
+pub const MEDIUMPURPLE: Srgb = Srgb::new(147, 112, 219);
+/// `mediumseagreen` (#3cb371).
+pub const MEDIUMSEAGREEN: Srgb = Srgb::new(60, 179, 113);
+/// `mediumslateblue` (#7b68ee).
+pub const MEDIUMSLATEBLUE: Srgb = Srgb::new(123, 104, 238);
+/// `mediumspringgreen` (#00fa9a).
+pub const MEDIUMSPRINGGREEN: Srgb = Srgb::new(0, 250, 154);
+/// `mediumturquoise` (#48d1cc).
+pub const MEDIUMTURQUOISE: Srgb = Srgb::new(72, 209, 204);
+/// `mediumvioletred` (#c71585).
+pub const MEDIUMVIOLETRED: Srgb = Srgb::new(199, 21, 133);
+/// `midnightblue` (#191970).
+pub const MIDNIGHTBLUE: Srgb = Srgb::new(25, 25, 112);
+/// `mintcream` (#f5fffa).
+pub const MINTCREAM: Srgb = Srgb::new(245, 255, 250);
+/// `mistyrose` (#ffe4e1).
+pub const MISTYROSE: Srgb = Srgb::new(255, 228, 225);
+/// `moccasin` (#ffe4b5).
+pub const MOCCASIN: Srgb = Srgb::new(255, 228, 181);
+/// `navajowhite` (#ffdead).
+pub const NAVAJOWHITE: Srgb = Srgb::new(255, 222, 173);
+/// `navy` (#000080).
+pub const NAVY: Srgb = Srgb::new(0, 0, 128);
+/// `oldlace` (#fdf5e6).
+pub const OLDLACE: Srgb = Srgb::new(253, 245, 230);
+/// `olive` (#808000).
+pub const OLIVE: Srgb = Srgb::new(128, 128, 0);
+/// `olivedrab` (#6b8e23).
+pub const OLIVEDRAB: Srgb = Srgb::new(107, 142, 35);
+/// `orange` (#ffa500).
+pub const ORANGE: Srgb = Srgb::new(255, 165, 0);
+/// `orangered` (#ff4500).
+pub const ORANGERED: Srgb = Srgb::new(255, 69, 0);
+/// `orchid` (#da70d6).
+pub const ORCHID: Srgb = Srgb::new(218, 112, 214);
+/// `palegoldenrod` (#eee8aa).
+pub const PALEGOLDENROD: Srgb = Srgb::new(238, 232, 170);
+/// `palegreen` (#98fb98).
+pub const PALEGREEN: Srgb = Srgb::new(152, 251, 152);
+/// `paleturquoise` (#afeeee).
+pub const PALETURQUOISE: Srgb = Srgb::new(175, 238, 238);
+/// `palevioletred` (#db7093).
+pub const PALEVIOLETRED: Srgb = Srgb::new(219, 112, 147);
+/// `papayawhip` (#ffefd5).
+pub const PAPAYAWHIP: Srgb = Srgb::new(255, 239, 213);
+/// `peachpuff` (#ffdab9).
+pub const PEACHPUFF: Srgb = Srgb::new(255, 218, 185);
+/// `peru` (#cd853f).
+pub const PERU: Srgb = Srgb::new(205, 133, 63);
+/// `pink` (#ffc0cb).
+pub const PINK: Srgb = Srgb::new(255, 192, 203);
+/// `plum` (#dda0dd).
+pub const PLUM: Srgb = Srgb::new(221, 160, 221);
+/// `powderblue` (#b0e0e6).
+pub const POWDERBLUE: Srgb = Srgb::new(176, 224, 230);
+/// `purple` (#800080).
+pub const PURPLE: Srgb = Srgb::new(128, 0, 128);
+/// `rebeccapurple` (#663399).
+pub const REBECCAPURPLE: Srgb = Srgb::new(102, 51, 153);
+/// `red` (#ff0000).
+pub const RED: Srgb = Srgb::new(255, 0, 0);
+/// `rosybrown` (#bc8f8f).
+pub const ROSYBROWN: Srgb = Srgb::new(188, 143, 143);
+/// `royalblue` (#4169e1).
+pub const ROYALBLUE: Srgb = Srgb::new(65, 105, 225);
+/// `saddlebrown` (#8b4513).
+pub const SADDLEBROWN: Srgb = Srgb::new(139, 69, 19);
+/// `salmon` (#fa8072).
+pub const SALMON: Srgb = Srgb::new(250, 128, 114);
+/// `sandybrown` (#f4a460).
+pub const SANDYBROWN: Srgb = Srgb::new(244, 164, 96);
+/// `seagreen` (#2e8b57).
+pub const SEAGREEN: Srgb = Srgb::new(46, 139, 87);
+/// `seashell` (#fff5ee).
+pub const SEASHELL: Srgb = Srgb::new(255, 245, 238);
+/// `sienna` (#a0522d).
+pub const SIENNA: Srgb = Srgb::new(160, 82, 45);
+/// `silver` (#c0c0c0).
+pub const SILVER: Srgb = Srgb::new(192, 192, 192);
+/// `skyblue` (#87ceeb).
+pub const SKYBLUE: Srgb = Srgb::new(135, 206, 235);
+/// `slateblue` (#6a5acd).
+pub const SLATEBLUE: Srgb = Srgb::new(106, 90, 205);
+/// `slategray` (#708090).
+pub const SLATEGRAY: Srgb = Srgb::new(112, 128, 144);
+/// `slategrey` (#708090).
+pub const SLATEGREY: Srgb = Srgb::new(112, 128, 144);
+/// `snow` (#fffafa).
+pub const SNOW: Srgb = Srgb::new(255, 250, 250);
+/// `springgreen` (#00ff7f).
+pub const SPRINGGREEN: Srgb = Srgb::new(0, 255, 127);
+/// `steelblue` (#4682b4).
+pub const STEELBLUE: Srgb = Srgb::new(70, 130, 180);
+/// `tan` (#d2b48c).
+pub const TAN: Srgb = Srgb::new(210, 180, 140);
+/// `teal` (#008080).
+pub const TEAL: Srgb = Srgb::new(0, 128, 128);
+/// `thistle` (#d8bfd8).
+pub const THISTLE: Srgb = Srgb::new(216, 191, 216);
+/// `tomato` (#ff6347).
+pub const TOMATO: Srgb = Srgb::new(255, 99, 71);
+/// `turquoise` (#40e0d0).
+pub const TURQUOISE: Srgb = Srgb::new(64, 224, 208);
+/// `violet` (#ee82ee).
+pub const VIOLET: Srgb = Srgb::new(238, 130, 238);
+/// `wheat` (#f5deb3).
+pub const WHEAT: Srgb = Srgb::new(245, 222, 179);
+/// `white` (#ffffff).
+pub const WHITE: Srgb = Srgb::new(255, 255, 255);
+/// `whitesmoke` (#f5f5f5).
+pub const WHITESMOKE: Srgb = Srgb::new(245, 245, 245);
+/// `yellow` (#ffff00).
+pub const YELLOW: Srgb = Srgb::new(255, 255, 0);
+/// `yellowgreen` (#9acd32).
+pub const YELLOWGREEN: Srgb = Srgb::new(154, 205, 50);
+
+/// All the named colors of CSS with their names, in alphabetical order.
+///
+/// Some colors have several names: `aqua` and `cyan`, `fuchsia` and `magenta`, and the grays
+/// which can be spelled with `gray` or `grey`.
+pub const ALL: [(&str, Srgb); 148] = [
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADETBLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkorange", DARKORANGE),
+    ("darkorchid", DARKORCHID),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOTPINK),
+    ("indianred", INDIANRED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lightblue", LIGHTBLUE),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightyellow", LIGHTYELLOW),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navy", NAVY),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGERED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("paleturquoise", PALETURQUOISE),
+    ("palevioletred", PALEVIOLETRED),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("rosybrown", ROSYBROWN),
+    ("royalblue", ROYALBLUE),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("slateblue", SLATEBLUE),
+    ("slategray", SLATEGRAY),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("springgreen", SPRINGGREEN),
+    ("steelblue", STEELBLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+/// Named color of a given name, ignoring ASCII case.
+/// The return value is None if no color has that name.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ named, Srgb };
+/// assert_eq!(named::from_name("steelblue"), Some(Srgb::new(70, 130, 180)));
+/// assert_eq!(named::from_name("DarkGrey"), named::from_name("darkgray"));
+/// assert_eq!(named::from_name("blurple"), None);
+/// ```
+pub fn from_name(name: &str) -> Option<Srgb> {
+    ALL.iter().find(|(x, _)| x.eq_ignore_ascii_case(name)).map(|(_, color)| *color)
+}
+
+/// Name of the named color equal to a given color.
+/// Of several names of the same color, the first in alphabetical order is returned.
+/// The return value is None if the color has no name.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ named, Srgb };
+/// assert_eq!(named::to_name(Srgb::new(0, 255, 255)), Some("aqua"));
+/// assert_eq!(named::to_name(Srgb::new(128, 128, 128)), Some("gray"));
+/// assert_eq!(named::to_name(Srgb::new(10, 20, 30)), None);
+/// ```
+pub fn to_name(srgb: Srgb) -> Option<&'static str> {
+    ALL.iter().find(|(_, color)| *color == srgb).map(|(name, _)| *name)
+}
+
+/// Named color perceptually nearest to a given color, with its name.
+/// Colors are compared with the CIEDE2000 color difference in CIELAB.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ named, Srgb };
+/// assert_eq!(named::nearest(Srgb::new(10, 20, 30)), ("black", named::BLACK));
+/// assert_eq!(named::nearest(Srgb::new(255, 165, 0)), ("orange", named::ORANGE));
+/// ```
+pub fn nearest(srgb: Srgb) -> (&'static str, Srgb) {
+    nearest_by(srgb, Ciede2000::default())
+}
+
+/// Named color nearest to a given color under a color difference metric, with its name.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ difference::Cie76, named, Srgb };
+/// assert_eq!(named::nearest_by(Srgb::new(100, 150, 240), Cie76).0, "cornflowerblue");
+/// ```
+pub fn nearest_by<M: Metric<Srgb> + Copy>(srgb: Srgb, metric: M) -> (&'static str, Srgb) {
+    ALL.iter().copied()
+        .min_by(|(_, a), (_, b)| srgb.difference(a, metric).total_cmp(&srgb.difference(b, metric)))
+        .unwrap()
+}
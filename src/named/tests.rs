@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn names() {
+    assert!(ALL.windows(2).all(|x| x[0].0 < x[1].0));
+    for (name, color) in ALL {
+        assert_eq!(from_name(name), Some(color));
+        assert_eq!(from_name(&name.to_ascii_uppercase()), Some(color));
+        assert_eq!(from_name(to_name(color).unwrap()), Some(color));
+    }
+    assert_eq!(from_name(""), None);
+    assert_eq!(from_name("transparent"), None);
+}
+
+#[test]
+fn aliases() {
+    assert_eq!(AQUA, CYAN);
+    assert_eq!(FUCHSIA, MAGENTA);
+    assert_eq!(to_name(MAGENTA), Some("fuchsia"));
+    assert_eq!(to_name(SLATEGREY), Some("slategray"));
+    let distinct = ALL.iter().filter(|(name, color)| to_name(*color) == Some(name)).count();
+    assert_eq!(distinct, 139);
+}
+
+#[test]
+fn nearest_names() {
+    for (name, color) in ALL {
+        assert_eq!(nearest(color), (to_name(color).unwrap(), color), "{name}");
+    }
+    assert_eq!(nearest(Srgb::new(254, 0, 1)).0, "red");
+    assert_eq!(nearest(Srgb::new(120, 120, 125)).0, "gray");
+    assert_eq!(nearest(Srgb::new(101, 150, 236)).0, "cornflowerblue");
+}
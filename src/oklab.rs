@@ -0,0 +1,80 @@
+mod conversions;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use conversions::{
+    from_ciexyz,
+    to_ciexyz,
+};
+
+/// A struct representing a color in the Oklab color space.
+///
+/// Oklab is a perceptual color space relative to D65, the reference white of sRGB,
+/// designed to predict lightness, chroma and hue better than CIELAB.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Oklab(f32, f32, f32); // lightness, a, b
+
+impl Oklab {
+    /// Creates an Oklab instance from 3 float
+    /// representing the lightness, a, and b components.
+    ///
+    /// The lightness component must between 0 and 1 included
+    /// otherwise it doesn't represent a valid Oklab color
+    /// and the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklab;
+    /// let valid = Oklab::new(0.5, 0.1, -0.1); // Ok.
+    /// let invalid = Oklab::new(1.5, 0.1, -0.1); // Not ok. The lightness component is greater than 1.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn new(lightness: f32, a: f32, b: f32) -> Option<Self> {
+        match (0f32..=1f32).contains(&lightness) {
+            true    => Some(Self(lightness, a, b)),
+            false   => None,
+        }
+    }
+
+    /// Lightness component of an Oklab color.
+    /// Value is between 0 and 1 included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklab;
+    /// let oklab = Oklab::new(0.5, 0.1, -0.1).unwrap();
+    /// assert_eq!(oklab.lightness(), 0.5);
+    /// ```
+    pub fn lightness(&self) -> f32 {
+        self.0
+    }
+
+    /// A component of an Oklab color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklab;
+    /// let oklab = Oklab::new(0.5, 0.1, -0.1).unwrap();
+    /// assert_eq!(oklab.a(), 0.1);
+    /// ```
+    pub fn a(&self) -> f32 {
+        self.1
+    }
+
+    /// B component of an Oklab color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklab;
+    /// let oklab = Oklab::new(0.5, 0.1, -0.1).unwrap();
+    /// assert_eq!(oklab.b(), -0.1);
+    /// ```
+    pub fn b(&self) -> f32 {
+        self.2
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    error::{ OutOfGamut, OutOfSpecification },
+    illuminant::Illuminant,
+    lms::ConeFundamentals,
+    matrix,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Lms,
+    Oklch,
+    Srgb,
+};
+use super::Oklab;
+
+//Matrix from CIEXYZ relative to D65 to the cone responses of Oklab, as used by CSS Color 4.
+const CIEXYZ_TO_LMS: [f32; 9] = [
+    0.819_022_5f32, 0.361_906_25f32, -0.128_873_78f32,
+    0.032_983_655f32, 0.929_286_9f32, 0.036_144_666f32,
+    0.048_177_19f32, 0.264_239_54f32, 0.633_547_8f32,
+];
+
+//Matrix from the cube roots of the cone responses of Oklab to Oklab, as used by CSS Color 4.
+const LMS_TO_OKLAB: [f32; 9] = [
+    0.210_454_27f32, 0.793_617_8f32, -0.004_072_043f32,
+    1.977_998_5f32, -2.428_592_2f32, 0.450_593_7f32,
+    0.025_904_042f32, 0.782_771_7f32, -0.808_675_77f32,
+];
+
+const LMS_TO_CIEXYZ: [f32; 9] = matrix::inverse_unchecked(CIEXYZ_TO_LMS);
+const OKLAB_TO_LMS: [f32; 9] = matrix::inverse_unchecked(LMS_TO_OKLAB);
+
+//Conversion of an Oklab color to CIEXYZ values relative to D65, which may fall outside of the
+//CIEXYZ specification for colors outside of the gamut of real colors.
+pub(crate) fn to_ciexyz(oklab: Oklab) -> [f32; 3] {
+    let lms = matrix::transform(OKLAB_TO_LMS, oklab.into());
+    matrix::transform(LMS_TO_CIEXYZ, lms.map(|x| x * x * x))
+}
+
+//Conversion of CIEXYZ values relative to D65, which may fall outside of the CIEXYZ specification
+//for colors outside of the gamut of real colors, to Oklab components. The lightness isn't clamped.
+pub(crate) fn from_ciexyz(xyz: [f32; 3]) -> [f32; 3] {
+    let lms = matrix::transform(CIEXYZ_TO_LMS, xyz);
+    matrix::transform(LMS_TO_OKLAB, lms.map(f32::cbrt))
+}
+
+/// Conversion from CIEXYZ to Oklab.
+/// The CIEXYZ color is taken as relative to D65.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Ciexyz, Oklab };
+/// let oklab: Oklab = Ciexyz::new(0.1, 0.2, 0.3).unwrap().into();
+/// ```
+impl From<Ciexyz> for Oklab {
+    fn from(ciexyz: Ciexyz) -> Self {
+        let [l, a, b] = from_ciexyz(ciexyz.into());
+        Self(l.clamp(0f32, 1f32), a, b)
+    }
+}
+
+/// Conversion from sRGB to Oklab.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklab, Srgb };
+/// let oklab: Oklab = Srgb::new(10, 20, 30).into();
+/// ```
+impl From<Srgb> for Oklab {
+    fn from(srgb: Srgb) -> Self {
+        let ciexyz: Ciexyz = srgb.into();
+        Self::from(ciexyz)
+    }
+}
+
+/// Conversion from CIELAB to Oklab.
+/// The CIELAB color is adapted from its reference white to D65.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielab, Oklab };
+/// let oklab: Oklab = Cielab::new(10.0, 20.0, 30.0).unwrap().into();
+/// ```
+impl<W: Illuminant> From<Cielab<W>> for Oklab {
+    fn from(cielab: Cielab<W>) -> Self {
+        let ciexyz: Ciexyz = cielab.into();
+        Self::from(ciexyz)
+    }
+}
+
+/// Conversion from CIELCh to Oklab.
+/// The CIELCh color is adapted from its reference white to D65.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielch, Oklab };
+/// let oklab: Oklab = Cielch::new(10.0, 20.0, 30f32.to_radians()).unwrap().into();
+/// ```
+impl<W: Illuminant> From<Cielch<W>> for Oklab {
+    fn from(cielch: Cielch<W>) -> Self {
+        let ciexyz: Ciexyz = cielch.into();
+        Self::from(ciexyz)
+    }
+}
+
+/// Conversion from Oklch to Oklab.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklab, Oklch };
+/// let oklab: Oklab = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap().into();
+/// ```
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let [l, c, h]: [f32; 3] = oklch.into();
+        Self(l, c * h.cos(), c * h.sin())
+    }
+}
+
+/// Conversion from LMS to Oklab.
+/// The conversion may fail and return an OutOfGamut error
+/// if the LMS color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklab, Lms, lms::Bradford };
+/// let oklab: Oklab = Lms::<Bradford>::new(0.1, 0.2, 0.3).try_into().unwrap();
+/// ```
+impl<M: ConeFundamentals> TryFrom<Lms<M>> for Oklab {
+    type Error = OutOfGamut;
+
+    fn try_from(lms: Lms<M>) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(lms)?;
+        Ok(Self::from(ciexyz))
+    }
+}
+
+/// New Oklab color from an array of 3 floats.
+/// Convertion may fail if the resulting color would fall outside
+/// the Oklab specification. The error type in that case is an
+/// OutOfSpecification error.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Oklab;
+/// let oklab: Oklab = [0.5, 0.1, -0.1].try_into().unwrap();
+/// ```
+impl TryFrom<[f32; 3]> for Oklab {
+    type Error = OutOfSpecification;
+
+    fn try_from(array: [f32; 3]) -> Result<Self, Self::Error> {
+        let [l, a, b] = array;
+        Self::new(l, a, b).ok_or(OutOfSpecification)
+    }
+}
+
+/// Convert an Oklab color into an array of 3 floats.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Oklab;
+/// let [l, a, b]: [f32; 3] = Oklab::new(0.5, 0.1, -0.1).unwrap().into();
+/// ```
+impl From<Oklab> for [f32; 3] {
+    fn from(oklab: Oklab) -> Self {
+        let Oklab(l, a, b) = oklab;
+        [l, a, b]
+    }
+}
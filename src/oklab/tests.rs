@@ -0,0 +1,58 @@
+use super::*;
+use crate::{
+    Ciexyz,
+    Oklch,
+    Srgb,
+};
+
+fn assert_close(oklab: Oklab, expected: [f32; 3]) {
+    let components: [f32; 3] = oklab.into();
+    for (component, expected) in components.into_iter().zip(expected) {
+        assert!((component - expected).abs() < 1e-4, "{:?} {:?}", components, expected);
+    }
+}
+
+#[test]
+fn new() {
+    let a = Oklab(0.5, 0.1, -0.1);
+    let b = Oklab::new(0.5, 0.1, -0.1).unwrap();
+    assert_eq!(a, b);
+    assert!(Oklab::new(-0.1, 0.1, -0.1).is_none());
+    assert!(Oklab::new(1.1, 0.1, -0.1).is_none());
+}
+
+#[test]
+fn components() {
+    let a = Oklab(0.5, 0.1, -0.2);
+    assert_eq!(a.lightness(), 0.5);
+    assert_eq!(a.a(), 0.1);
+    assert_eq!(a.b(), -0.2);
+}
+
+#[test]
+fn from_srgb() {
+    //Reference values from CSS Color 4.
+    assert_close(Srgb::new(255, 255, 255).into(), [1.0, 0.0, 0.0]);
+    assert_close(Srgb::new(0, 0, 0).into(), [0.0, 0.0, 0.0]);
+    assert_close(Srgb::new(255, 0, 0).into(), [0.62796, 0.22486, 0.12585]);
+    assert_close(Srgb::new(0, 255, 0).into(), [0.86644, -0.23389, 0.1795]);
+    assert_close(Srgb::new(0, 0, 255).into(), [0.45201, -0.03246, -0.31153]);
+}
+
+#[test]
+fn round_trip() {
+    for rgb in [[255, 0, 0], [10, 200, 30], [128, 128, 128], [255, 255, 255], [0, 0, 0], [12, 34, 250]] {
+        let srgb = Srgb::from(rgb);
+        let oklab = Oklab::from(srgb);
+        assert_eq!(Srgb::try_from(oklab).unwrap(), srgb);
+        assert_eq!(Srgb::try_from(Oklch::from(oklab)).unwrap(), srgb);
+        assert_close(Oklab::from(Ciexyz::try_from(oklab).unwrap()), oklab.into());
+    }
+}
+
+#[test]
+fn out_of_gamut() {
+    assert!(Srgb::try_from(Oklab::new(0.9, 0.3, 0.0).unwrap()).is_err());
+    assert!(Ciexyz::try_from(Oklab::new(0.5, -0.5, 0.5).unwrap()).is_err());
+}
+
@@ -0,0 +1,103 @@
+mod conversions;
+#[cfg(test)]
+mod tests;
+
+/// A struct representing a color in the Oklch color space,
+/// the polar form of the Oklab color space.
+#[derive(Debug, Clone, Copy)]
+pub struct Oklch(f32, f32, f32); // lightness, chroma, hue
+
+impl Oklch {
+    /// Creates an Oklch instance from 3 float
+    /// representing the lightness, chroma, and hue components.
+    ///
+    /// The lightness component must between 0 and 1 included
+    /// and the chroma component must be positive
+    /// otherwise it doesn't represent a valid Oklch color
+    /// and the return value is None.
+    ///
+    /// The hue component is in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklch;
+    /// let valid = Oklch::new(0.5, 0.1, 30f32.to_radians()); // Ok.
+    /// let invalid = Oklch::new(0.5, -0.1, 30f32.to_radians()); // Not ok. The chroma component is negative.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn new(lightness: f32, chroma: f32, hue: f32) -> Option<Self> {
+        match (0f32..=1f32).contains(&lightness) && chroma >= 0f32 {
+            true    => Some(Self(lightness, chroma, hue)),
+            false   => None,
+        }
+    }
+
+    /// Lightness component of an Oklch color.
+    /// Value is between 0 and 1 included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklch;
+    /// let oklch = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap();
+    /// assert_eq!(oklch.lightness(), 0.5);
+    /// ```
+    pub fn lightness(&self) -> f32 {
+        self.0
+    }
+
+    /// Chroma component of an Oklch color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklch;
+    /// let oklch = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap();
+    /// assert_eq!(oklch.chroma(), 0.1);
+    /// ```
+    pub fn chroma(&self) -> f32 {
+        self.1
+    }
+
+    /// Hue component of an Oklch color.
+    /// Value is in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Oklch;
+    /// let oklch = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap();
+    /// assert_eq!(oklch.hue(), 30f32.to_radians());
+    /// ```
+    pub fn hue(&self) -> f32 {
+        self.2
+    }
+}
+
+/// Two Oklch instances are equal if all their components are equal
+/// unless the chroma components are both 0 in which case
+/// only the lightness components are compared.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Oklch;
+/// let a = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap();
+/// let b = Oklch::new(0.5, 0.0, 30f32.to_radians()).unwrap();
+/// let c = Oklch::new(0.5, 0.0, 60f32.to_radians()).unwrap();
+/// assert_ne!(a, b);
+/// assert_eq!(b, c);
+/// assert_eq!(a, a);
+/// ```
+impl PartialEq for Oklch {
+    fn eq(&self, other: &Self) -> bool {
+        let lhs: [f32; 3] = (*self).into();
+        let rhs: [f32; 3] = (*other).into();
+        match self.chroma() != 0f32 || other.chroma() != 0f32 {
+            true    => (0..3).all(|x| lhs[x] == rhs[x]),
+            false   => self.lightness() == other.lightness(),
+        }
+    }
+}
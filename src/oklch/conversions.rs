@@ -0,0 +1,146 @@
+use crate::{
+    error::{ OutOfGamut, OutOfSpecification },
+    illuminant::Illuminant,
+    lms::ConeFundamentals,
+    Cielab,
+    Cielch,
+    Ciexyz,
+    Lms,
+    Oklab,
+    Srgb,
+};
+use super::Oklch;
+
+/// Conversion from Oklab to Oklch.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklab, Oklch };
+/// let oklch: Oklch = Oklab::new(0.5, 0.1, -0.1).unwrap().into();
+/// ```
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let [l, a, b]: [f32; 3] = oklab.into();
+        let c = f32::sqrt(a * a + b * b);
+        let h = f32::atan2(b, a);
+        Self(l, c, h)
+    }
+}
+
+/// Conversion from CIEXYZ to Oklch.
+/// The CIEXYZ color is taken as relative to D65.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Ciexyz, Oklch };
+/// let oklch: Oklch = Ciexyz::new(0.1, 0.2, 0.3).unwrap().into();
+/// ```
+impl From<Ciexyz> for Oklch {
+    fn from(ciexyz: Ciexyz) -> Self {
+        let oklab: Oklab = ciexyz.into();
+        Self::from(oklab)
+    }
+}
+
+/// Conversion from sRGB to Oklch.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklch, Srgb };
+/// let oklch: Oklch = Srgb::new(10, 20, 30).into();
+/// ```
+impl From<Srgb> for Oklch {
+    fn from(srgb: Srgb) -> Self {
+        let oklab: Oklab = srgb.into();
+        Self::from(oklab)
+    }
+}
+
+/// Conversion from CIELAB to Oklch.
+/// The CIELAB color is adapted from its reference white to D65.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielab, Oklch };
+/// let oklch: Oklch = Cielab::new(10.0, 20.0, 30.0).unwrap().into();
+/// ```
+impl<W: Illuminant> From<Cielab<W>> for Oklch {
+    fn from(cielab: Cielab<W>) -> Self {
+        let oklab: Oklab = cielab.into();
+        Self::from(oklab)
+    }
+}
+
+/// Conversion from CIELCh to Oklch.
+/// The CIELCh color is adapted from its reference white to D65.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Cielch, Oklch };
+/// let oklch: Oklch = Cielch::new(10.0, 20.0, 30f32.to_radians()).unwrap().into();
+/// ```
+impl<W: Illuminant> From<Cielch<W>> for Oklch {
+    fn from(cielch: Cielch<W>) -> Self {
+        let oklab: Oklab = cielch.into();
+        Self::from(oklab)
+    }
+}
+
+/// Conversion from LMS to Oklch.
+/// The conversion may fail and return an OutOfGamut error
+/// if the LMS color doesn't correspond to a valid CIEXYZ color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklch, Lms, lms::Bradford };
+/// let oklch: Oklch = Lms::<Bradford>::new(0.1, 0.2, 0.3).try_into().unwrap();
+/// ```
+impl<M: ConeFundamentals> TryFrom<Lms<M>> for Oklch {
+    type Error = OutOfGamut;
+
+    fn try_from(lms: Lms<M>) -> Result<Self, Self::Error> {
+        let oklab = Oklab::try_from(lms)?;
+        Ok(Self::from(oklab))
+    }
+}
+
+/// New Oklch color from an array of 3 floats.
+/// Convertion may fail if the resulting color would fall outside
+/// the Oklch specification. The error type in that case is an
+/// OutOfSpecification error.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Oklch;
+/// let oklch: Oklch = [0.5, 0.1, 30f32.to_radians()].try_into().unwrap();
+/// ```
+impl TryFrom<[f32; 3]> for Oklch {
+    type Error = OutOfSpecification;
+
+    fn try_from(array: [f32; 3]) -> Result<Self, Self::Error> {
+        let [l, c, h] = array;
+        Self::new(l, c, h).ok_or(OutOfSpecification)
+    }
+}
+
+/// Convert an Oklch color into an array of 3 floats.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Oklch;
+/// let [l, c, h]: [f32; 3] = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap().into();
+/// ```
+impl From<Oklch> for [f32; 3] {
+    fn from(oklch: Oklch) -> Self {
+        let Oklch(l, c, h) = oklch;
+        [l, c, h]
+    }
+}
@@ -0,0 +1,43 @@
+use super::*;
+use crate::{
+    Oklab,
+    Srgb,
+};
+
+#[test]
+fn new() {
+    let a = Oklch(0.5, 0.1, 30f32.to_radians());
+    let b = Oklch::new(0.5, 0.1, 30f32.to_radians()).unwrap();
+    assert_eq!(a, b);
+    assert!(Oklch::new(-0.1, 0.1, 0.0).is_none());
+    assert!(Oklch::new(1.1, 0.1, 0.0).is_none());
+    assert!(Oklch::new(0.5, -0.1, 0.0).is_none());
+}
+
+#[test]
+fn components() {
+    let a = Oklch(0.5, 0.1, 2.0);
+    assert_eq!(a.lightness(), 0.5);
+    assert_eq!(a.chroma(), 0.1);
+    assert_eq!(a.hue(), 2.0);
+}
+
+#[test]
+fn from_srgb() {
+    //Reference value from CSS Color 4.
+    let [l, c, h]: [f32; 3] = Oklch::from(Srgb::new(255, 0, 0)).into();
+    assert!((l - 0.62796).abs() < 1e-4);
+    assert!((c - 0.25768).abs() < 1e-4);
+    assert!((h.to_degrees() - 29.2339).abs() < 1e-2);
+}
+
+#[test]
+fn from_oklab() {
+    let oklch = Oklch::from(Oklab::new(0.5, 0.0, 0.1).unwrap());
+    assert!((oklch.chroma() - 0.1).abs() < 1e-6);
+    assert!((oklch.hue().to_degrees() - 90.0).abs() < 1e-4);
+    let [l, a, b]: [f32; 3] = Oklab::from(oklch).into();
+    assert_eq!(l, 0.5);
+    assert!(a.abs() < 1e-6);
+    assert!((b - 0.1).abs() < 1e-6);
+}
@@ -1,7 +1,10 @@
 mod conversions;
+mod hex;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use conversions::FROM_CIEXYZ_CONVERSION_MATRIX;
+
 use std::fmt;
 
 /// A struct representing a color in the sRGB color space.
@@ -53,9 +56,8 @@ impl Srgb {
     /// use cowor::Srgb;
     /// let srgb = Srgb::new(10, 20, 30);
     /// ```
-    pub fn new(red: u8, green: u8, blue: u8) -> Self {
-        let [norm_r, norm_g, norm_b] = [red, green, blue].map(|x| x as f32 / 255f32);
-        Self(norm_r, norm_g, norm_b)
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self(red as f32 / 255f32, green as f32 / 255f32, blue as f32 / 255f32)
     }
 
     /// Red component of a sRGB color.
@@ -180,6 +182,20 @@ impl Srgb {
     pub fn linear_blue(&self) -> f32 {
         gamma_expand(self.2)
     }
+
+    /// Relative luminance of a sRGB color, as defined by WCAG 2.x.
+    /// Value is normalized (between 0 and 1 included).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Srgb;
+    /// assert_eq!(Srgb::new(0, 0, 0).relative_luminance(), 0.0);
+    /// assert_eq!(Srgb::new(255, 255, 255).relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f32 {
+        0.2126f32 * self.linear_red() + 0.7152f32 * self.linear_green() + 0.0722f32 * self.linear_blue()
+    }
 }
 
 
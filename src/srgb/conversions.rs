@@ -1,8 +1,23 @@
 use crate::{
     cielab::Cielab,
     cielch::Cielch,
-    ciexyz::Ciexyz,
+    ciexyz::{
+        Ciexyz,
+        FROM_SRGB_CONVERSION_MATRIX,
+    },
     error::OutOfGamut,
+    illuminant::{
+        Illuminant,
+        D65,
+    },
+    lms::ConeFundamentals,
+    matrix,
+    oklab,
+    Lms,
+    Oklab,
+    Oklch,
+    Ycocg,
+    YcocgR,
 };
 use super::{
     gamma_compress,
@@ -10,7 +25,7 @@ use super::{
 };
 
 //Conversion matrix to convert from CIEXYZ to sRGB.
-const FROM_CIEXYZ_CONVERSION_MATRIX: [f32; 9] = {
+pub(crate) const FROM_CIEXYZ_CONVERSION_MATRIX: [f32; 9] = {
     let xr = 12831f32 / 3959f32;
     let xg = -329f32 / 214f32;
     let xb = -1974f32 / 3959f32;
@@ -45,9 +60,13 @@ impl TryFrom<Ciexyz> for Srgb {
         let lr = x * xr + y * xg + z * xb;
         let lg = x * yr + y * yg + z * yb;
         let lb = x * zr + y * zg + z * zb;
-        let [r, g, b] = [lr, lg, lb].map(gamma_compress);
-        match [r, g, b].iter().all(|x| (0f32..=1f32).contains(x)) {
-            true    => Ok(Self(r, g, b)),
+        //Rounding errors can push colors on the edge of the gamut slightly outside of it.
+        let tolerance = 1e-5f32;
+        match [lr, lg, lb].iter().all(|x| (-tolerance..=1f32 + tolerance).contains(x)) {
+            true    => {
+                let [r, g, b] = [lr, lg, lb].map(|x| gamma_compress(x.clamp(0f32, 1f32)));
+                Ok(Self(r, g, b))
+            },
             false   => Err(OutOfGamut),
         }
     }
@@ -66,10 +85,10 @@ impl TryFrom<Ciexyz> for Srgb {
 /// assert!(Srgb::try_from(valid).is_ok());
 /// assert!(Srgb::try_from(invalid).is_err());
 /// ```
-impl TryFrom<Cielab> for Srgb {
+impl<W: Illuminant> TryFrom<Cielab<W>> for Srgb {
     type Error = OutOfGamut;
 
-    fn try_from(cielab: Cielab) -> Result<Self, Self::Error> {
+    fn try_from(cielab: Cielab<W>) -> Result<Self, Self::Error> {
         let ciexyz: Ciexyz = cielab.into();
         Self::try_from(ciexyz)
     }
@@ -88,15 +107,241 @@ impl TryFrom<Cielab> for Srgb {
 /// assert!(Srgb::try_from(valid).is_ok());
 /// assert!(Srgb::try_from(invalid).is_err());
 /// ```
-impl TryFrom<Cielch> for Srgb {
+impl<W: Illuminant> TryFrom<Cielch<W>> for Srgb {
     type Error = OutOfGamut;
 
-    fn try_from(cielch: Cielch) -> Result<Self, Self::Error> {
+    fn try_from(cielch: Cielch<W>) -> Result<Self, Self::Error> {
         let ciexyz: Ciexyz = cielch.into();
         Self::try_from(ciexyz)
     }
 }
 
+impl Srgb {
+    //Conversion from CIELCh to sRGB which never fails. The chroma of colors outside of the sRGB
+    //gamut is reduced, keeping their lightness and hue, until they land in the gamut.
+    pub(crate) fn from_cielch_reducing_chroma(cielch: Cielch<D65>) -> Self {
+        let [l, c, h]: [f32; 3] = cielch.into();
+        let in_gamut = |chroma| Cielch::new(l, chroma, h).and_then(|x| Self::try_from(x).ok());
+        if let Some(srgb) = in_gamut(c) {
+            return srgb;
+        }
+        let (mut low, mut high) = (0f32, c);
+        for _ in 0..24 {
+            let middle = (low + high) / 2f32;
+            match in_gamut(middle) {
+                Some(_) => low = middle,
+                None    => high = middle,
+            }
+        }
+        in_gamut(low).unwrap_or_else(|| {
+            let y = Ciexyz::from(Cielab::new(l, 0f32, 0f32).unwrap()).y();
+            let gray = gamma_compress(y).clamp(0f32, 1f32);
+            Self(gray, gray, gray)
+        })
+    }
+
+    //Conversion from CIEXYZ values relative to D65 to sRGB which never fails, mapping colors
+    //outside of the sRGB gamut with from_oklab_reducing_chroma. NaN values of colors too far from
+    //the gamut of real colors are taken as 0.
+    pub(crate) fn from_ciexyz_reducing_chroma(xyz: [f32; 3]) -> Self {
+        let xyz = xyz.map(|x| match x.is_nan() {
+            true    => 0f32,
+            false   => x,
+        });
+        Self::from_oklab_reducing_chroma(oklab::from_ciexyz(xyz))
+    }
+
+    //Conversion from Oklab components to sRGB which never fails, with the gamut mapping of
+    //CSS Color 4: the chroma of colors outside of the sRGB gamut is reduced in Oklch at constant
+    //lightness and hue until clipping them to the gamut changes them less than a just noticeable
+    //difference. Colors at least as light as white or as dark as black become white or black.
+    pub(crate) fn from_oklab_reducing_chroma(oklab: [f32; 3]) -> Self {
+        //Just noticeable difference in Oklab and precision of the chroma, as used by CSS Color 4.
+        let (jnd, epsilon) = (0.02f32, 0.0001f32);
+        let [l, a, b] = oklab.map(|x| match x.is_nan() {
+            true    => 0f32,
+            false   => x,
+        });
+        match l {
+            l if l >= 1f32  => return Self(1f32, 1f32, 1f32),
+            l if l <= 0f32  => return Self(0f32, 0f32, 0f32),
+            _               => (),
+        }
+        //Linear sRGB components of the color with a given chroma.
+        let (hue, chroma) = (b.atan2(a), a.hypot(b));
+        let linear = |chroma: f32| {
+            let xyz = oklab::to_ciexyz(Oklab::new(l, chroma * hue.cos(), chroma * hue.sin()).unwrap());
+            matrix::transform(FROM_CIEXYZ_CONVERSION_MATRIX, xyz)
+        };
+        let in_gamut = |linear: [f32; 3]| linear.iter().all(|x| (0f32..=1f32).contains(x));
+        let clip = |linear: [f32; 3]| linear.map(|x| x.clamp(0f32, 1f32));
+        //Difference in Oklab between the color with a given chroma and the same color clipped.
+        let clipping_difference = |chroma: f32| {
+            let clipped = oklab::from_ciexyz(matrix::transform(FROM_SRGB_CONVERSION_MATRIX, clip(linear(chroma))));
+            let color = [l, chroma * hue.cos(), chroma * hue.sin()];
+            (0..3).map(|i| (color[i] - clipped[i]).powi(2)).sum::<f32>().sqrt()
+        };
+        let components = linear(chroma);
+        let components = match in_gamut(components) || clipping_difference(chroma) < jnd {
+            true    => components,
+            false   => {
+                //No color of the sRGB gamut has a chroma greater than 1.
+                let (mut low, mut high) = (0f32, chroma.min(1f32));
+                let mut low_in_gamut = true;
+                while high - low > epsilon {
+                    let middle = (low + high) / 2f32;
+                    if low_in_gamut && in_gamut(linear(middle)) {
+                        low = middle;
+                        continue;
+                    }
+                    match clipping_difference(middle) {
+                        x if x >= jnd               => high = middle,
+                        x if jnd - x < epsilon      => {
+                            low = middle;
+                            break;
+                        },
+                        _                           => {
+                            low_in_gamut = false;
+                            low = middle;
+                        },
+                    }
+                }
+                linear(low)
+            },
+        };
+        let [r, g, b] = clip(components).map(gamma_compress);
+        Self(r, g, b)
+    }
+}
+
+/// Conversion from Oklab to sRGB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklab color can not be represented in the sRGB color gamut.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklab, Srgb };
+/// let valid = Oklab::new(0.5, 0.0, 0.0).unwrap(); // Will land in the sRGB color gamut.
+/// let invalid = Oklab::new(0.9, 0.3, 0.0).unwrap(); // Won't land in the sRGB color gamut.
+/// assert!(Srgb::try_from(valid).is_ok());
+/// assert!(Srgb::try_from(invalid).is_err());
+/// ```
+impl TryFrom<Oklab> for Srgb {
+    type Error = OutOfGamut;
+
+    fn try_from(oklab: Oklab) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(oklab)?;
+        Self::try_from(ciexyz)
+    }
+}
+
+/// Conversion from Oklch to sRGB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the Oklch color can not be represented in the sRGB color gamut.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Oklch, Srgb };
+/// let valid = Oklch::new(0.5, 0.0, 0.0).unwrap(); // Will land in the sRGB color gamut.
+/// let invalid = Oklch::new(0.9, 0.3, 0.0).unwrap(); // Won't land in the sRGB color gamut.
+/// assert!(Srgb::try_from(valid).is_ok());
+/// assert!(Srgb::try_from(invalid).is_err());
+/// ```
+impl TryFrom<Oklch> for Srgb {
+    type Error = OutOfGamut;
+
+    fn try_from(oklch: Oklch) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(oklch)?;
+        Self::try_from(ciexyz)
+    }
+}
+
+/// Conversion from LMS to sRGB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the LMS color can not be represented in the sRGB color gamut.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Lms, lms::Bradford, Srgb };
+/// let valid = Lms::<Bradford>::new(0.5, 0.5, 0.5); // Will land in the sRGB color gamut.
+/// let invalid = Lms::<Bradford>::new(0.1, 0.5, 0.1); // Won't land in the sRGB color gamut.
+/// assert!(Srgb::try_from(valid).is_ok());
+/// assert!(Srgb::try_from(invalid).is_err());
+/// ```
+impl<M: ConeFundamentals> TryFrom<Lms<M>> for Srgb {
+    type Error = OutOfGamut;
+
+    fn try_from(lms: Lms<M>) -> Result<Self, Self::Error> {
+        let ciexyz = Ciexyz::try_from(lms)?;
+        Self::try_from(ciexyz)
+    }
+}
+
+/// Conversion from YCoCg to sRGB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the YCoCg color can not be represented in the sRGB color gamut.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Srgb, Ycocg };
+/// let valid = Ycocg::new(0.5, 0.0, 0.0).unwrap(); // Will land in the sRGB color gamut.
+/// let invalid = Ycocg::new(0.0, 0.5, 0.5).unwrap(); // Won't land in the sRGB color gamut.
+/// assert!(Srgb::try_from(valid).is_ok());
+/// assert!(Srgb::try_from(invalid).is_err());
+/// ```
+impl TryFrom<Ycocg> for Srgb {
+    type Error = OutOfGamut;
+
+    fn try_from(ycocg: Ycocg) -> Result<Self, Self::Error> {
+        let t = ycocg.y() - ycocg.cg();
+        let r = t + ycocg.co();
+        let g = ycocg.y() + ycocg.cg();
+        let b = t - ycocg.co();
+        //Rounding errors can push components of colors on the edge of the gamut slightly outside of it.
+        let tolerance = 1e-6f32;
+        match [r, g, b].iter().all(|x| (-tolerance..=1f32 + tolerance).contains(x)) {
+            true    => {
+                let [r, g, b] = [r, g, b].map(|x| x.clamp(0f32, 1f32));
+                Ok(Self(r, g, b))
+            },
+            false   => Err(OutOfGamut),
+        }
+    }
+}
+
+/// Conversion from YCoCg-R to sRGB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the YCoCg-R color can not be represented in the sRGB color gamut.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Srgb, YcocgR };
+/// let valid = YcocgR::new(128, 0, 0).unwrap(); // Will land in the sRGB color gamut.
+/// let invalid = YcocgR::new(0, 255, 255).unwrap(); // Won't land in the sRGB color gamut.
+/// assert!(Srgb::try_from(valid).is_ok());
+/// assert!(Srgb::try_from(invalid).is_err());
+/// ```
+impl TryFrom<YcocgR> for Srgb {
+    type Error = OutOfGamut;
+
+    fn try_from(ycocg_r: YcocgR) -> Result<Self, Self::Error> {
+        let y = i16::from(ycocg_r.y());
+        let t = y - (ycocg_r.cg() >> 1);
+        let g = ycocg_r.cg() + t;
+        let b = t - (ycocg_r.co() >> 1);
+        let r = b + ycocg_r.co();
+        match [r, g, b].map(u8::try_from) {
+            [Ok(r), Ok(g), Ok(b)]   => Ok(Self::new(r, g, b)),
+            _                       => Err(OutOfGamut),
+        }
+    }
+}
+
 /// New sRGB color from an array of 3 unsigned 8 bit integers.
 ///
 /// # Examples
@@ -0,0 +1,155 @@
+use std::{
+    fmt,
+    str::FromStr,
+};
+use crate::{
+    error::ParseHexError,
+    Alpha,
+};
+use super::Srgb;
+
+//Parses the red, green, blue and alpha components of a hexadecimal color, with or without its
+//leading '#'. The alpha is 255 when the color has none.
+fn parse_hex(hex: &str) -> Result<[u8; 4], ParseHexError> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    let length = hex.chars().count();
+    if ![3, 4, 6, 8].contains(&length) {
+        return Err(ParseHexError::InvalidLength(length));
+    }
+    let digits = hex.chars()
+        .map(|x| x.to_digit(16).map(|d| d as u8).ok_or(ParseHexError::InvalidDigit(x)))
+        .collect::<Result<Vec<u8>, _>>()?;
+    Ok(match digits[..] {
+        [r, g, b]                           => [r * 17, g * 17, b * 17, 255],
+        [r, g, b, a]                        => [r * 17, g * 17, b * 17, a * 17],
+        [r1, r2, g1, g2, b1, b2]            => [r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2, 255],
+        [r1, r2, g1, g2, b1, b2, a1, a2]    => [r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2, a1 * 16 + a2],
+        _                                   => unreachable!(),
+    })
+}
+
+//Prefix of a hexadecimal color, '#' with the alternate flag.
+fn prefix(f: &fmt::Formatter) -> &'static str {
+    match f.alternate() {
+        true    => "#",
+        false   => "",
+    }
+}
+
+/// Parses an sRGB color from a hexadecimal color in the `rgb`, `rgba`, `rrggbb` or `rrggbbaa`
+/// forms, with or without a leading `#`.
+/// The color may have an alpha only if it is fully opaque, parse an `Srgba` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ error::ParseHexError, Srgb };
+/// assert_eq!("#0a141e".parse::<Srgb>(), Ok(Srgb::new(10, 20, 30)));
+/// assert_eq!("F80".parse::<Srgb>(), Ok(Srgb::new(255, 136, 0)));
+/// assert_eq!("#0a141eff".parse::<Srgb>(), Ok(Srgb::new(10, 20, 30)));
+/// assert_eq!("#0a141e80".parse::<Srgb>(), Err(ParseHexError::Translucent));
+/// assert_eq!("#0a141".parse::<Srgb>(), Err(ParseHexError::InvalidLength(5)));
+/// assert_eq!("#0a141g".parse::<Srgb>(), Err(ParseHexError::InvalidDigit('g')));
+/// ```
+impl FromStr for Srgb {
+    type Err = ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_hex(s)? {
+            [r, g, b, 255]  => Ok(Self::new(r, g, b)),
+            _               => Err(ParseHexError::Translucent),
+        }
+    }
+}
+
+/// Parses an sRGB color with an alpha channel from a hexadecimal color in the `rgb`, `rgba`,
+/// `rrggbb` or `rrggbbaa` forms, with or without a leading `#`.
+/// Colors without an alpha are fully opaque.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Srgb, Srgba };
+/// let srgba: Srgba = "#0a141e80".parse().unwrap();
+/// assert_eq!(srgba.color(), Srgb::new(10, 20, 30));
+/// assert_eq!(srgba.alpha(), 128.0 / 255.0);
+/// assert_eq!("#0a141e".parse::<Srgba>().unwrap().alpha(), 1.0);
+/// ```
+impl FromStr for Alpha<Srgb> {
+    type Err = ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_hex(s).map(Self::from)
+    }
+}
+
+/// Formats an sRGB color as a lowercase `rrggbb` hexadecimal color.
+/// The alternate flag adds a leading `#`.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Srgb;
+/// let srgb = Srgb::new(10, 20, 30);
+/// assert_eq!(format!("{:x}", srgb), "0a141e");
+/// assert_eq!(format!("{:#x}", srgb), "#0a141e");
+/// ```
+impl fmt::LowerHex for Srgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [r, g, b]: [u8; 3] = (*self).into();
+        write!(f, "{}{r:02x}{g:02x}{b:02x}", prefix(f))
+    }
+}
+
+/// Formats an sRGB color as an uppercase `RRGGBB` hexadecimal color.
+/// The alternate flag adds a leading `#`.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Srgb;
+/// let srgb = Srgb::new(10, 20, 30);
+/// assert_eq!(format!("{:X}", srgb), "0A141E");
+/// assert_eq!(format!("{:#X}", srgb), "#0A141E");
+/// ```
+impl fmt::UpperHex for Srgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [r, g, b]: [u8; 3] = (*self).into();
+        write!(f, "{}{r:02X}{g:02X}{b:02X}", prefix(f))
+    }
+}
+
+/// Formats an sRGB color with an alpha channel as a lowercase `rrggbbaa` hexadecimal color.
+/// The alternate flag adds a leading `#`.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Alpha, Srgb };
+/// let srgba = Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap();
+/// assert_eq!(format!("{:x}", srgba), "0a141e80");
+/// assert_eq!(format!("{:#x}", srgba), "#0a141e80");
+/// ```
+impl fmt::LowerHex for Alpha<Srgb> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [r, g, b, a]: [u8; 4] = (*self).into();
+        write!(f, "{}{r:02x}{g:02x}{b:02x}{a:02x}", prefix(f))
+    }
+}
+
+/// Formats an sRGB color with an alpha channel as an uppercase `RRGGBBAA` hexadecimal color.
+/// The alternate flag adds a leading `#`.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Alpha, Srgb };
+/// let srgba = Alpha::new(Srgb::new(10, 20, 30), 0.5).unwrap();
+/// assert_eq!(format!("{:#X}", srgba), "#0A141E80");
+/// ```
+impl fmt::UpperHex for Alpha<Srgb> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [r, g, b, a]: [u8; 4] = (*self).into();
+        write!(f, "{}{r:02X}{g:02X}{b:02X}{a:02X}", prefix(f))
+    }
+}
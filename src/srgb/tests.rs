@@ -1,5 +1,12 @@
 use super::*;
-use crate::ciexyz::Ciexyz;
+use super::conversions::FROM_CIEXYZ_CONVERSION_MATRIX;
+use crate::{
+    ciexyz::Ciexyz,
+    error::ParseHexError,
+    matrix,
+    Cielch,
+    Srgba,
+};
 
 #[test]
 fn new() {
@@ -80,6 +87,16 @@ fn linear_blue() {
     assert_eq!(b.linear_blue(), 1.0);
 }
 
+#[test]
+fn relative_luminance() {
+    let a = Srgb(0.0, 0.0, 0.0);
+    assert_eq!(a.relative_luminance(), 0.0);
+    let b = Srgb(1.0, 1.0, 1.0);
+    assert_eq!(b.relative_luminance(), 1.0);
+    let c = Srgb(0.0, 1.0, 0.0);
+    assert_eq!(c.relative_luminance(), 0.7152);
+}
+
 #[test]
 fn from_ciexyz_precision() {
     let srgb_black = Srgb::new(0x00, 0x00, 0x00);
@@ -90,3 +107,63 @@ fn from_ciexyz_precision() {
     let ciexyz_white = crate::ciexyz::D65.try_into().unwrap();
     assert_eq!(srgb_white, ciexyz_white);
 }
+
+#[test]
+fn from_ciexyz_conversion_matrix() {
+    let derived = matrix::ciexyz_to_rgb([0.64, 0.33], [0.30, 0.60], [0.15, 0.06], [0.3127, 0.3290]).unwrap();
+    assert!((0..9).all(|i| (derived[i] - FROM_CIEXYZ_CONVERSION_MATRIX[i]).abs() < 1e-5));
+}
+
+#[test]
+fn from_cielch_reducing_chroma() {
+    let in_gamut = Srgb::new(200, 100, 50);
+    assert_eq!(Srgb::from_cielch_reducing_chroma(in_gamut.into()), in_gamut);
+    let out_of_gamut = Cielch::new(50.0, 150.0, 140f32.to_radians()).unwrap();
+    let mapped: Cielch = Srgb::from_cielch_reducing_chroma(out_of_gamut).into();
+    assert!((mapped.lightness() - 50.0).abs() < 0.5);
+    assert!((mapped.hue() - 140f32.to_radians()).abs() < 0.05);
+    assert!(mapped.chroma() < 150.0);
+}
+
+#[test]
+fn parse_hex() {
+    let cases = [
+        ("#0a141e", Srgb::new(10, 20, 30)),
+        ("0a141e", Srgb::new(10, 20, 30)),
+        ("#0A141E", Srgb::new(10, 20, 30)),
+        ("#fa3", Srgb::new(255, 170, 51)),
+        ("fa3f", Srgb::new(255, 170, 51)),
+        ("#0a141eff", Srgb::new(10, 20, 30)),
+    ];
+    for (hex, expected) in cases {
+        assert_eq!(hex.parse::<Srgb>(), Ok(expected), "{}", hex);
+    }
+    assert_eq!("".parse::<Srgb>(), Err(ParseHexError::InvalidLength(0)));
+    assert_eq!("#".parse::<Srgb>(), Err(ParseHexError::InvalidLength(0)));
+    assert_eq!("##fa3".parse::<Srgb>(), Err(ParseHexError::InvalidDigit('#')));
+    assert_eq!("#0a141e0".parse::<Srgb>(), Err(ParseHexError::InvalidLength(7)));
+    assert_eq!("#fa3".parse::<Srgb>(), Ok(Srgb::new(255, 170, 51)));
+    assert_eq!("#f a".parse::<Srgb>(), Err(ParseHexError::InvalidDigit(' ')));
+    assert_eq!("#éa3".parse::<Srgb>(), Err(ParseHexError::InvalidDigit('é')));
+    assert_eq!("#fa38".parse::<Srgb>(), Err(ParseHexError::Translucent));
+
+    let srgba: Srgba = "#fa38".parse().unwrap();
+    assert_eq!(<[u8; 4]>::from(srgba), [255, 170, 51, 136]);
+    let srgba: Srgba = "0a141e00".parse().unwrap();
+    assert_eq!(<[u8; 4]>::from(srgba), [10, 20, 30, 0]);
+}
+
+#[test]
+fn format_hex() {
+    let srgb = Srgb::new(171, 205, 239);
+    assert_eq!(format!("{:x}", srgb), "abcdef");
+    assert_eq!(format!("{:#X}", srgb), "#ABCDEF");
+    let srgba = Srgba::from([171, 205, 239, 1]);
+    assert_eq!(format!("{:#x}", srgba), "#abcdef01");
+    assert_eq!(format!("{:X}", srgba), "ABCDEF01");
+    //Formatting and parsing round trip.
+    for rgba in [[0, 0, 0, 0], [1, 2, 3, 4], [255, 128, 7, 255]] {
+        let srgba = Srgba::from(rgba);
+        assert_eq!(format!("{:x}", srgba).parse::<Srgba>().map(<[u8; 4]>::from), Ok(rgba));
+    }
+}
@@ -0,0 +1,409 @@
+//! X11 color specification strings, as parsed by `XParseColor` and the Xcms color management
+//! system, and answered by terminal emulators to OSC color queries.
+//!
+//! The device-independent formats (`CIEXYZ:`, `CIExyY:`, `CIELab:`, `CIELuv:`, `TekHVC:`) are
+//! taken as relative to D65, the white point of sRGB screens.
+//!
+//! # Examples
+//!
+//! ```
+//! use cowor::{ x11::XColorSpec, Srgb };
+//! let spec: XColorSpec = "rgb:ffff/8080/0000".parse().unwrap();
+//! assert_eq!(Srgb::try_from(spec).unwrap(), Srgb::new(255, 128, 0));
+//! assert_eq!(XColorSpec::from(Srgb::new(10, 20, 30)).to_string(), "rgb:0a0a/1414/1e1e");
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fmt,
+    str::FromStr,
+};
+use crate::{
+    error::{ OutOfGamut, ParseXColorError },
+    illuminant::{ Illuminant, D65 },
+    Cielab,
+    Ciexyz,
+    Srgb,
+};
+
+//Chromaticity of the best red of TekHVC, in the CIE 1976 u'v' diagram.
+const TEKHVC_RED: [f32; 2] = [0.7127f32, 0.4931f32];
+
+//Scale factor of the chroma of TekHVC.
+const TEKHVC_CHROMA_SCALE: f32 = 7.50725f32;
+
+/// A color given by an X11 color specification, in the format it was written in.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum XColorSpec {
+    /// `rgb:<red>/<green>/<blue>`, with 1 to 4 hexadecimal digits per channel.
+    Rgb(Srgb),
+    /// `rgbi:<red>/<green>/<blue>`, with intensities between 0 and 1.
+    RgbIntensity(Srgb),
+    /// `CIEXYZ:<X>/<Y>/<Z>`.
+    Ciexyz(Ciexyz),
+    /// `CIExyY:<x>/<y>/<Y>`, held as CIEXYZ.
+    CiexyY(Ciexyz),
+    /// `CIELab:<L>/<a>/<b>`.
+    Cielab(Cielab),
+    /// `CIELuv:<L>/<u>/<v>`, held as CIEXYZ.
+    Cieluv(Ciexyz),
+    /// `TekHVC:<H>/<V>/<C>`, with the hue in degrees, held as CIEXYZ.
+    TekHvc(Ciexyz),
+}
+
+impl XColorSpec {
+    /// Formats an sRGB color as an `rgb:` specification with a given number of hexadecimal
+    /// digits per channel. The number of digits must be between 1 and 4 included otherwise the
+    /// return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::{ x11::XColorSpec, Srgb };
+    /// assert_eq!(XColorSpec::rgb_with_digits(Srgb::new(255, 128, 0), 2), Some(String::from("rgb:ff/80/00")));
+    /// assert_eq!(XColorSpec::rgb_with_digits(Srgb::new(255, 128, 0), 1), Some(String::from("rgb:f/8/0")));
+    /// assert_eq!(XColorSpec::rgb_with_digits(Srgb::new(255, 128, 0), 5), None);
+    /// ```
+    pub fn rgb_with_digits(srgb: Srgb, digits: usize) -> Option<String> {
+        if !(1..=4).contains(&digits) {
+            return None;
+        }
+        let maximum = (1u32 << (4 * digits)) - 1;
+        let channels: [f32; 3] = srgb.into();
+        let [r, g, b] = channels.map(|x| format!("{:0digits$x}", (x * maximum as f32).round() as u32));
+        Some(format!("rgb:{r}/{g}/{b}"))
+    }
+}
+
+/// Parses an X11 color specification: `rgb:`, `rgbi:`, `CIEXYZ:`, `CIExyY:`, `CIELab:`,
+/// `CIELuv:` or `TekHVC:` followed by three components separated by slashes.
+/// The prefix is case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ error::ParseXColorError, x11::XColorSpec, Srgb };
+/// let spec: XColorSpec = "rgb:f/80/000".parse().unwrap();
+/// assert_eq!(Srgb::try_from(spec).unwrap(), Srgb::new(255, 128, 0));
+/// assert!("rgbi:1/0.5/0".parse::<XColorSpec>().is_ok());
+/// assert!("CIELab:50/20/-30".parse::<XColorSpec>().is_ok());
+/// assert!("TekHVC:120/50/30".parse::<XColorSpec>().is_ok());
+/// assert_eq!("hsv:1/2/3".parse::<XColorSpec>(), Err(ParseXColorError::UnknownFormat(String::from("hsv"))));
+/// ```
+impl FromStr for XColorSpec {
+    type Err = ParseXColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (prefix, components) = s.trim().split_once(':').ok_or(ParseXColorError::InvalidSyntax)?;
+        let components: Vec<&str> = components.split('/').collect();
+        let [first, second, third] = components[..] else {
+            return Err(ParseXColorError::InvalidSyntax);
+        };
+        let prefix = prefix.to_ascii_lowercase();
+        if prefix == "rgb" {
+            return Ok(XColorSpec::Rgb(Srgb::from([hex(first)?, hex(second)?, hex(third)?])));
+        }
+        let values = [number(first)?, number(second)?, number(third)?];
+        let spec = match prefix.as_str() {
+            "rgbi"      => match values.iter().all(|x| (0f32..=1f32).contains(x)) {
+                true    => Some(XColorSpec::RgbIntensity(Srgb::from(values))),
+                false   => None,
+            },
+            "ciexyz"    => Ciexyz::try_from(values).ok().map(XColorSpec::Ciexyz),
+            "ciexyy"    => from_xyy(values).map(XColorSpec::CiexyY),
+            "cielab"    => Cielab::try_from(values).ok().map(XColorSpec::Cielab),
+            "cieluv"    => from_luv(values).map(XColorSpec::Cieluv),
+            "tekhvc"    => from_tekhvc(values).map(XColorSpec::TekHvc),
+            _           => return Err(ParseXColorError::UnknownFormat(prefix)),
+        };
+        spec.ok_or(ParseXColorError::OutOfSpecification)
+    }
+}
+
+/// Formats an X11 color specification in its format. `rgb:` specifications have 4 hexadecimal
+/// digits per channel, the most common form in terminal emulators' answers.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ x11::XColorSpec, Cielab, Srgb };
+/// assert_eq!(XColorSpec::Rgb(Srgb::new(255, 128, 0)).to_string(), "rgb:ffff/8080/0000");
+/// assert_eq!(XColorSpec::RgbIntensity(Srgb::new(255, 0, 0)).to_string(), "rgbi:1/0/0");
+/// assert_eq!(XColorSpec::Cielab(Cielab::new(50.0, 20.0, -30.0).unwrap()).to_string(), "CIELab:50/20/-30");
+/// ```
+impl fmt::Display for XColorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (prefix, values): (&str, [f32; 3]) = match *self {
+            XColorSpec::Rgb(srgb)           => return write!(f, "{}", Self::rgb_with_digits(srgb, 4).unwrap()),
+            XColorSpec::RgbIntensity(srgb)  => ("rgbi", srgb.into()),
+            XColorSpec::Ciexyz(ciexyz)      => ("CIEXYZ", ciexyz.into()),
+            XColorSpec::CiexyY(ciexyz)      => ("CIExyY", to_xyy(ciexyz)),
+            XColorSpec::Cielab(cielab)      => ("CIELab", cielab.into()),
+            XColorSpec::Cieluv(ciexyz)      => ("CIELuv", to_luv(ciexyz)),
+            XColorSpec::TekHvc(ciexyz)      => ("TekHVC", to_tekhvc(ciexyz)),
+        };
+        let [first, second, third] = values.map(|x| (x * 1e5f32).round() / 1e5f32 + 0f32);
+        write!(f, "{prefix}:{first}/{second}/{third}")
+    }
+}
+
+/// Conversion from an X11 color specification to CIEXYZ.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ x11::XColorSpec, Ciexyz };
+/// let ciexyz: Ciexyz = "CIExyY:0.3127/0.329/1".parse::<XColorSpec>().unwrap().into();
+/// assert!((ciexyz.x() - 0.9505).abs() < 1e-3);
+/// ```
+impl From<XColorSpec> for Ciexyz {
+    fn from(spec: XColorSpec) -> Self {
+        match spec {
+            XColorSpec::Rgb(srgb) | XColorSpec::RgbIntensity(srgb)  => srgb.into(),
+            XColorSpec::Cielab(cielab)                              => cielab.into(),
+            XColorSpec::Ciexyz(ciexyz)
+            | XColorSpec::CiexyY(ciexyz)
+            | XColorSpec::Cieluv(ciexyz)
+            | XColorSpec::TekHvc(ciexyz)                            => ciexyz,
+        }
+    }
+}
+
+/// Conversion from an X11 color specification to CIELAB.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ x11::XColorSpec, Cielab };
+/// let cielab: Cielab = "rgb:ffff/ffff/ffff".parse::<XColorSpec>().unwrap().into();
+/// assert!((cielab.lightness() - 100.0).abs() < 1e-3);
+/// ```
+impl From<XColorSpec> for Cielab {
+    fn from(spec: XColorSpec) -> Self {
+        match spec {
+            XColorSpec::Cielab(cielab)  => cielab,
+            _                           => Ciexyz::from(spec).into(),
+        }
+    }
+}
+
+/// Conversion from an X11 color specification to sRGB.
+/// The conversion may fail and return an OutOfGamut error
+/// if the color falls outside of the sRGB gamut.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ x11::XColorSpec, Srgb };
+/// let spec: XColorSpec = "rgbi:0/0.5/1".parse().unwrap();
+/// assert_eq!(Srgb::try_from(spec).unwrap(), Srgb::new(0, 128, 255));
+/// let spec: XColorSpec = "CIEXYZ:0/1/0".parse().unwrap();
+/// assert!(Srgb::try_from(spec).is_err());
+/// ```
+impl TryFrom<XColorSpec> for Srgb {
+    type Error = OutOfGamut;
+
+    fn try_from(spec: XColorSpec) -> Result<Self, Self::Error> {
+        match spec {
+            XColorSpec::Rgb(srgb) | XColorSpec::RgbIntensity(srgb)  => Ok(srgb),
+            _                                                       => Ciexyz::from(spec).try_into(),
+        }
+    }
+}
+
+/// Conversion from sRGB to an `rgb:` X11 color specification.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ x11::XColorSpec, Srgb };
+/// assert_eq!(XColorSpec::from(Srgb::new(255, 0, 0)), XColorSpec::Rgb(Srgb::new(255, 0, 0)));
+/// ```
+impl From<Srgb> for XColorSpec {
+    fn from(srgb: Srgb) -> Self {
+        XColorSpec::Rgb(srgb)
+    }
+}
+
+/// Conversion from CIEXYZ to a `CIEXYZ:` X11 color specification.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ x11::XColorSpec, Ciexyz };
+/// let spec = XColorSpec::from(Ciexyz::new(0.1, 0.2, 0.3).unwrap());
+/// assert_eq!(spec.to_string(), "CIEXYZ:0.1/0.2/0.3");
+/// ```
+impl From<Ciexyz> for XColorSpec {
+    fn from(ciexyz: Ciexyz) -> Self {
+        XColorSpec::Ciexyz(ciexyz)
+    }
+}
+
+/// Conversion from CIELAB to a `CIELab:` X11 color specification.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ x11::XColorSpec, Cielab };
+/// let spec = XColorSpec::from(Cielab::new(10.0, 20.0, 30.0).unwrap());
+/// assert_eq!(spec.to_string(), "CIELab:10/20/30");
+/// ```
+impl From<Cielab> for XColorSpec {
+    fn from(cielab: Cielab) -> Self {
+        XColorSpec::Cielab(cielab)
+    }
+}
+
+//Parses a channel of an `rgb:` specification, 1 to 4 hexadecimal digits, into a value between 0
+//and 1.
+fn hex(channel: &str) -> Result<f32, ParseXColorError> {
+    let invalid = || ParseXColorError::InvalidComponent(channel.to_string());
+    if !(1..=4).contains(&channel.len()) || !channel.chars().all(|x| x.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let value = u32::from_str_radix(channel, 16).map_err(|_| invalid())?;
+    Ok(value as f32 / ((1u32 << (4 * channel.len())) - 1) as f32)
+}
+
+//Parses a floating point component.
+fn number(component: &str) -> Result<f32, ParseXColorError> {
+    match component.parse::<f32>() {
+        Ok(x) if x.is_finite()  => Ok(x),
+        _                       => Err(ParseXColorError::InvalidComponent(component.to_string())),
+    }
+}
+
+//CIE 1976 u'v' chromaticity of CIEXYZ values. Black has the chromaticity of the white point.
+fn uv_prime(xyz: [f32; 3]) -> [f32; 2] {
+    let [x, y, z] = xyz;
+    let denominator = x + 15f32 * y + 3f32 * z;
+    match denominator {
+        0f32    => uv_prime(D65::WHITE_POINT.into()),
+        _       => [4f32 * x / denominator, 9f32 * y / denominator],
+    }
+}
+
+//CIEXYZ color from a luminance and a u'v' chromaticity, None if it falls outside of the CIEXYZ
+//specification.
+fn from_uv_prime(y: f32, uv: [f32; 2]) -> Option<Ciexyz> {
+    let [u, v] = uv;
+    match v {
+        0f32    => None,
+        _       => Ciexyz::new(y * 9f32 * u / (4f32 * v), y, y * (12f32 - 3f32 * u - 20f32 * v) / (4f32 * v)),
+    }
+}
+
+//Conversion of CIEXYZ to xyY. Black has the chromaticity of the white point.
+fn to_xyy(ciexyz: Ciexyz) -> [f32; 3] {
+    let [x, y, z]: [f32; 3] = ciexyz.into();
+    match x + y + z {
+        0f32    => {
+            let [wx, wy, wz]: [f32; 3] = D65::WHITE_POINT.into();
+            [wx / (wx + wy + wz), wy / (wx + wy + wz), 0f32]
+        },
+        sum     => [x / sum, y / sum, y],
+    }
+}
+
+//Conversion of xyY to CIEXYZ, None if it falls outside of the CIEXYZ specification.
+fn from_xyy(xyy: [f32; 3]) -> Option<Ciexyz> {
+    let [x, y, luminance] = xyy;
+    match (y, luminance) {
+        (_, 0f32)   => Ciexyz::new(0f32, 0f32, 0f32),
+        (0f32, _)   => None,
+        _           => Ciexyz::new(x * luminance / y, luminance, (1f32 - x - y) * luminance / y),
+    }
+}
+
+//CIE lightness of a relative luminance.
+fn lightness(y: f32) -> f32 {
+    match y > 216f32 / 24389f32 {
+        true    => 116f32 * y.cbrt() - 16f32,
+        false   => y * 24389f32 / 27f32,
+    }
+}
+
+//Relative luminance of a CIE lightness.
+fn luminance(l: f32) -> f32 {
+    match l > 8f32 {
+        true    => ((l + 16f32) / 116f32).powi(3),
+        false   => l * 27f32 / 24389f32,
+    }
+}
+
+//Offset of the u'v' chromaticity of CIEXYZ values from the one of the white point. Offsets only
+//coming from rounding errors are taken as 0, so that grays have no chroma.
+fn uv_offset(xyz: [f32; 3]) -> [f32; 2] {
+    let [u, v] = uv_prime(xyz);
+    let [un, vn] = uv_prime(D65::WHITE_POINT.into());
+    match f32::hypot(u - un, v - vn) < 1e-6f32 {
+        true    => [0f32, 0f32],
+        false   => [u - un, v - vn],
+    }
+}
+
+//Conversion of CIEXYZ to CIELUV.
+fn to_luv(ciexyz: Ciexyz) -> [f32; 3] {
+    let [du, dv] = uv_offset(ciexyz.into());
+    let l = lightness(ciexyz.y());
+    [l, 13f32 * l * du, 13f32 * l * dv]
+}
+
+//Conversion of CIELUV to CIEXYZ, None if it falls outside of the CIEXYZ specification.
+fn from_luv(luv: [f32; 3]) -> Option<Ciexyz> {
+    let [l, u, v] = luv;
+    let [un, vn] = uv_prime(D65::WHITE_POINT.into());
+    match l {
+        0f32                                => Ciexyz::new(0f32, 0f32, 0f32),
+        _ if (0f32..=100f32).contains(&l)   => from_uv_prime(luminance(l), [u / (13f32 * l) + un, v / (13f32 * l) + vn]),
+        _                                   => None,
+    }
+}
+
+//Angle in degrees between the best red of TekHVC and the u' axis, seen from the white point.
+fn tekhvc_hue_offset() -> f32 {
+    let [un, vn] = uv_prime(D65::WHITE_POINT.into());
+    f32::atan2(TEKHVC_RED[1] - vn, TEKHVC_RED[0] - un).to_degrees()
+}
+
+//Value of TekHVC of a relative luminance.
+fn tekhvc_value(y: f32) -> f32 {
+    match y < 0.008856f32 {
+        true    => y * 903.29f32,
+        false   => 116f32 * y.cbrt() - 16f32,
+    }
+}
+
+//Conversion of CIEXYZ to TekHVC. The hue of grays is 0.
+fn to_tekhvc(ciexyz: Ciexyz) -> [f32; 3] {
+    let [du, dv] = uv_offset(ciexyz.into());
+    let value = tekhvc_value(ciexyz.y());
+    let chroma = f32::hypot(du, dv) * value * TEKHVC_CHROMA_SCALE;
+    let hue = match [du, dv] {
+        [0f32, 0f32]    => 0f32,
+        _               => (f32::atan2(dv, du).to_degrees() - tekhvc_hue_offset()).rem_euclid(360f32),
+    };
+    [hue, value, chroma]
+}
+
+//Conversion of TekHVC to CIEXYZ, None if it falls outside of the CIEXYZ specification.
+fn from_tekhvc(hvc: [f32; 3]) -> Option<Ciexyz> {
+    let [hue, value, chroma] = hvc;
+    if !(0f32..=100f32).contains(&value) || chroma < 0f32 {
+        return None;
+    }
+    if value == 0f32 {
+        return Ciexyz::new(0f32, 0f32, 0f32);
+    }
+    let y = match value < 7.999_536f32 {
+        true    => value / 903.29f32,
+        false   => ((value + 16f32) / 116f32).powi(3),
+    };
+    let [un, vn] = uv_prime(D65::WHITE_POINT.into());
+    let angle = (hue + tekhvc_hue_offset()).to_radians();
+    let distance = chroma / (value * TEKHVC_CHROMA_SCALE);
+    from_uv_prime(y, [un + distance * angle.cos(), vn + distance * angle.sin()])
+}
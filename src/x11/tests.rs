@@ -0,0 +1,111 @@
+use super::*;
+
+fn parse(spec: &str) -> XColorSpec {
+    spec.parse().unwrap()
+}
+
+fn close(a: [f32; 3], b: [f32; 3], tolerance: f32) -> bool {
+    (0..3).all(|i| (a[i] - b[i]).abs() < tolerance)
+}
+
+#[test]
+fn rgb() {
+    for spec in ["rgb:f/8/0", "rgb:ff/88/00", "rgb:fff/888/000", "rgb:ffff/8888/0000", "RGB:FFFF/8888/0", "rgb:f/88/000"] {
+        assert_eq!(parse(spec), XColorSpec::Rgb(Srgb::new(255, 136, 0)), "{spec}");
+    }
+    let XColorSpec::Rgb(srgb) = parse("rgb:8000/0/1") else { panic!() };
+    assert!((srgb.red() - 32768.0 / 65535.0).abs() < 1e-7);
+    assert!((srgb.blue() - 1.0 / 15.0).abs() < 1e-7);
+    assert_eq!(parse("rgb:ffff/8080/0000").to_string(), "rgb:ffff/8080/0000");
+    assert_eq!(XColorSpec::rgb_with_digits(Srgb::new(10, 20, 30), 3), Some(String::from("rgb:0a1/141/1e2")));
+    assert_eq!(XColorSpec::rgb_with_digits(Srgb::new(10, 20, 30), 0), None);
+}
+
+#[test]
+fn rgb_intensity() {
+    assert_eq!(parse("rgbi:1/0.5/0"), XColorSpec::RgbIntensity(Srgb::new(255, 128, 0)));
+    assert_eq!(parse("RGBi:1.0/5e-1/0").to_string(), "rgbi:1/0.5/0");
+    assert_eq!("rgbi:1.5/0/0".parse::<XColorSpec>(), Err(ParseXColorError::OutOfSpecification));
+}
+
+#[test]
+fn device_independent() {
+    let red: Ciexyz = Srgb::new(255, 0, 0).into();
+    let ciexyz = Ciexyz::from(parse("CIEXYZ:0.41239/0.21264/0.01933"));
+    assert!(close(ciexyz.into(), red.into(), 1e-4));
+    let ciexyz = Ciexyz::from(parse("CIExyY:0.64/0.33/0.21264"));
+    assert!(close(ciexyz.into(), red.into(), 1e-4));
+    let ciexyz = Ciexyz::from(parse("CIELuv:53.2329/175.0151/37.7564"));
+    assert!(close(ciexyz.into(), red.into(), 1e-4));
+    let ciexyz = Ciexyz::from(parse("CIELab:53.2408/80.0925/67.2032"));
+    assert!(close(ciexyz.into(), red.into(), 1e-4));
+    assert_eq!(Srgb::try_from(parse("CIELab:50/0/0")).unwrap(), Srgb::new(119, 119, 119));
+    assert_eq!(Ciexyz::from(parse("CIExyY:0.2/0/0")), Ciexyz::new(0.0, 0.0, 0.0).unwrap());
+    assert_eq!(Ciexyz::from(parse("CIELuv:0/10/10")), Ciexyz::new(0.0, 0.0, 0.0).unwrap());
+}
+
+#[test]
+fn tekhvc() {
+    //White and grays have no chroma.
+    let [_, value, chroma] = to_tekhvc(D65::WHITE_POINT);
+    assert!((value - 100.0).abs() < 1e-3 && chroma.abs() < 1e-3);
+    let gray = from_tekhvc([0.0, 50.0, 0.0]).unwrap();
+    assert!((lightness(gray.y()) - 50.0).abs() < 1e-3);
+    //The hue of the best red of TekHVC is 0.
+    let [un, vn] = uv_prime(D65::WHITE_POINT.into());
+    let red = from_uv_prime(0.2, [un + (TEKHVC_RED[0] - un) * 0.5, vn + (TEKHVC_RED[1] - vn) * 0.5]).unwrap();
+    let [hue, _, _] = to_tekhvc(red);
+    assert!(hue.abs() < 1e-2 || (hue - 360.0).abs() < 1e-2);
+    for srgb in [Srgb::new(255, 0, 0), Srgb::new(10, 200, 30), Srgb::new(40, 20, 250), Srgb::new(1, 2, 3)] {
+        let spec = XColorSpec::TekHvc(srgb.into());
+        assert_eq!(Srgb::try_from(parse(&spec.to_string())).unwrap(), srgb);
+    }
+    assert_eq!("TekHVC:0/101/0".parse::<XColorSpec>(), Err(ParseXColorError::OutOfSpecification));
+    assert_eq!("TekHVC:0/50/-1".parse::<XColorSpec>(), Err(ParseXColorError::OutOfSpecification));
+}
+
+#[test]
+fn grays() {
+    for srgb in [Srgb::new(255, 255, 255), Srgb::new(128, 128, 128), Srgb::new(3, 3, 3)] {
+        let tekhvc = XColorSpec::TekHvc(srgb.into()).to_string();
+        assert!(tekhvc.starts_with("TekHVC:0/") && tekhvc.ends_with("/0"), "{tekhvc}");
+        let cieluv = XColorSpec::Cieluv(srgb.into()).to_string();
+        assert!(cieluv.ends_with("/0/0"), "{cieluv}");
+    }
+    assert_eq!(XColorSpec::TekHvc(Srgb::new(255, 255, 255).into()).to_string(), "TekHVC:0/100/0");
+}
+
+#[test]
+fn round_trip() {
+    let ciexyz: Ciexyz = Srgb::new(200, 100, 50).into();
+    for spec in [
+        XColorSpec::Rgb(Srgb::new(200, 100, 50)),
+        XColorSpec::RgbIntensity(Srgb::new(200, 100, 50)),
+        XColorSpec::Ciexyz(ciexyz),
+        XColorSpec::CiexyY(ciexyz),
+        XColorSpec::Cielab(ciexyz.into()),
+        XColorSpec::Cieluv(ciexyz),
+        XColorSpec::TekHvc(ciexyz),
+    ] {
+        let parsed = parse(&spec.to_string());
+        assert!(close(Ciexyz::from(parsed).into(), ciexyz.into(), 1e-4), "{spec}");
+        assert_eq!(std::mem::discriminant(&parsed), std::mem::discriminant(&spec));
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!("#ff8000".parse::<XColorSpec>(), Err(ParseXColorError::InvalidSyntax));
+    assert_eq!("rgb:ff/80".parse::<XColorSpec>(), Err(ParseXColorError::InvalidSyntax));
+    assert_eq!("rgb:ff/80/00/00".parse::<XColorSpec>(), Err(ParseXColorError::InvalidSyntax));
+    assert_eq!("rgb:fffff/80/00".parse::<XColorSpec>(), Err(ParseXColorError::InvalidComponent(String::from("fffff"))));
+    assert_eq!("rgb:ff/8g/00".parse::<XColorSpec>(), Err(ParseXColorError::InvalidComponent(String::from("8g"))));
+    assert_eq!("rgb:ff//00".parse::<XColorSpec>(), Err(ParseXColorError::InvalidComponent(String::new())));
+    assert_eq!("rgb:+f/0/0".parse::<XColorSpec>(), Err(ParseXColorError::InvalidComponent(String::from("+f"))));
+    assert_eq!("rgb:+ff/0/0".parse::<XColorSpec>(), Err(ParseXColorError::InvalidComponent(String::from("+ff"))));
+    assert_eq!("rgb:-f/0/0".parse::<XColorSpec>(), Err(ParseXColorError::InvalidComponent(String::from("-f"))));
+    assert_eq!("CIELab:50/x/0".parse::<XColorSpec>(), Err(ParseXColorError::InvalidComponent(String::from("x"))));
+    assert_eq!("CIELab:150/0/0".parse::<XColorSpec>(), Err(ParseXColorError::OutOfSpecification));
+    assert_eq!("CIEXYZ:0.5/1.5/0.5".parse::<XColorSpec>(), Err(ParseXColorError::OutOfSpecification));
+    assert_eq!("CIEuvY:0.2/0.4/0.5".parse::<XColorSpec>(), Err(ParseXColorError::UnknownFormat(String::from("cieuvy"))));
+}
@@ -0,0 +1,150 @@
+mod conversions;
+#[cfg(test)]
+mod tests;
+
+/// A struct representing a color in the YCoCg color space.
+///
+/// YCoCg is a simple linear transform of sRGB into a luma component and two chrominance
+/// components (orange and green), mostly used to decorrelate channels before compression.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ycocg(f32, f32, f32); // y, co, cg
+
+/// A struct representing a color with the reversible YCoCg-R transform.
+///
+/// YCoCg-R is the lossless integer variant of YCoCg. Any 8 bit sRGB color converted to
+/// YCoCg-R and back is guaranteed to be exactly the same color.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct YcocgR(u8, i16, i16); // y, co, cg
+
+impl Ycocg {
+    /// Creates a Ycocg instance from 3 float
+    /// representing the luma, orange chrominance and green chrominance components.
+    ///
+    /// The luma component must be between 0 and 1 included and both chrominance components must
+    /// be between -0.5 and 0.5 included otherwise it doesn't represent a valid YCoCg color
+    /// and the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Ycocg;
+    /// let valid = Ycocg::new(0.5, 0.1, -0.2); // Ok.
+    /// let invalid = Ycocg::new(0.5, 0.6, -0.2); // Not ok. The orange chrominance is greater than 0.5.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn new(y: f32, co: f32, cg: f32) -> Option<Self> {
+        match (0f32..=1f32).contains(&y) && (-0.5f32..=0.5f32).contains(&co) && (-0.5f32..=0.5f32).contains(&cg) {
+            true    => Some(Self(y, co, cg)),
+            false   => None,
+        }
+    }
+
+    /// Luma component of a YCoCg color.
+    /// Value is between 0 and 1 included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Ycocg;
+    /// let ycocg = Ycocg::new(0.5, 0.1, -0.2).unwrap();
+    /// assert_eq!(ycocg.y(), 0.5);
+    /// ```
+    pub fn y(&self) -> f32 {
+        self.0
+    }
+
+    /// Orange chrominance component of a YCoCg color.
+    /// Value is between -0.5 and 0.5 included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Ycocg;
+    /// let ycocg = Ycocg::new(0.5, 0.1, -0.2).unwrap();
+    /// assert_eq!(ycocg.co(), 0.1);
+    /// ```
+    pub fn co(&self) -> f32 {
+        self.1
+    }
+
+    /// Green chrominance component of a YCoCg color.
+    /// Value is between -0.5 and 0.5 included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::Ycocg;
+    /// let ycocg = Ycocg::new(0.5, 0.1, -0.2).unwrap();
+    /// assert_eq!(ycocg.cg(), -0.2);
+    /// ```
+    pub fn cg(&self) -> f32 {
+        self.2
+    }
+}
+
+impl YcocgR {
+    /// Creates a YcocgR instance from the luma, orange chrominance and green chrominance
+    /// components.
+    ///
+    /// Both chrominance components must be between -255 and 255 included
+    /// otherwise it doesn't represent a valid YCoCg-R color
+    /// and the return value is None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::YcocgR;
+    /// let valid = YcocgR::new(128, 10, -20); // Ok.
+    /// let invalid = YcocgR::new(128, 300, -20); // Not ok. The orange chrominance is greater than 255.
+    /// assert!(valid.is_some());
+    /// assert!(invalid.is_none());
+    /// ```
+    pub fn new(y: u8, co: i16, cg: i16) -> Option<Self> {
+        match (-255i16..=255i16).contains(&co) && (-255i16..=255i16).contains(&cg) {
+            true    => Some(Self(y, co, cg)),
+            false   => None,
+        }
+    }
+
+    /// Luma component of a YCoCg-R color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::YcocgR;
+    /// let ycocg_r = YcocgR::new(128, 10, -20).unwrap();
+    /// assert_eq!(ycocg_r.y(), 128);
+    /// ```
+    pub fn y(&self) -> u8 {
+        self.0
+    }
+
+    /// Orange chrominance component of a YCoCg-R color.
+    /// Value is between -255 and 255 included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::YcocgR;
+    /// let ycocg_r = YcocgR::new(128, 10, -20).unwrap();
+    /// assert_eq!(ycocg_r.co(), 10);
+    /// ```
+    pub fn co(&self) -> i16 {
+        self.1
+    }
+
+    /// Green chrominance component of a YCoCg-R color.
+    /// Value is between -255 and 255 included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cowor::YcocgR;
+    /// let ycocg_r = YcocgR::new(128, 10, -20).unwrap();
+    /// assert_eq!(ycocg_r.cg(), -20);
+    /// ```
+    pub fn cg(&self) -> i16 {
+        self.2
+    }
+}
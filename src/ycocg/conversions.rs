@@ -0,0 +1,121 @@
+use crate::{
+    error::OutOfSpecification,
+    Srgb,
+};
+use super::{
+    Ycocg,
+    YcocgR,
+};
+
+/// Conversion from sRGB to YCoCg.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Srgb, Ycocg };
+/// let ycocg: Ycocg = Srgb::new(10, 20, 30).into();
+/// ```
+impl From<Srgb> for Ycocg {
+    fn from(srgb: Srgb) -> Self {
+        let [r, g, b]: [f32; 3] = srgb.into();
+        let y = r / 4f32 + g / 2f32 + b / 4f32;
+        let co = r / 2f32 - b / 2f32;
+        let cg = -r / 4f32 + g / 2f32 - b / 4f32;
+        Self(y, co, cg)
+    }
+}
+
+/// New YCoCg color from an array of 3 floats.
+/// Convertion may fail if the resulting color would fall outside
+/// the YCoCg specification. The error type in that case is an
+/// OutOfSpecification error.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Ycocg;
+/// let ycocg: Ycocg = [0.5, 0.1, -0.2].try_into().unwrap();
+/// ```
+impl TryFrom<[f32; 3]> for Ycocg {
+    type Error = OutOfSpecification;
+
+    fn try_from(array: [f32; 3]) -> Result<Self, Self::Error> {
+        let [y, co, cg] = array;
+        Self::new(y, co, cg).ok_or(OutOfSpecification)
+    }
+}
+
+/// Convert a YCoCg color into an array of 3 floats.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::Ycocg;
+/// let [y, co, cg]: [f32; 3] = Ycocg::new(0.5, 0.1, -0.2).unwrap().into();
+/// ```
+impl From<Ycocg> for [f32; 3] {
+    fn from(ycocg: Ycocg) -> Self {
+        let Ycocg(y, co, cg) = ycocg;
+        [y, co, cg]
+    }
+}
+
+/// Conversion from sRGB to YCoCg-R.
+/// The conversion is lossless, converting the result back to sRGB always gives the
+/// starting color.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::{ Srgb, YcocgR };
+/// let srgb = Srgb::new(10, 20, 30);
+/// let ycocg_r: YcocgR = srgb.into();
+/// assert_eq!(Srgb::try_from(ycocg_r).unwrap(), srgb);
+/// ```
+impl From<Srgb> for YcocgR {
+    fn from(srgb: Srgb) -> Self {
+        let [r, g, b]: [u8; 3] = srgb.into();
+        let [r, g, b] = [r, g, b].map(i16::from);
+        let co = r - b;
+        let t = b + (co >> 1);
+        let cg = g - t;
+        let y = t + (cg >> 1);
+        Self(y as u8, co, cg)
+    }
+}
+
+/// New YCoCg-R color from an array of 3 signed 16 bit integers.
+/// Convertion may fail if the resulting color would fall outside
+/// the YCoCg-R specification. The error type in that case is an
+/// OutOfSpecification error.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::YcocgR;
+/// let ycocg_r: YcocgR = [128, 10, -20].try_into().unwrap();
+/// ```
+impl TryFrom<[i16; 3]> for YcocgR {
+    type Error = OutOfSpecification;
+
+    fn try_from(array: [i16; 3]) -> Result<Self, Self::Error> {
+        let [y, co, cg] = array;
+        let y = u8::try_from(y).map_err(|_| OutOfSpecification)?;
+        Self::new(y, co, cg).ok_or(OutOfSpecification)
+    }
+}
+
+/// Convert a YCoCg-R color into an array of 3 signed 16 bit integers.
+///
+/// # Examples
+///
+/// ```
+/// use cowor::YcocgR;
+/// let [y, co, cg]: [i16; 3] = YcocgR::new(128, 10, -20).unwrap().into();
+/// ```
+impl From<YcocgR> for [i16; 3] {
+    fn from(ycocg_r: YcocgR) -> Self {
+        let YcocgR(y, co, cg) = ycocg_r;
+        [y.into(), co, cg]
+    }
+}
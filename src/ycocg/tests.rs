@@ -0,0 +1,63 @@
+use super::*;
+use crate::Srgb;
+
+#[test]
+fn new() {
+    let a = Ycocg(0.5, 0.1, -0.2);
+    let b = Ycocg::new(0.5, 0.1, -0.2).unwrap();
+    let c = Ycocg::new(1.5, 0.1, -0.2);
+    let d = Ycocg::new(0.5, 0.1, -0.6);
+    assert_eq!(a, b);
+    assert!(c.is_none());
+    assert!(d.is_none());
+}
+
+#[test]
+fn new_r() {
+    let a = YcocgR(128, 10, -20);
+    let b = YcocgR::new(128, 10, -20).unwrap();
+    let c = YcocgR::new(128, -256, -20);
+    let d = YcocgR::new(128, 10, 256);
+    assert_eq!(a, b);
+    assert!(c.is_none());
+    assert!(d.is_none());
+}
+
+#[test]
+fn from_srgb() {
+    let white: Ycocg = Srgb::new(255, 255, 255).into();
+    assert_eq!(white, Ycocg(1.0, 0.0, 0.0));
+    let green: Ycocg = Srgb::new(0, 255, 0).into();
+    assert_eq!(green, Ycocg(0.5, 0.0, 0.5));
+    let white_r: YcocgR = Srgb::new(255, 255, 255).into();
+    assert_eq!(white_r, YcocgR(255, 0, 0));
+    let red_r: YcocgR = Srgb::new(255, 0, 0).into();
+    assert_eq!(red_r, YcocgR(63, 255, -127));
+}
+
+#[test]
+fn srgb_round_trip() {
+    for r in (0..=255).step_by(3) {
+        for g in (0..=255).step_by(3) {
+            for b in (0..=255).step_by(3) {
+                let srgb = Srgb::new(r, g, b);
+                assert_eq!(Srgb::try_from(Ycocg::from(srgb)).unwrap(), srgb);
+            }
+        }
+    }
+}
+
+#[test]
+fn srgb_round_trip_r() {
+    for r in (0..=255).step_by(5) {
+        for g in 0..=255 {
+            for b in 0..=255 {
+                let srgb = Srgb::new(r, g, b);
+                let [y, co, cg] = <[i16; 3]>::from(YcocgR::from(srgb));
+                assert!((-255..=255).contains(&co) && (-255..=255).contains(&cg));
+                let back = Srgb::try_from(YcocgR::new(y as u8, co, cg).unwrap()).unwrap();
+                assert_eq!(<[u8; 3]>::from(back), [r, g, b]);
+            }
+        }
+    }
+}